@@ -0,0 +1,374 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+use num_traits::Float;
+
+// Area notice is broadcast with DAC 1 FID 22 (message type 8). A notice consists of a header
+// and up to ten 87-bit sub-areas. Polylines and polygons continue from the position of the
+// preceding sub-area and long ones are split into several consecutive sub-areas, as are texts.
+// The decoder merges the consecutive parts back together.
+
+/// Mean Earth radius in meters used for converting polyline points to coordinates
+const EARTH_RADIUS_METERS: f64 = 6371000.0;
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Area notice
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AreaNotice {
+    /// Message linkage ID (10 bits)
+    pub link_id: u16,
+
+    /// Notice description type (7 bits)
+    pub notice_type: u8,
+
+    /// Start of the validity period
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// End of the validity period; `None` means the notice is valid until further notice
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub end_time: Option<DateTime<Utc>>,
+
+    /// Sub-areas of the notice
+    pub sub_areas: Vec<SubArea>,
+}
+
+impl AreaNotice {
+    /// Return human-readable description of the notice type.
+    pub fn notice_description(&self) -> &'static str {
+        notice_description(self.notice_type)
+    }
+}
+
+impl LatLon for AreaNotice {
+    fn latitude(&self) -> Option<f64> {
+        self.sub_areas.iter().find_map(|a| a.latitude())
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.sub_areas.iter().find_map(|a| a.longitude())
+    }
+}
+
+/// Sub-area of an area notice. Distances are in meters and angles in degrees.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum SubArea {
+    /// Circle or point (radius 0)
+    Circle {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        radius_meters: u32,
+    },
+
+    /// Rectangle with the south-west corner at the position
+    Rectangle {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        east_dimension_meters: u32,
+        north_dimension_meters: u32,
+        orientation: u16,
+    },
+
+    /// Sector between the left and right boundary bearings
+    Sector {
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        radius_meters: u32,
+        left_boundary: u16,
+        right_boundary: u16,
+    },
+
+    /// Polyline including the starting point
+    Polyline { points: Vec<Waypoint> },
+
+    /// Polygon including the starting point
+    Polygon { points: Vec<Waypoint> },
+
+    /// Associated text
+    Text { text: String },
+}
+
+impl LatLon for SubArea {
+    fn latitude(&self) -> Option<f64> {
+        match self {
+            SubArea::Circle { latitude, .. } => *latitude,
+            SubArea::Rectangle { latitude, .. } => *latitude,
+            SubArea::Sector { latitude, .. } => *latitude,
+            SubArea::Polyline { points } => points.first().map(|p| p.latitude),
+            SubArea::Polygon { points } => points.first().map(|p| p.latitude),
+            SubArea::Text { .. } => None,
+        }
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        match self {
+            SubArea::Circle { longitude, .. } => *longitude,
+            SubArea::Rectangle { longitude, .. } => *longitude,
+            SubArea::Sector { longitude, .. } => *longitude,
+            SubArea::Polyline { points } => points.first().map(|p| p.longitude),
+            SubArea::Polygon { points } => points.first().map(|p| p.longitude),
+            SubArea::Text { .. } => None,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode area notice application data starting at bit `index`.
+pub(crate) fn decode(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<AreaNotice, ParseError> {
+    let start_time = pick_month_day_time(bv, index + 17, now)?;
+    let sub_area_count = min(bv.len().saturating_sub(index + 55) / 87, 10);
+    let mut sub_areas: Vec<SubArea> = Vec::with_capacity(sub_area_count);
+    for i in 0..sub_area_count {
+        let start = index + 55 + 87 * i;
+        let shape = pick_u64(bv, start, 3);
+        let scale = [1, 10, 100, 1000][pick_u64(bv, start + 3, 2) as usize];
+        match shape {
+            0 => sub_areas.push(SubArea::Circle {
+                longitude: pick_longitude(bv, start + 5, 25, 60000),
+                latitude: pick_latitude(bv, start + 30, 24, 60000),
+                radius_meters: pick_u64(bv, start + 57, 12) as u32 * scale,
+            }),
+            1 => sub_areas.push(SubArea::Rectangle {
+                longitude: pick_longitude(bv, start + 5, 25, 60000),
+                latitude: pick_latitude(bv, start + 30, 24, 60000),
+                east_dimension_meters: pick_u64(bv, start + 57, 8) as u32 * scale,
+                north_dimension_meters: pick_u64(bv, start + 65, 8) as u32 * scale,
+                orientation: pick_u64(bv, start + 73, 9) as u16,
+            }),
+            2 => sub_areas.push(SubArea::Sector {
+                longitude: pick_longitude(bv, start + 5, 25, 60000),
+                latitude: pick_latitude(bv, start + 30, 24, 60000),
+                radius_meters: pick_u64(bv, start + 57, 12) as u32 * scale,
+                left_boundary: pick_u64(bv, start + 69, 9) as u16,
+                right_boundary: pick_u64(bv, start + 78, 9) as u16,
+            }),
+            3 | 4 => {
+                // Continue an earlier polyline/polygon of the same shape or start a new one from
+                // the position of the preceding sub-area
+                let continued = matches!(
+                    (shape, sub_areas.last()),
+                    (3, Some(SubArea::Polyline { .. })) | (4, Some(SubArea::Polygon { .. }))
+                );
+                let mut points = match (shape, sub_areas.last_mut()) {
+                    (3, Some(SubArea::Polyline { points })) => core::mem::take(points),
+                    (4, Some(SubArea::Polygon { points })) => core::mem::take(points),
+                    (_, Some(previous)) => match (previous.latitude(), previous.longitude()) {
+                        (Some(latitude), Some(longitude)) => vec![Waypoint {
+                            latitude,
+                            longitude,
+                        }],
+                        _ => {
+                            warn!("Skipping area notice polyline without a starting point");
+                            continue;
+                        }
+                    },
+                    (_, None) => {
+                        warn!("Skipping area notice polyline without a starting point");
+                        continue;
+                    }
+                };
+                for j in 0..4 {
+                    let angle_raw = pick_u64(bv, start + 5 + 20 * j, 10);
+                    if angle_raw >= 720 {
+                        break;
+                    }
+                    let distance = pick_u64(bv, start + 15 + 20 * j, 10) as u32 * scale;
+                    let previous = points[points.len() - 1];
+                    points.push(destination_point(
+                        previous,
+                        angle_raw as f64 * 0.5,
+                        distance,
+                    ));
+                }
+                let sub_area = if shape == 3 {
+                    SubArea::Polyline { points }
+                } else {
+                    SubArea::Polygon { points }
+                };
+                if continued {
+                    let last = sub_areas.len() - 1;
+                    sub_areas[last] = sub_area;
+                } else {
+                    sub_areas.push(sub_area);
+                }
+            }
+            5 => {
+                let text = pick_untrimmed_string(bv, start + 3, 14);
+                if let Some(SubArea::Text { text: previous }) = sub_areas.last_mut() {
+                    previous.push_str(&text);
+                } else {
+                    sub_areas.push(SubArea::Text { text });
+                }
+            }
+            _ => {
+                warn!("Unsupported area notice sub-area shape: {}", shape);
+            }
+        }
+    }
+
+    for sub_area in sub_areas.iter_mut() {
+        if let SubArea::Text { text } = sub_area {
+            let trimmed_len = text.trim_end().len();
+            text.truncate(trimmed_len);
+        }
+    }
+
+    Ok(AreaNotice {
+        link_id: pick_u64(bv, index, 10) as u16,
+        notice_type: pick_u64(bv, index + 10, 7) as u8,
+        start_time,
+        end_time: {
+            let duration = pick_u64(bv, index + 37, 18);
+            match start_time {
+                Some(t) if duration != 262143 => {
+                    Some(t + chrono::Duration::minutes(duration as i64))
+                }
+                _ => None,
+            }
+        },
+        sub_areas,
+    })
+}
+
+/// Calculate the point at `distance` meters from `origin` to the direction of `bearing` degrees
+/// along a great circle.
+fn destination_point(origin: Waypoint, bearing: f64, distance: u32) -> Waypoint {
+    let lat1 = Float::to_radians(origin.latitude);
+    let lon1 = Float::to_radians(origin.longitude);
+    let theta = Float::to_radians(bearing);
+    let delta = distance as f64 / EARTH_RADIUS_METERS;
+    let lat2 = Float::asin(
+        Float::sin(lat1) * Float::cos(delta)
+            + Float::cos(lat1) * Float::sin(delta) * Float::cos(theta),
+    );
+    let lon2 = lon1
+        + Float::atan2(
+            Float::sin(theta) * Float::sin(delta) * Float::cos(lat1),
+            Float::cos(delta) - Float::sin(lat1) * Float::sin(lat2),
+        );
+    Waypoint {
+        latitude: Float::to_degrees(lat2),
+        longitude: (Float::to_degrees(lon2) + 540.0) % 360.0 - 180.0,
+    }
+}
+
+/// Return human-readable description of an area notice type.
+fn notice_description(notice_type: u8) -> &'static str {
+    match notice_type {
+        0 => "Caution Area: Marine mammals habitat",
+        1 => "Caution Area: Marine mammals in area - reduce speed",
+        2 => "Caution Area: Marine mammals in area - stay clear",
+        3 => "Caution Area: Marine mammals in area - report sightings",
+        4 => "Caution Area: Protected habitat - reduce speed",
+        5 => "Caution Area: Protected habitat - stay clear",
+        6 => "Caution Area: Protected habitat - no fishing or anchoring",
+        7 => "Caution Area: Derelicts (drifting objects)",
+        8 => "Caution Area: Traffic congestion",
+        9 => "Caution Area: Marine event",
+        10 => "Caution Area: Divers down",
+        11 => "Caution Area: Swim area",
+        12 => "Caution Area: Dredge operations",
+        13 => "Caution Area: Survey operations",
+        14 => "Caution Area: Underwater operation",
+        15 => "Caution Area: Seaplane operations",
+        16 => "Caution Area: Fishery - nets in water",
+        17 => "Caution Area: Cluster of fishing vessels",
+        18 => "Caution Area: Fairway closed",
+        19 => "Caution Area: Harbour closed",
+        20 => "Caution Area: Risk",
+        21 => "Caution Area: Underwater vehicle operation",
+        23 => "Environmental Caution Area: Storm front (line squall)",
+        24 => "Environmental Caution Area: Hazardous sea ice",
+        25 => "Environmental Caution Area: Storm warning",
+        26 => "Environmental Caution Area: High wind",
+        27 => "Environmental Caution Area: High waves",
+        28 => "Environmental Caution Area: Restricted visibility",
+        29 => "Environmental Caution Area: Strong currents",
+        30 => "Environmental Caution Area: Heavy icing",
+        32 => "Restricted Area: Fishing prohibited",
+        33 => "Restricted Area: No anchoring",
+        34 => "Restricted Area: Entry approval required prior to transit",
+        35 => "Restricted Area: Entry prohibited",
+        36 => "Restricted Area: Active military OPAREA",
+        37 => "Restricted Area: Firing - danger area",
+        38 => "Restricted Area: Drifting mines",
+        40 => "Anchorage Area: Anchorage open",
+        41 => "Anchorage Area: Anchorage closed",
+        42 => "Anchorage Area: Anchoring prohibited",
+        43 => "Anchorage Area: Deep draft anchorage",
+        44 => "Anchorage Area: Shallow draft anchorage",
+        45 => "Anchorage Area: Vessel transfer operations",
+        56 => "Security Alert - Level 1",
+        57 => "Security Alert - Level 2",
+        58 => "Security Alert - Level 3",
+        64 => "Distress Area: Vessel disabled and adrift",
+        65 => "Distress Area: Vessel sinking",
+        66 => "Distress Area: Vessel abandoning ship",
+        67 => "Distress Area: Vessel requests medical assistance",
+        68 => "Distress Area: Vessel flooding",
+        69 => "Distress Area: Vessel fire/explosion",
+        70 => "Distress Area: Vessel grounding",
+        71 => "Distress Area: Vessel collision",
+        72 => "Distress Area: Vessel listing/capsizing",
+        73 => "Distress Area: Vessel under assault",
+        74 => "Distress Area: Person overboard",
+        75 => "Distress Area: SAR area",
+        76 => "Distress Area: Pollution response area",
+        80 => "Instruction: Contact VTS at this point/juncture",
+        81 => "Instruction: Contact Port Administration at this point/juncture",
+        82 => "Instruction: Do not proceed beyond this point/juncture",
+        83 => "Instruction: Await instructions prior to proceeding beyond this point/juncture",
+        88 => "Information: Pilot boarding position",
+        89 => "Information: Icebreaker waiting area",
+        90 => "Information: Places of refuge",
+        91 => "Information: Position of icebreakers",
+        92 => "Information: Location of response units",
+        93 => "VTS active target",
+        94 => "Rogue or suspicious vessel",
+        95 => "Vessel requesting non-distress assistance",
+        96 => "Chart Feature: Sunken vessel",
+        97 => "Chart Feature: Submerged object",
+        98 => "Chart Feature: Semi-submerged object",
+        99 => "Chart Feature: Shoal area",
+        100 => "Chart Feature: Shoal area due north",
+        101 => "Chart Feature: Shoal area due east",
+        102 => "Chart Feature: Shoal area due south",
+        103 => "Chart Feature: Shoal area due west",
+        104 => "Chart Feature: Channel obstruction",
+        105 => "Chart Feature: Reduced vertical clearance",
+        106 => "Chart Feature: Bridge closed",
+        107 => "Chart Feature: Bridge partially open",
+        108 => "Chart Feature: Bridge fully open",
+        112 => "Report from ship: Icing info",
+        114 => "Report from ship: Miscellaneous information",
+        120 => "Route: Recommended route",
+        121 => "Route: Alternative route",
+        122 => "Route: Recommended route through ice",
+        126 => "Other",
+        127 => "Cancellation",
+        _ => "(reserved)",
+    }
+}
@@ -0,0 +1,172 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Dangerous cargo indication is addressed with DAC 1 FID 25 (message type 6).
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Dangerous cargo indication
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DangerousCargoIndication {
+    /// Unit of the quantity (2 bits)
+    pub unit: CargoQuantityUnit,
+
+    /// Amount of the cargo (10 bits)
+    pub amount: Option<u16>,
+
+    /// Cargos on board (max 28)
+    pub cargos: Vec<DangerousCargo>,
+}
+
+/// Single cargo entry of a dangerous cargo indication
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DangerousCargo {
+    /// Code under which the cargo is carried (4 bits)
+    pub code: DangerousCargoCode,
+
+    /// Code specific subtype: IMDG class or division (7 bits), BC code (3 bits),
+    /// MARPOL Annex I oil type (4 bits) or MARPOL Annex II IBC category (3 bits)
+    pub subtype: Option<u8>,
+}
+
+/// Unit of quantity of a dangerous cargo indication
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CargoQuantityUnit {
+    #[default]
+    NotAvailable = 0, // 0
+    Kilograms = 1,  // 1
+    Tonnes = 2,     // 2
+    Kilotonnes = 3, // 3
+}
+
+impl CargoQuantityUnit {
+    pub fn new(raw: u8) -> CargoQuantityUnit {
+        match raw {
+            0 => CargoQuantityUnit::NotAvailable,
+            1 => CargoQuantityUnit::Kilograms,
+            2 => CargoQuantityUnit::Tonnes,
+            3 => CargoQuantityUnit::Kilotonnes,
+            _ => {
+                warn!("Unrecognized cargo quantity unit: {}", raw);
+                CargoQuantityUnit::NotAvailable
+            }
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for CargoQuantityUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CargoQuantityUnit::NotAvailable => write!(f, "not available"),
+            CargoQuantityUnit::Kilograms => write!(f, "kg"),
+            CargoQuantityUnit::Tonnes => write!(f, "t"),
+            CargoQuantityUnit::Kilotonnes => write!(f, "kt"),
+        }
+    }
+}
+
+/// Code under which a dangerous cargo is carried
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DangerousCargoCode {
+    #[default]
+    NotAvailable = 0, // 0
+    Imdg = 1,          // 1
+    Bc = 2,            // 2
+    MarpolAnnexI = 3,  // 3
+    MarpolAnnexII = 4, // 4
+    RegionalUse = 5,   // 5
+    Reserved = 6,      // 6-15
+}
+
+impl DangerousCargoCode {
+    pub fn new(raw: u8) -> DangerousCargoCode {
+        match raw {
+            0 => DangerousCargoCode::NotAvailable,
+            1 => DangerousCargoCode::Imdg,
+            2 => DangerousCargoCode::Bc,
+            3 => DangerousCargoCode::MarpolAnnexI,
+            4 => DangerousCargoCode::MarpolAnnexII,
+            5 => DangerousCargoCode::RegionalUse,
+            6..=15 => DangerousCargoCode::Reserved,
+            _ => {
+                warn!("Unrecognized dangerous cargo code: {}", raw);
+                DangerousCargoCode::NotAvailable
+            }
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for DangerousCargoCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DangerousCargoCode::NotAvailable => write!(f, "not available"),
+            DangerousCargoCode::Imdg => write!(f, "IMDG code"),
+            DangerousCargoCode::Bc => write!(f, "BC code"),
+            DangerousCargoCode::MarpolAnnexI => write!(f, "MARPOL Annex I"),
+            DangerousCargoCode::MarpolAnnexII => write!(f, "MARPOL Annex II IBC"),
+            DangerousCargoCode::RegionalUse => write!(f, "regional use"),
+            DangerousCargoCode::Reserved => write!(f, "(reserved)"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode dangerous cargo indication application data starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> DangerousCargoIndication {
+    let cargo_count = min(bv.len().saturating_sub(index + 12) / 17, 28);
+    DangerousCargoIndication {
+        unit: CargoQuantityUnit::new(pick_u64(bv, index, 2) as u8),
+        amount: {
+            let raw = pick_u64(bv, index + 2, 10) as u16;
+            if raw != 0 {
+                Some(raw)
+            } else {
+                None
+            }
+        },
+        cargos: {
+            let mut v = Vec::with_capacity(cargo_count);
+            for i in 0..cargo_count {
+                let start = index + 12 + 17 * i;
+                let code = DangerousCargoCode::new(pick_u64(bv, start, 4) as u8);
+                let subtype = match code {
+                    DangerousCargoCode::Imdg => Some(pick_u64(bv, start + 4, 7) as u8),
+                    DangerousCargoCode::Bc => Some(pick_u64(bv, start + 4, 3) as u8),
+                    DangerousCargoCode::MarpolAnnexI => Some(pick_u64(bv, start + 4, 4) as u8),
+                    DangerousCargoCode::MarpolAnnexII => Some(pick_u64(bv, start + 4, 3) as u8),
+                    _ => None,
+                };
+                v.push(DangerousCargo { code, subtype });
+            }
+            v
+        },
+    }
+}
@@ -0,0 +1,329 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Meteorological and hydrographic data is broadcast with DAC 1 FID 31 (message type 8).
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Meteorological and hydrographic data
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeteorologicalHydrographicData {
+    /// Latitude of the observation point
+    pub latitude: Option<f64>,
+
+    /// Longitude of the observation point
+    pub longitude: Option<f64>,
+
+    /// Position accuracy: true = high (<= 10 m), false = low (> 10 m)
+    pub high_position_accuracy: bool,
+
+    /// UTC day of the observation (5 bits)
+    pub day: Option<u8>,
+
+    /// UTC hour of the observation (5 bits)
+    pub hour: Option<u8>,
+
+    /// UTC minute of the observation (6 bits)
+    pub minute: Option<u8>,
+
+    /// Average wind speed of the last 10 minutes in knots; 126 means 126 knots or more
+    pub wind_speed_knots: Option<u8>,
+
+    /// Wind gust speed of the last 10 minutes in knots; 126 means 126 knots or more
+    pub wind_gust_knots: Option<u8>,
+
+    /// Wind direction in degrees
+    pub wind_direction: Option<u16>,
+
+    /// Wind gust direction in degrees
+    pub wind_gust_direction: Option<u16>,
+
+    /// Air temperature in degrees Celsius
+    pub air_temperature_celsius: Option<f64>,
+
+    /// Relative humidity in percents
+    pub relative_humidity: Option<u8>,
+
+    /// Dew point in degrees Celsius
+    pub dew_point_celsius: Option<f64>,
+
+    /// Air pressure in hPa; 799 means 799 hPa or less and 1201 means 1201 hPa or more
+    pub air_pressure_hpa: Option<u16>,
+
+    /// Air pressure tendency
+    pub air_pressure_tendency: Option<Tendency>,
+
+    /// Horizontal visibility in nautical miles
+    pub visibility_nm: Option<f64>,
+
+    /// True if the visibility is greater than the value of `visibility_nm`
+    pub visibility_greater_than: bool,
+
+    /// Water level including tide, deviation from the local chart datum in meters
+    pub water_level_meters: Option<f64>,
+
+    /// Water level trend
+    pub water_level_trend: Option<Tendency>,
+
+    /// Surface current
+    pub surface_current: WaterCurrent,
+
+    /// Current at the depth given in the struct
+    pub current2: WaterCurrent,
+
+    /// Current at the depth given in the struct
+    pub current3: WaterCurrent,
+
+    /// Significant wave height in meters; 25.1 means 25.1 meters or more
+    pub wave_height_meters: Option<f64>,
+
+    /// Wave period in seconds
+    pub wave_period_seconds: Option<u8>,
+
+    /// Wave direction in degrees
+    pub wave_direction: Option<u16>,
+
+    /// Swell height in meters; 25.1 means 25.1 meters or more
+    pub swell_height_meters: Option<f64>,
+
+    /// Swell period in seconds
+    pub swell_period_seconds: Option<u8>,
+
+    /// Swell direction in degrees
+    pub swell_direction: Option<u16>,
+
+    /// Sea state according to the Beaufort scale (0-12)
+    pub sea_state_beaufort: Option<u8>,
+
+    /// Water temperature in degrees Celsius
+    pub water_temperature_celsius: Option<f64>,
+
+    /// Precipitation type
+    pub precipitation: Option<PrecipitationType>,
+
+    /// Salinity in parts per thousand
+    pub salinity_permille: Option<f64>,
+
+    /// Ice presence
+    pub ice: Option<bool>,
+}
+
+impl LatLon for MeteorologicalHydrographicData {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+/// Water current measurement of meteorological and hydrographic data
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WaterCurrent {
+    /// Current speed in knots; 25.1 means 25.1 knots or more
+    pub speed_knots: Option<f64>,
+
+    /// Current direction in degrees
+    pub direction: Option<u16>,
+
+    /// Measurement depth in meters; `None` for the surface current
+    pub depth_meters: Option<u8>,
+}
+
+/// Tendency of a meteorological or hydrographic quantity
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Tendency {
+    Steady = 0,     // 0
+    Decreasing = 1, // 1
+    Increasing = 2, // 2
+}
+
+impl Tendency {
+    /// Return `None` for the "not available" value 3.
+    pub fn new(raw: u8) -> Option<Tendency> {
+        match raw {
+            0 => Some(Tendency::Steady),
+            1 => Some(Tendency::Decreasing),
+            2 => Some(Tendency::Increasing),
+            _ => None,
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for Tendency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Tendency::Steady => write!(f, "steady"),
+            Tendency::Decreasing => write!(f, "decreasing"),
+            Tendency::Increasing => write!(f, "increasing"),
+        }
+    }
+}
+
+/// Precipitation type according to WMO
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PrecipitationType {
+    Reserved = 0,     // 0, 6
+    Rain = 1,         // 1
+    Thunderstorm = 2, // 2
+    FreezingRain = 3, // 3
+    MixedIce = 4,     // 4
+    Snow = 5,         // 5
+}
+
+impl PrecipitationType {
+    /// Return `None` for the "not available" value 7.
+    pub fn new(raw: u8) -> Option<PrecipitationType> {
+        match raw {
+            0 | 6 => Some(PrecipitationType::Reserved),
+            1 => Some(PrecipitationType::Rain),
+            2 => Some(PrecipitationType::Thunderstorm),
+            3 => Some(PrecipitationType::FreezingRain),
+            4 => Some(PrecipitationType::MixedIce),
+            5 => Some(PrecipitationType::Snow),
+            _ => None,
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for PrecipitationType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PrecipitationType::Reserved => write!(f, "(reserved)"),
+            PrecipitationType::Rain => write!(f, "rain"),
+            PrecipitationType::Thunderstorm => write!(f, "thunderstorm"),
+            PrecipitationType::FreezingRain => write!(f, "freezing rain"),
+            PrecipitationType::MixedIce => write!(f, "mixed/ice"),
+            PrecipitationType::Snow => write!(f, "snow"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode meteorological and hydrographic data application data starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> MeteorologicalHydrographicData {
+    MeteorologicalHydrographicData {
+        longitude: pick_longitude(bv, index, 25, 60000),
+        latitude: pick_latitude(bv, index + 25, 24, 60000),
+        high_position_accuracy: pick_u64(bv, index + 49, 1) != 0,
+        day: pick_u64_unless_na(bv, index + 50, 5, 0).map(|v| v as u8),
+        hour: pick_u64_unless_na(bv, index + 55, 5, 24).map(|v| v as u8),
+        minute: pick_u64_unless_na(bv, index + 60, 6, 60).map(|v| v as u8),
+        wind_speed_knots: pick_u64_unless_na(bv, index + 66, 7, 127).map(|v| v as u8),
+        wind_gust_knots: pick_u64_unless_na(bv, index + 73, 7, 127).map(|v| v as u8),
+        wind_direction: pick_direction(bv, index + 80),
+        wind_gust_direction: pick_direction(bv, index + 89),
+        air_temperature_celsius: pick_i64_unless_na(bv, index + 98, 11, -1024)
+            .map(|v| v as f64 * 0.1),
+        relative_humidity: pick_u64_unless_na(bv, index + 109, 7, 101).map(|v| v as u8),
+        dew_point_celsius: pick_i64_unless_na(bv, index + 116, 10, 501).map(|v| v as f64 * 0.1),
+        air_pressure_hpa: {
+            let raw = pick_u64(bv, index + 126, 9) as u16;
+            if raw <= 402 {
+                Some(raw + 799)
+            } else {
+                None
+            }
+        },
+        air_pressure_tendency: Tendency::new(pick_u64(bv, index + 135, 2) as u8),
+        visibility_nm: pick_u64_unless_na(bv, index + 138, 7, 127).map(|v| v as f64 * 0.1),
+        visibility_greater_than: pick_u64(bv, index + 137, 1) != 0,
+        water_level_meters: pick_u64_unless_na(bv, index + 145, 12, 4001)
+            .map(|v| v as f64 * 0.01 - 10.0),
+        water_level_trend: Tendency::new(pick_u64(bv, index + 157, 2) as u8),
+        surface_current: WaterCurrent {
+            speed_knots: pick_tenths(bv, index + 159),
+            direction: pick_direction(bv, index + 167),
+            depth_meters: None,
+        },
+        current2: WaterCurrent {
+            speed_knots: pick_tenths(bv, index + 176),
+            direction: pick_direction(bv, index + 184),
+            depth_meters: pick_u64_unless_na(bv, index + 193, 5, 31).map(|v| v as u8),
+        },
+        current3: WaterCurrent {
+            speed_knots: pick_tenths(bv, index + 198),
+            direction: pick_direction(bv, index + 206),
+            depth_meters: pick_u64_unless_na(bv, index + 215, 5, 31).map(|v| v as u8),
+        },
+        wave_height_meters: pick_tenths(bv, index + 220),
+        wave_period_seconds: pick_u64_unless_na(bv, index + 228, 6, 63).map(|v| v as u8),
+        wave_direction: pick_direction(bv, index + 234),
+        swell_height_meters: pick_tenths(bv, index + 243),
+        swell_period_seconds: pick_u64_unless_na(bv, index + 251, 6, 63).map(|v| v as u8),
+        swell_direction: pick_direction(bv, index + 257),
+        sea_state_beaufort: {
+            let raw = pick_u64(bv, index + 266, 4) as u8;
+            if raw <= 12 {
+                Some(raw)
+            } else {
+                None
+            }
+        },
+        water_temperature_celsius: pick_i64_unless_na(bv, index + 270, 10, 501)
+            .map(|v| v as f64 * 0.1),
+        precipitation: PrecipitationType::new(pick_u64(bv, index + 280, 3) as u8),
+        salinity_permille: {
+            let raw = pick_u64(bv, index + 283, 9);
+            if raw <= 500 {
+                Some(raw as f64 * 0.1)
+            } else {
+                None
+            }
+        },
+        ice: match pick_u64(bv, index + 292, 2) {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        },
+    }
+}
+
+/// Pick a 9-bit direction field where values 360-511 mean "not available".
+fn pick_direction(bv: &BitVec, index: usize) -> Option<u16> {
+    let raw = pick_u64(bv, index, 9) as u16;
+    if raw < 360 {
+        Some(raw)
+    } else {
+        None
+    }
+}
+
+/// Pick an 8-bit field of tenths (speed or height) where values 252-255 mean "not available".
+fn pick_tenths(bv: &BitVec, index: usize) -> Option<f64> {
+    let raw = pick_u64(bv, index, 8);
+    if raw <= 251 {
+        Some(raw as f64 * 0.1)
+    } else {
+        None
+    }
+}
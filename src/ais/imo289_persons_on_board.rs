@@ -0,0 +1,46 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Number of persons on board is addressed with DAC 1 FID 40 (message type 6). The earlier
+// IMO Circ.236 application DAC 1 FID 16 has the same layout.
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Number of persons on board
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PersonsOnBoard {
+    /// Number of persons on board (13 bits); 8191 means 8191 or more
+    pub persons: Option<u16>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode number of persons on board application data starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> PersonsOnBoard {
+    PersonsOnBoard {
+        persons: {
+            let raw = pick_u64(bv, index, 13) as u16;
+            if raw != 0 {
+                Some(raw)
+            } else {
+                None
+            }
+        },
+    }
+}
@@ -0,0 +1,186 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Route information is broadcast with DAC 1 FID 27 (message type 8) and addressed with
+// DAC 1 FID 28 (message type 6). The application data layout is identical in both.
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Route information
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RouteInformation {
+    /// Message linkage ID (10 bits)
+    pub link_id: u16,
+
+    /// Sender classification (3 bits): 0 = ship, 1 = authority, 2-7 = reserved
+    pub sender_classification: u8,
+
+    /// Route type (5 bits)
+    pub route_type: RouteType,
+
+    /// Start date and time of the route
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// Duration in minutes (18 bits); `None` means the route is valid until further notice
+    pub duration_minutes: Option<u32>,
+
+    /// Waypoints of the route (max 16); `None` means the position of the waypoint is not
+    /// available
+    pub waypoints: Vec<Option<Waypoint>>,
+}
+
+impl LatLon for RouteInformation {
+    fn latitude(&self) -> Option<f64> {
+        self.waypoints
+            .first()
+            .copied()
+            .flatten()
+            .map(|w| w.latitude)
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.waypoints
+            .first()
+            .copied()
+            .flatten()
+            .map(|w| w.longitude)
+    }
+}
+
+/// Geographical position of a waypoint
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Waypoint {
+    /// Latitude in degrees
+    pub latitude: f64,
+
+    /// Longitude in degrees
+    pub longitude: f64,
+}
+
+impl LatLon for Waypoint {
+    fn latitude(&self) -> Option<f64> {
+        Some(self.latitude)
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        Some(self.longitude)
+    }
+}
+
+/// Route type of IMO SN.1/Circ.289 route information
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RouteType {
+    #[default]
+    Undefined = 0, // 0
+    Mandatory = 1,             // 1
+    Recommended = 2,           // 2
+    Alternative = 3,           // 3
+    RecommendedThroughIce = 4, // 4
+    ShipRoutePlan = 5,         // 5
+    Reserved = 6,              // 6-30
+    Cancellation = 31,         // 31
+}
+
+impl RouteType {
+    pub fn new(raw: u8) -> RouteType {
+        match raw {
+            0 => RouteType::Undefined,
+            1 => RouteType::Mandatory,
+            2 => RouteType::Recommended,
+            3 => RouteType::Alternative,
+            4 => RouteType::RecommendedThroughIce,
+            5 => RouteType::ShipRoutePlan,
+            6..=30 => RouteType::Reserved,
+            31 => RouteType::Cancellation,
+            _ => {
+                warn!("Unrecognized route type: {}", raw);
+                RouteType::Undefined
+            }
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for RouteType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RouteType::Undefined => write!(f, "undefined"),
+            RouteType::Mandatory => write!(f, "mandatory"),
+            RouteType::Recommended => write!(f, "recommended"),
+            RouteType::Alternative => write!(f, "alternative"),
+            RouteType::RecommendedThroughIce => write!(f, "recommended through ice"),
+            RouteType::ShipRoutePlan => write!(f, "ship route plan"),
+            RouteType::Reserved => write!(f, "(reserved)"),
+            RouteType::Cancellation => write!(f, "cancellation"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode route information application data starting at bit `index`.
+pub(crate) fn decode(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<RouteInformation, ParseError> {
+    let waypoint_count = min(
+        min(pick_u64(bv, index + 56, 5) as usize, 16),
+        bv.len().saturating_sub(index + 61) / 55,
+    );
+    Ok(RouteInformation {
+        link_id: pick_u64(bv, index, 10) as u16,
+        sender_classification: pick_u64(bv, index + 10, 3) as u8,
+        route_type: RouteType::new(pick_u64(bv, index + 13, 5) as u8),
+        start_time: pick_month_day_time(bv, index + 18, now)?,
+        duration_minutes: {
+            let raw = pick_u64(bv, index + 38, 18) as u32;
+            if raw != 262143 {
+                Some(raw)
+            } else {
+                None
+            }
+        },
+        waypoints: {
+            let mut v = Vec::with_capacity(waypoint_count);
+            for i in 0..waypoint_count {
+                let start = index + 61 + 55 * i;
+                v.push(
+                    match (
+                        pick_longitude(bv, start, 28, 600000),
+                        pick_latitude(bv, start + 28, 27, 600000),
+                    ) {
+                        (Some(longitude), Some(latitude)) => Some(Waypoint {
+                            latitude,
+                            longitude,
+                        }),
+                        _ => None,
+                    },
+                );
+            }
+            v
+        },
+    })
+}
@@ -0,0 +1,44 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Text description is broadcast with DAC 1 FID 29 (message type 8) and addressed with
+// DAC 1 FID 30 (message type 6). The application data layout is identical in both.
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Text description
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TextDescription {
+    /// Message linkage ID (10 bits)
+    pub link_id: u16,
+
+    /// Text (6-bit ASCII, max 161 characters)
+    pub text: String,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode text description application data starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> TextDescription {
+    let char_count = bv.len().saturating_sub(index + 10) / 6;
+    TextDescription {
+        link_id: pick_u64(bv, index, 10) as u16,
+        text: pick_string(bv, index + 10, char_count),
+    }
+}
@@ -0,0 +1,126 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Tidal window is addressed with DAC 1 FID 32 (message type 6).
+
+// -------------------------------------------------------------------------------------------------
+
+/// IMO SN.1/Circ.289 Tidal window
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TidalWindow {
+    /// Tidal windows (max 3)
+    pub windows: Vec<TidalWindowEntry>,
+}
+
+impl LatLon for TidalWindow {
+    fn latitude(&self) -> Option<f64> {
+        self.windows.first().and_then(|w| w.latitude)
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.windows.first().and_then(|w| w.longitude)
+    }
+}
+
+/// Single tidal window of a tidal window message
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TidalWindowEntry {
+    /// Latitude of the window position
+    pub latitude: Option<f64>,
+
+    /// Longitude of the window position
+    pub longitude: Option<f64>,
+
+    /// Start of the window
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub from: Option<DateTime<Utc>>,
+
+    /// End of the window
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub to: Option<DateTime<Utc>>,
+
+    /// Direction of the current in degrees (9 bits)
+    pub current_direction: Option<u16>,
+
+    /// Speed of the current in knots (8 bits); 25.1 means 25.1 knots or more
+    pub current_speed_knots: Option<f64>,
+}
+
+impl LatLon for TidalWindowEntry {
+    fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode tidal window application data starting at bit `index`.
+pub(crate) fn decode(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<TidalWindow, ParseError> {
+    let month = pick_u64(bv, index, 4) as u32;
+    let day = pick_u64(bv, index + 4, 5) as u32;
+    let window_count = min(bv.len().saturating_sub(index + 9) / 88, 3);
+    let mut windows = Vec::with_capacity(window_count);
+    for i in 0..window_count {
+        let start = index + 9 + 88 * i;
+        windows.push(TidalWindowEntry {
+            longitude: pick_longitude(bv, start, 25, 60000),
+            latitude: pick_latitude(bv, start + 25, 24, 60000),
+            from: month_day_time_to_utc(
+                month,
+                day,
+                pick_u64(bv, start + 49, 5) as u32,
+                pick_u64(bv, start + 54, 6) as u32,
+                now,
+            )?,
+            to: month_day_time_to_utc(
+                month,
+                day,
+                pick_u64(bv, start + 60, 5) as u32,
+                pick_u64(bv, start + 65, 6) as u32,
+                now,
+            )?,
+            current_direction: {
+                let raw = pick_u64(bv, start + 71, 9) as u16;
+                if raw < 360 {
+                    Some(raw)
+                } else {
+                    None
+                }
+            },
+            current_speed_knots: {
+                let raw = pick_u64(bv, start + 80, 8);
+                if raw <= 251 {
+                    Some(raw as f64 * 0.1)
+                } else {
+                    None
+                }
+            },
+        });
+    }
+    Ok(TidalWindow { windows })
+}
@@ -0,0 +1,151 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Inland AIS ETA report is addressed with DAC 200 FID 21 (message type 6) to a lock, bridge or
+// terminal, which replies with an RTA report DAC 200 FID 22 (message type 6). Both start with
+// an ISRS location code.
+
+// -------------------------------------------------------------------------------------------------
+
+/// ISRS location code identifying a lock, bridge or terminal
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InlandLocation {
+    /// UN country code (2 characters)
+    pub country_code: String,
+
+    /// UN location code (3 characters)
+    pub location_code: String,
+
+    /// Fairway section number (5 characters)
+    pub fairway_section: String,
+
+    /// Terminal code (5 characters)
+    pub terminal_code: String,
+
+    /// Fairway hectometre (5 characters)
+    pub fairway_hectometre: String,
+}
+
+/// Inland AIS ETA at lock, bridge or terminal
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InlandEta {
+    /// Location of the lock, bridge or terminal
+    pub location: InlandLocation,
+
+    /// Estimated time of arrival
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub eta: Option<DateTime<Utc>>,
+
+    /// Number of assisting tugboats (3 bits)
+    pub assisting_tugboats: Option<u8>,
+
+    /// Air draught in meters (12 bits)
+    pub air_draught_meters: Option<f64>,
+}
+
+/// Inland AIS RTA at lock, bridge or terminal
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InlandRta {
+    /// Location of the lock, bridge or terminal
+    pub location: InlandLocation,
+
+    /// Requested time of arrival
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub rta: Option<DateTime<Utc>>,
+
+    /// Status of the lock, bridge or terminal (2 bits)
+    pub status: Option<LockStatus>,
+}
+
+/// Status of a lock, bridge or terminal
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LockStatus {
+    Operational = 0, // 0
+    Limited = 1,     // 1
+    OutOfOrder = 2,  // 2
+}
+
+impl LockStatus {
+    /// Return `None` for the "not available" value 3.
+    pub fn new(raw: u8) -> Option<LockStatus> {
+        match raw {
+            0 => Some(LockStatus::Operational),
+            1 => Some(LockStatus::Limited),
+            2 => Some(LockStatus::OutOfOrder),
+            _ => None,
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for LockStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LockStatus::Operational => write!(f, "operational"),
+            LockStatus::Limited => write!(f, "limited operation"),
+            LockStatus::OutOfOrder => write!(f, "out of order"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode inland ETA report starting at bit `index`.
+pub(crate) fn decode_eta(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<InlandEta, ParseError> {
+    Ok(InlandEta {
+        location: decode_location(bv, index),
+        eta: pick_month_day_time(bv, index + 120, now)?,
+        assisting_tugboats: pick_u64_unless_na(bv, index + 140, 3, 7).map(|v| v as u8),
+        air_draught_meters: pick_u64_unless_na(bv, index + 143, 12, 0).map(|v| v as f64 * 0.01),
+    })
+}
+
+/// Decode inland RTA report starting at bit `index`.
+pub(crate) fn decode_rta(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<InlandRta, ParseError> {
+    Ok(InlandRta {
+        location: decode_location(bv, index),
+        rta: pick_month_day_time(bv, index + 120, now)?,
+        status: LockStatus::new(pick_u64(bv, index + 140, 2) as u8),
+    })
+}
+
+/// Decode 120-bit ISRS location code starting at bit `index`.
+fn decode_location(bv: &BitVec, index: usize) -> InlandLocation {
+    InlandLocation {
+        country_code: pick_string(bv, index, 2),
+        location_code: pick_string(bv, index + 12, 3),
+        fairway_section: pick_string(bv, index + 30, 5),
+        terminal_code: pick_string(bv, index + 60, 5),
+        fairway_hectometre: pick_string(bv, index + 90, 5),
+    }
+}
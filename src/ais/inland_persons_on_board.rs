@@ -0,0 +1,46 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Inland AIS number of persons on board is broadcast with DAC 200 FID 55 (message type 8).
+
+// -------------------------------------------------------------------------------------------------
+
+/// Inland AIS number of persons on board
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InlandPersonsOnBoard {
+    /// Number of crew members on board (8 bits)
+    pub crew: Option<u8>,
+
+    /// Number of passengers on board (13 bits)
+    pub passengers: Option<u16>,
+
+    /// Number of shipboard personnel on board (8 bits)
+    pub shipboard_personnel: Option<u8>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode inland number of persons on board starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> InlandPersonsOnBoard {
+    InlandPersonsOnBoard {
+        crew: pick_u64_unless_na(bv, index, 8, 255).map(|v| v as u8),
+        passengers: pick_u64_unless_na(bv, index + 8, 13, 8191).map(|v| v as u16),
+        shipboard_personnel: pick_u64_unless_na(bv, index + 21, 8, 255).map(|v| v as u8),
+    }
+}
@@ -0,0 +1,474 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Inland ship static and voyage related data is broadcast with DAC 200 FID 10 (message type 8)
+// according to the Inland AIS standard (ECE/TRANS/SC.3/176).
+
+// -------------------------------------------------------------------------------------------------
+
+/// Inland AIS ship static and voyage related data
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InlandStaticVoyageData {
+    /// Unique European vessel identification number, ENI (8 characters)
+    pub eni: Option<String>,
+
+    /// Length of the ship or convoy in meters (13 bits)
+    pub length_meters: Option<f64>,
+
+    /// Beam of the ship or convoy in meters (10 bits)
+    pub beam_meters: Option<f64>,
+
+    /// ERI ship or combination type (14 bits)
+    pub eri_ship_type: EriShipType,
+
+    /// Ship type corresponding to the ERI ship type
+    pub ship_type: ShipType,
+
+    /// Hazardous cargo (3 bits)
+    pub hazardous_cargo: Option<HazardousCargo>,
+
+    /// Draught in meters (11 bits)
+    pub draught_meters: Option<f64>,
+
+    /// True if the ship is loaded, false if unloaded (2 bits)
+    pub loaded: Option<bool>,
+
+    /// Quality of speed information: true = high, false = low/GNSS
+    pub high_speed_quality: bool,
+
+    /// Quality of course information: true = high, false = low/GNSS
+    pub high_course_quality: bool,
+
+    /// Quality of heading information: true = high, false = low
+    pub high_heading_quality: bool,
+}
+
+/// Number of blue cones or lights according to ADN
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HazardousCargo {
+    NoBlueCones = 0,    // 0
+    OneBlueCone = 1,    // 1
+    TwoBlueCones = 2,   // 2
+    ThreeBlueCones = 3, // 3
+    BFlag = 4,          // 4
+}
+
+impl HazardousCargo {
+    /// Return `None` for the "unknown" value 5 and the unused values.
+    pub fn new(raw: u8) -> Option<HazardousCargo> {
+        match raw {
+            0 => Some(HazardousCargo::NoBlueCones),
+            1 => Some(HazardousCargo::OneBlueCone),
+            2 => Some(HazardousCargo::TwoBlueCones),
+            3 => Some(HazardousCargo::ThreeBlueCones),
+            4 => Some(HazardousCargo::BFlag),
+            _ => None,
+        }
+    }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl core::fmt::Display for HazardousCargo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HazardousCargo::NoBlueCones => write!(f, "0 blue cones/lights"),
+            HazardousCargo::OneBlueCone => write!(f, "1 blue cone/light"),
+            HazardousCargo::TwoBlueCones => write!(f, "2 blue cones/lights"),
+            HazardousCargo::ThreeBlueCones => write!(f, "3 blue cones/lights"),
+            HazardousCargo::BFlag => write!(f, "B-flag"),
+        }
+    }
+}
+
+/// ERI ship or combination type
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EriShipType {
+    #[default]
+    Unknown = 8000, // 8000
+    MotorFreighter = 8010,                    // 8010
+    MotorTanker = 8020,                       // 8020
+    MotorTankerLiquidN = 8021,                // 8021
+    MotorTankerLiquidC = 8022,                // 8022
+    MotorTankerDryCargo = 8023,               // 8023
+    ContainerVessel = 8030,                   // 8030
+    GasTanker = 8040,                         // 8040
+    MotorFreighterTug = 8050,                 // 8050
+    MotorTankerTug = 8060,                    // 8060
+    MotorFreighterAlongside = 8070,           // 8070
+    MotorFreighterWithTanker = 8080,          // 8080
+    MotorFreighterPushingFreighters = 8090,   // 8090
+    MotorFreighterPushingTanker = 8100,       // 8100
+    TugFreighter = 8110,                      // 8110
+    TugTanker = 8120,                         // 8120
+    TugFreighterCoupled = 8130,               // 8130
+    TugFreighterTankerCoupled = 8140,         // 8140
+    FreightBarge = 8150,                      // 8150
+    TankBarge = 8160,                         // 8160
+    TankBargeLiquidN = 8161,                  // 8161
+    TankBargeLiquidC = 8162,                  // 8162
+    TankBargeDryCargo = 8163,                 // 8163
+    FreightBargeContainers = 8170,            // 8170
+    TankBargeGas = 8180,                      // 8180
+    PushtowOneCargoBarge = 8210,              // 8210
+    PushtowTwoCargoBarges = 8220,             // 8220
+    PushtowThreeCargoBarges = 8230,           // 8230
+    PushtowFourCargoBarges = 8240,            // 8240
+    PushtowFiveCargoBarges = 8250,            // 8250
+    PushtowSixCargoBarges = 8260,             // 8260
+    PushtowSevenCargoBarges = 8270,           // 8270
+    PushtowEightCargoBarges = 8280,           // 8280
+    PushtowNineOrMoreBarges = 8290,           // 8290
+    PushtowOneTankBarge = 8310,               // 8310
+    PushtowTwoBargesTanker = 8320,            // 8320
+    PushtowThreeBargesTanker = 8330,          // 8330
+    PushtowFourBargesTanker = 8340,           // 8340
+    PushtowFiveBargesTanker = 8350,           // 8350
+    PushtowSixBargesTanker = 8360,            // 8360
+    PushtowSevenBargesTanker = 8370,          // 8370
+    PushtowEightBargesTanker = 8380,          // 8380
+    PushtowNineOrMoreBargesTanker = 8390,     // 8390
+    Tug = 8400,                               // 8400
+    TugWithTows = 8410,                       // 8410
+    TugAssisting = 8420,                      // 8420
+    Pushboat = 8430,                          // 8430
+    PassengerShip = 8440,                     // 8440
+    Ferry = 8441,                             // 8441
+    RedCrossShip = 8442,                      // 8442
+    CruiseShip = 8443,                        // 8443
+    PassengerShipWithoutAccommodation = 8444, // 8444
+    ServiceVessel = 8450,                     // 8450
+    WorkCraft = 8460,                         // 8460
+    TowedObject = 8470,                       // 8470
+    FishingBoat = 8480,                       // 8480
+    Bunkership = 8490,                        // 8490
+    ChemicalTankerBarge = 8500,               // 8500
+    Object = 8510,                            // 8510
+    MaritimeGeneralCargo = 1500,              // 1500
+    MaritimeUnitCarrier = 1510,               // 1510
+    MaritimeBulkCarrier = 1520,               // 1520
+    MaritimeTanker = 1530,                    // 1530
+    MaritimeGasTanker = 1540,                 // 1540
+    PleasureCraft = 1850,                     // 1850
+    FastShip = 1900,                          // 1900
+    Hydrofoil = 1910,                         // 1910
+    FastCatamaran = 1920,                     // 1920
+}
+
+impl EriShipType {
+    pub fn new(raw: u16) -> EriShipType {
+        match raw {
+            8000 => EriShipType::Unknown,
+            8010 => EriShipType::MotorFreighter,
+            8020 => EriShipType::MotorTanker,
+            8021 => EriShipType::MotorTankerLiquidN,
+            8022 => EriShipType::MotorTankerLiquidC,
+            8023 => EriShipType::MotorTankerDryCargo,
+            8030 => EriShipType::ContainerVessel,
+            8040 => EriShipType::GasTanker,
+            8050 => EriShipType::MotorFreighterTug,
+            8060 => EriShipType::MotorTankerTug,
+            8070 => EriShipType::MotorFreighterAlongside,
+            8080 => EriShipType::MotorFreighterWithTanker,
+            8090 => EriShipType::MotorFreighterPushingFreighters,
+            8100 => EriShipType::MotorFreighterPushingTanker,
+            8110 => EriShipType::TugFreighter,
+            8120 => EriShipType::TugTanker,
+            8130 => EriShipType::TugFreighterCoupled,
+            8140 => EriShipType::TugFreighterTankerCoupled,
+            8150 => EriShipType::FreightBarge,
+            8160 => EriShipType::TankBarge,
+            8161 => EriShipType::TankBargeLiquidN,
+            8162 => EriShipType::TankBargeLiquidC,
+            8163 => EriShipType::TankBargeDryCargo,
+            8170 => EriShipType::FreightBargeContainers,
+            8180 => EriShipType::TankBargeGas,
+            8210 => EriShipType::PushtowOneCargoBarge,
+            8220 => EriShipType::PushtowTwoCargoBarges,
+            8230 => EriShipType::PushtowThreeCargoBarges,
+            8240 => EriShipType::PushtowFourCargoBarges,
+            8250 => EriShipType::PushtowFiveCargoBarges,
+            8260 => EriShipType::PushtowSixCargoBarges,
+            8270 => EriShipType::PushtowSevenCargoBarges,
+            8280 => EriShipType::PushtowEightCargoBarges,
+            8290 => EriShipType::PushtowNineOrMoreBarges,
+            8310 => EriShipType::PushtowOneTankBarge,
+            8320 => EriShipType::PushtowTwoBargesTanker,
+            8330 => EriShipType::PushtowThreeBargesTanker,
+            8340 => EriShipType::PushtowFourBargesTanker,
+            8350 => EriShipType::PushtowFiveBargesTanker,
+            8360 => EriShipType::PushtowSixBargesTanker,
+            8370 => EriShipType::PushtowSevenBargesTanker,
+            8380 => EriShipType::PushtowEightBargesTanker,
+            8390 => EriShipType::PushtowNineOrMoreBargesTanker,
+            8400 => EriShipType::Tug,
+            8410 => EriShipType::TugWithTows,
+            8420 => EriShipType::TugAssisting,
+            8430 => EriShipType::Pushboat,
+            8440 => EriShipType::PassengerShip,
+            8441 => EriShipType::Ferry,
+            8442 => EriShipType::RedCrossShip,
+            8443 => EriShipType::CruiseShip,
+            8444 => EriShipType::PassengerShipWithoutAccommodation,
+            8450 => EriShipType::ServiceVessel,
+            8460 => EriShipType::WorkCraft,
+            8470 => EriShipType::TowedObject,
+            8480 => EriShipType::FishingBoat,
+            8490 => EriShipType::Bunkership,
+            8500 => EriShipType::ChemicalTankerBarge,
+            8510 => EriShipType::Object,
+            1500 => EriShipType::MaritimeGeneralCargo,
+            1510 => EriShipType::MaritimeUnitCarrier,
+            1520 => EriShipType::MaritimeBulkCarrier,
+            1530 => EriShipType::MaritimeTanker,
+            1540 => EriShipType::MaritimeGasTanker,
+            1850 => EriShipType::PleasureCraft,
+            1900 => EriShipType::FastShip,
+            1910 => EriShipType::Hydrofoil,
+            1920 => EriShipType::FastCatamaran,
+            _ => {
+                warn!("Unrecognized ERI ship type: {}", raw);
+                EriShipType::Unknown
+            }
+        }
+    }
+
+    pub fn to_value(&self) -> u16 {
+        *self as u16
+    }
+
+    /// Return the AIS ship type corresponding to the ERI ship type.
+    pub fn ship_type(&self) -> ShipType {
+        ShipType::new(self.ship_and_cargo_type())
+    }
+
+    /// Return the AIS ship and cargo type value (as in message type 5) corresponding to the ERI
+    /// ship type.
+    pub fn ship_and_cargo_type(&self) -> u8 {
+        match self {
+            EriShipType::Unknown => 99,
+            EriShipType::MotorFreighter => 79,
+            EriShipType::MotorTanker => 89,
+            EriShipType::MotorTankerLiquidN => 80,
+            EriShipType::MotorTankerLiquidC => 80,
+            EriShipType::MotorTankerDryCargo => 89,
+            EriShipType::ContainerVessel => 79,
+            EriShipType::GasTanker => 80,
+            EriShipType::MotorFreighterTug => 79,
+            EriShipType::MotorTankerTug => 89,
+            EriShipType::MotorFreighterAlongside => 79,
+            EriShipType::MotorFreighterWithTanker => 89,
+            EriShipType::MotorFreighterPushingFreighters => 79,
+            EriShipType::MotorFreighterPushingTanker => 89,
+            EriShipType::TugFreighter => 79,
+            EriShipType::TugTanker => 89,
+            EriShipType::TugFreighterCoupled => 31,
+            EriShipType::TugFreighterTankerCoupled => 31,
+            EriShipType::FreightBarge => 99,
+            EriShipType::TankBarge => 99,
+            EriShipType::TankBargeLiquidN => 90,
+            EriShipType::TankBargeLiquidC => 90,
+            EriShipType::TankBargeDryCargo => 99,
+            EriShipType::FreightBargeContainers => 99,
+            EriShipType::TankBargeGas => 90,
+            EriShipType::PushtowOneCargoBarge => 79,
+            EriShipType::PushtowTwoCargoBarges => 79,
+            EriShipType::PushtowThreeCargoBarges => 79,
+            EriShipType::PushtowFourCargoBarges => 79,
+            EriShipType::PushtowFiveCargoBarges => 79,
+            EriShipType::PushtowSixCargoBarges => 79,
+            EriShipType::PushtowSevenCargoBarges => 79,
+            EriShipType::PushtowEightCargoBarges => 79,
+            EriShipType::PushtowNineOrMoreBarges => 79,
+            EriShipType::PushtowOneTankBarge => 80,
+            EriShipType::PushtowTwoBargesTanker => 80,
+            EriShipType::PushtowThreeBargesTanker => 80,
+            EriShipType::PushtowFourBargesTanker => 80,
+            EriShipType::PushtowFiveBargesTanker => 80,
+            EriShipType::PushtowSixBargesTanker => 80,
+            EriShipType::PushtowSevenBargesTanker => 80,
+            EriShipType::PushtowEightBargesTanker => 80,
+            EriShipType::PushtowNineOrMoreBargesTanker => 80,
+            EriShipType::Tug => 52,
+            EriShipType::TugWithTows => 31,
+            EriShipType::TugAssisting => 31,
+            EriShipType::Pushboat => 99,
+            EriShipType::PassengerShip => 69,
+            EriShipType::Ferry => 69,
+            EriShipType::RedCrossShip => 58,
+            EriShipType::CruiseShip => 69,
+            EriShipType::PassengerShipWithoutAccommodation => 69,
+            EriShipType::ServiceVessel => 99,
+            EriShipType::WorkCraft => 33,
+            EriShipType::TowedObject => 99,
+            EriShipType::FishingBoat => 30,
+            EriShipType::Bunkership => 99,
+            EriShipType::ChemicalTankerBarge => 80,
+            EriShipType::Object => 99,
+            EriShipType::MaritimeGeneralCargo => 79,
+            EriShipType::MaritimeUnitCarrier => 79,
+            EriShipType::MaritimeBulkCarrier => 79,
+            EriShipType::MaritimeTanker => 80,
+            EriShipType::MaritimeGasTanker => 80,
+            EriShipType::PleasureCraft => 37,
+            EriShipType::FastShip => 49,
+            EriShipType::Hydrofoil => 49,
+            EriShipType::FastCatamaran => 49,
+        }
+    }
+}
+
+impl core::fmt::Display for EriShipType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EriShipType::Unknown => write!(f, "vessel, type unknown"),
+            EriShipType::MotorFreighter => write!(f, "motor freighter"),
+            EriShipType::MotorTanker => write!(f, "motor tanker"),
+            EriShipType::MotorTankerLiquidN => write!(f, "motor tanker, liquid cargo, type N"),
+            EriShipType::MotorTankerLiquidC => write!(f, "motor tanker, liquid cargo, type C"),
+            EriShipType::MotorTankerDryCargo => write!(f, "motor tanker, dry cargo as if liquid"),
+            EriShipType::ContainerVessel => write!(f, "container vessel"),
+            EriShipType::GasTanker => write!(f, "gas tanker"),
+            EriShipType::MotorFreighterTug => write!(f, "motor freighter, tug"),
+            EriShipType::MotorTankerTug => write!(f, "motor tanker, tug"),
+            EriShipType::MotorFreighterAlongside => {
+                write!(f, "motor freighter with one or more ships alongside")
+            }
+            EriShipType::MotorFreighterWithTanker => write!(f, "motor freighter with tanker"),
+            EriShipType::MotorFreighterPushingFreighters => {
+                write!(f, "motor freighter pushing one or more freighters")
+            }
+            EriShipType::MotorFreighterPushingTanker => {
+                write!(f, "motor freighter pushing at least one tank-ship")
+            }
+            EriShipType::TugFreighter => write!(f, "tug, freighter"),
+            EriShipType::TugTanker => write!(f, "tug, tanker"),
+            EriShipType::TugFreighterCoupled => write!(f, "tug freighter, coupled"),
+            EriShipType::TugFreighterTankerCoupled => write!(f, "tug, freighter/tanker, coupled"),
+            EriShipType::FreightBarge => write!(f, "freightbarge"),
+            EriShipType::TankBarge => write!(f, "tankbarge"),
+            EriShipType::TankBargeLiquidN => write!(f, "tankbarge, liquid cargo, type N"),
+            EriShipType::TankBargeLiquidC => write!(f, "tankbarge, liquid cargo, type C"),
+            EriShipType::TankBargeDryCargo => write!(f, "tankbarge, dry cargo as if liquid"),
+            EriShipType::FreightBargeContainers => write!(f, "freightbarge with containers"),
+            EriShipType::TankBargeGas => write!(f, "tankbarge, gas"),
+            EriShipType::PushtowOneCargoBarge => write!(f, "pushtow, one cargo barge"),
+            EriShipType::PushtowTwoCargoBarges => write!(f, "pushtow, two cargo barges"),
+            EriShipType::PushtowThreeCargoBarges => write!(f, "pushtow, three cargo barges"),
+            EriShipType::PushtowFourCargoBarges => write!(f, "pushtow, four cargo barges"),
+            EriShipType::PushtowFiveCargoBarges => write!(f, "pushtow, five cargo barges"),
+            EriShipType::PushtowSixCargoBarges => write!(f, "pushtow, six cargo barges"),
+            EriShipType::PushtowSevenCargoBarges => write!(f, "pushtow, seven cargo barges"),
+            EriShipType::PushtowEightCargoBarges => write!(f, "pushtow, eight cargo barges"),
+            EriShipType::PushtowNineOrMoreBarges => write!(f, "pushtow, nine or more barges"),
+            EriShipType::PushtowOneTankBarge => write!(f, "pushtow, one tank/gas barge"),
+            EriShipType::PushtowTwoBargesTanker => {
+                write!(f, "pushtow, two barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowThreeBargesTanker => {
+                write!(f, "pushtow, three barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowFourBargesTanker => {
+                write!(f, "pushtow, four barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowFiveBargesTanker => {
+                write!(f, "pushtow, five barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowSixBargesTanker => {
+                write!(f, "pushtow, six barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowSevenBargesTanker => {
+                write!(f, "pushtow, seven barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowEightBargesTanker => {
+                write!(f, "pushtow, eight barges at least one tanker or gas barge")
+            }
+            EriShipType::PushtowNineOrMoreBargesTanker => write!(
+                f,
+                "pushtow, nine or more barges at least one tanker or gas barge"
+            ),
+            EriShipType::Tug => write!(f, "tug, single"),
+            EriShipType::TugWithTows => write!(f, "tug, one or more tows"),
+            EriShipType::TugAssisting => write!(f, "tug, assisting a vessel or linked combination"),
+            EriShipType::Pushboat => write!(f, "pushboat, single"),
+            EriShipType::PassengerShip => {
+                write!(f, "passenger ship, ferry, cruise ship, red cross ship")
+            }
+            EriShipType::Ferry => write!(f, "ferry"),
+            EriShipType::RedCrossShip => write!(f, "red cross ship"),
+            EriShipType::CruiseShip => write!(f, "cruise ship"),
+            EriShipType::PassengerShipWithoutAccommodation => {
+                write!(f, "passenger ship without accommodation")
+            }
+            EriShipType::ServiceVessel => write!(f, "service vessel, police patrol, port service"),
+            EriShipType::WorkCraft => write!(
+                f,
+                "vessel, work maintenance craft, floating derrick, cable-ship, buoy-ship, dredge"
+            ),
+            EriShipType::TowedObject => write!(f, "object, towed, not otherwise specified"),
+            EriShipType::FishingBoat => write!(f, "fishing boat"),
+            EriShipType::Bunkership => write!(f, "bunkership"),
+            EriShipType::ChemicalTankerBarge => write!(f, "barge, tanker, chemical"),
+            EriShipType::Object => write!(f, "object, not otherwise specified"),
+            EriShipType::MaritimeGeneralCargo => write!(f, "general cargo vessel maritime"),
+            EriShipType::MaritimeUnitCarrier => write!(f, "unit carrier maritime"),
+            EriShipType::MaritimeBulkCarrier => write!(f, "bulk carrier maritime"),
+            EriShipType::MaritimeTanker => write!(f, "tanker"),
+            EriShipType::MaritimeGasTanker => write!(f, "liquefied gas tanker"),
+            EriShipType::PleasureCraft => write!(f, "pleasure craft, longer than 20 metres"),
+            EriShipType::FastShip => write!(f, "fast ship"),
+            EriShipType::Hydrofoil => write!(f, "hydrofoil"),
+            EriShipType::FastCatamaran => write!(f, "catamaran fast"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode inland ship static and voyage related data starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> InlandStaticVoyageData {
+    let eri_ship_type = EriShipType::new(pick_u64(bv, index + 71, 14) as u16);
+    InlandStaticVoyageData {
+        eni: {
+            let raw = pick_string(bv, index, 8);
+            match raw.as_str() {
+                "" | "00000000" => None,
+                _ => Some(raw),
+            }
+        },
+        length_meters: pick_u64_unless_na(bv, index + 48, 13, 0).map(|v| v as f64 * 0.1),
+        beam_meters: pick_u64_unless_na(bv, index + 61, 10, 0).map(|v| v as f64 * 0.1),
+        eri_ship_type,
+        ship_type: eri_ship_type.ship_type(),
+        hazardous_cargo: HazardousCargo::new(pick_u64(bv, index + 85, 3) as u8),
+        draught_meters: pick_u64_unless_na(bv, index + 88, 11, 0).map(|v| v as f64 * 0.01),
+        loaded: match pick_u64(bv, index + 99, 2) {
+            1 => Some(true),
+            2 => Some(false),
+            _ => None,
+        },
+        high_speed_quality: pick_u64(bv, index + 101, 1) != 0,
+        high_course_quality: pick_u64(bv, index + 102, 1) != 0,
+        high_heading_quality: pick_u64(bv, index + 103, 1) != 0,
+    }
+}
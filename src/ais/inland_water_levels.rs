@@ -0,0 +1,72 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// Inland AIS water levels are broadcast with DAC 200 FID 24 (message type 8).
+
+// -------------------------------------------------------------------------------------------------
+
+/// Inland AIS water levels
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InlandWaterLevels {
+    /// UN country code (2 characters)
+    pub country_code: String,
+
+    /// Water levels of gauges (max 4)
+    pub gauges: Vec<GaugeWaterLevel>,
+}
+
+/// Water level reported by a single gauge
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GaugeWaterLevel {
+    /// National gauge ID (11 bits)
+    pub gauge_id: u16,
+
+    /// Water level in centimeters relative to the gauge datum (14 bits)
+    pub water_level_cm: Option<i16>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decode inland water levels starting at bit `index`.
+pub(crate) fn decode(bv: &BitVec, index: usize) -> InlandWaterLevels {
+    let mut gauges = Vec::with_capacity(4);
+    for i in 0..4 {
+        let start = index + 12 + 25 * i;
+        let gauge_id = pick_u64(bv, start, 11) as u16;
+        if gauge_id == 0 {
+            continue;
+        }
+        // Sign bit 1 means positive and 0 negative
+        let positive = pick_u64(bv, start + 11, 1) != 0;
+        let level = pick_u64(bv, start + 12, 13) as i16;
+        gauges.push(GaugeWaterLevel {
+            gauge_id,
+            water_level_cm: match level {
+                0 => None,
+                _ if positive => Some(level),
+                _ => Some(-level),
+            },
+        });
+    }
+    InlandWaterLevels {
+        country_code: pick_string(bv, index, 2),
+        gauges,
+    }
+}
@@ -20,6 +20,8 @@ pub(crate) mod vdm_t1t2t3;
 pub(crate) mod vdm_t4;
 pub(crate) mod vdm_t5;
 pub(crate) mod vdm_t6;
+pub(crate) mod vdm_t7;
+pub(crate) mod vdm_t8;
 pub(crate) mod vdm_t9;
 pub(crate) mod vdm_t10;
 pub(crate) mod vdm_t11;
@@ -39,10 +41,26 @@ pub(crate) mod vdm_t24;
 pub(crate) mod vdm_t25;
 pub(crate) mod vdm_t26;
 pub(crate) mod vdm_t27;
+pub(crate) mod imo289_area_notice;
+pub(crate) mod imo289_dangerous_cargo;
+pub(crate) mod imo289_met_hydro;
+pub(crate) mod imo289_persons_on_board;
+pub(crate) mod imo289_route;
+pub(crate) mod imo289_text;
+pub(crate) mod imo289_tidal_window;
+pub(crate) mod inland_eta;
+pub(crate) mod inland_persons_on_board;
+pub(crate) mod inland_static_voyage;
+pub(crate) mod inland_water_levels;
+pub(crate) mod vdm_encoder;
 
 use super::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 pub use vdm_t4::BaseStationReport;
-pub use vdm_t6::BinaryAddressedMessage;
+pub use vdm_t6::{AddressedApplication, BinaryAddressedMessage};
+pub use vdm_t7::BinaryAcknowledge;
+pub use vdm_t8::{BinaryBroadcastMessage, BroadcastApplication};
 pub use vdm_t9::StandardSarAircraftPositionReport;
 pub use vdm_t10::UtcDateInquiry;
 pub use vdm_t12::AddressedSafetyRelatedMessage;
@@ -51,17 +69,46 @@ pub use vdm_t14::SafetyRelatedBroadcastMessage;
 pub use vdm_t15::{Interrogation, InterrogationCase};
 pub use vdm_t16::AssignmentModeCommand;
 pub use vdm_t17::DgnssBroadcastBinaryMessage;
+pub use vdm_t19::ExtendedClassBPositionReport;
 pub use vdm_t20::{DataLinkManagementMessage};
 pub use vdm_t21::{AidToNavigationReport, NavAidType};
 pub use vdm_t22::{ChannelManagement};
 pub use vdm_t23::{GroupAssignmentCommand};
 pub use vdm_t25::{SingleSlotBinaryMessage};
 pub use vdm_t26::{MultipleSlotBinaryMessage};
+pub use imo289_area_notice::{AreaNotice, SubArea};
+pub use imo289_dangerous_cargo::{
+    CargoQuantityUnit, DangerousCargo, DangerousCargoCode, DangerousCargoIndication,
+};
+pub use imo289_met_hydro::{MeteorologicalHydrographicData, PrecipitationType, Tendency, WaterCurrent};
+pub use imo289_persons_on_board::PersonsOnBoard;
+pub use imo289_route::{RouteInformation, RouteType, Waypoint};
+pub use imo289_text::TextDescription;
+pub use imo289_tidal_window::{TidalWindow, TidalWindowEntry};
+pub use inland_eta::{InlandEta, InlandLocation, InlandRta, LockStatus};
+pub use inland_persons_on_board::InlandPersonsOnBoard;
+pub use inland_static_voyage::{EriShipType, HazardousCargo, InlandStaticVoyageData};
+pub use inland_water_levels::{GaugeWaterLevel, InlandWaterLevels};
+pub use vdm_encoder::VdmEncoder;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Encoding of AIS data structures to VDM/VDO payloads. Use `VdmEncoder` to turn the payloads
+/// into sentences.
+pub trait ToPayload {
+    /// Encode the data as one or more message payloads. Most structures produce a single payload,
+    /// class B static data produces type 24 parts A and B.
+    fn to_payloads(&self) -> Vec<BitVec>;
+
+    /// True if the data is about own vessel and should be encoded as VDO.
+    fn own_vessel(&self) -> bool;
+}
 
 // -------------------------------------------------------------------------------------------------
 
 /// AIS station based on talker id
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Station {
     BaseStation,             // !AB
     DependentAisBaseStation, // !AD
@@ -101,9 +148,10 @@ impl core::str::FromStr for Station {
 
     fn from_str(talker_id: &str) -> Result<Self, Self::Err> {
         if talker_id.len() < 2 {
-            return Err(ParseError::InvalidSentence(
-                "Invalid station identifier".to_string(),
-            ));
+            return Err(
+                ParseError::new(ParseErrorKind::BadTalker, "Invalid station identifier")
+                    .with_raw_value(talker_id),
+            );
         }
         match &talker_id[0..2] {
             "AB" => Ok(Self::BaseStation),
@@ -121,8 +169,9 @@ impl core::str::FromStr for Station {
 
 // -------------------------------------------------------------------------------------------------
 
-/// Types 1, 2, 3 and 18: Position Report Class A, and Long Range AIS Broadcast message
+/// Types 1, 2, 3, 18 and 19: Position Report Class A, and Long Range AIS Broadcast message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VesselDynamicData {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -226,6 +275,7 @@ pub struct VesselDynamicData {
 
 /// AIS class which is either Class A or Class B
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AisClass {
     /// AIS class not known.
     Unknown,
@@ -263,8 +313,23 @@ impl LatLon for VesselDynamicData {
     }
 }
 
+impl ToPayload for VesselDynamicData {
+    /// Encode class A data as type 1 payload and class B data as type 18 payload.
+    fn to_payloads(&self) -> Vec<BitVec> {
+        match self.ais_type {
+            AisClass::ClassB => vec![vdm_t18::encode(self)],
+            _ => vec![vdm_t1t2t3::encode(self)],
+        }
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
 /// Navigation status for VesselDynamicData
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NavigationStatus {
     UnderWayUsingEngine = 0,        // 0
     AtAnchor = 1,                   // 1
@@ -346,6 +411,7 @@ impl Default for NavigationStatus {
 
 /// Location metadata about positioning system
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PositioningSystemMeta {
     Operative, // When timestamp second is 0-59
     ManualInputMode,
@@ -368,6 +434,7 @@ impl core::fmt::Display for PositioningSystemMeta {
 
 /// Vessel rotation direction
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RotDirection {
     /// Turning port (left, when seen by an observer aboard the vessel looking forward)
     Port,
@@ -397,8 +464,9 @@ impl core::fmt::Display for RotDirection {
 
 // -------------------------------------------------------------------------------------------------
 
-/// Types 5 and 24: Ship static voyage related data, and boat static data report.
+/// Types 5, 19 and 24: Ship static voyage related data, and boat static data report.
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VesselStaticData {
     /// True if the data is about own vessel, false if about other vessel.
     pub own_vessel: bool,
@@ -449,6 +517,7 @@ pub struct VesselStaticData {
     pub position_fix_type: Option<PositionFixType>,
 
     /// ETA (20 bits)
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub eta: Option<DateTime<Utc>>,
 
     /// Maximum present static draught in decimetres (1-255; 8 bits)
@@ -465,6 +534,7 @@ pub struct VesselStaticData {
 
 /// Ship type derived from combined ship and cargo type field
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ShipType {
     NotAvailable = 0,             // 0
     Reserved1 = 10,               // 1x
@@ -589,6 +659,7 @@ impl core::fmt::Display for ShipType {
 
 /// Cargo type derived from combined ship and cargo type field
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CargoType {
     Undefined = 10,          // x0
     HazardousCategoryA = 11, // x1
@@ -655,6 +726,7 @@ impl Default for CargoType {
 
 /// EPFD position fix types
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PositionFixType {
     Undefined = 0,                  // 0
     GPS = 1,                        // 1
@@ -1012,3 +1084,45 @@ impl VesselStaticData {
         }
     }
 }
+
+impl ToPayload for VesselStaticData {
+    /// Encode class A data as type 5 payload and class B data as type 24 part A and B payloads.
+    fn to_payloads(&self) -> Vec<BitVec> {
+        match self.ais_type {
+            AisClass::ClassB => vdm_t24::encode(self),
+            _ => vec![vdm_t5::encode(self)],
+        }
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+/// Combine ship and cargo types back to the ship and cargo type field of NMEA.
+pub(crate) fn ship_and_cargo_type(ship_type: ShipType, cargo_type: CargoType) -> u8 {
+    match ship_type {
+        ShipType::Reserved1
+        | ShipType::WingInGround
+        | ShipType::HighSpeedCraft
+        | ShipType::Passenger
+        | ShipType::Cargo
+        | ShipType::Tanker
+        | ShipType::Other => ship_type.to_value() + cargo_type.to_value() - 10,
+        _ => ship_type.to_value(),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Return the decoded application data of a binary message, or `None` if it can't be decoded so
+/// that a malformed application doesn't fail the whole message.
+pub(crate) fn application_or_none<T>(result: Result<T, ParseError>) -> Option<T> {
+    match result {
+        Ok(application) => Some(application),
+        Err(e) => {
+            warn!("Ignoring malformed application data: {}", e);
+            None
+        }
+    }
+}
@@ -0,0 +1,175 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Encoder producing AIS VDM/VDO sentences from AIS data structures. Payloads which don't fit in
+/// a single sentence are split into fragments sharing a sequential message ID.
+#[derive(Clone, Debug)]
+pub struct VdmEncoder {
+    talker_id: String,
+    radio_channel: String,
+    max_payload_length: usize,
+    message_id: u8,
+}
+
+impl Default for VdmEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VdmEncoder {
+    /// Construct an encoder with talker ID "AI", radio channel "A" and maximum payload length of
+    /// 60 characters per sentence.
+    pub fn new() -> VdmEncoder {
+        VdmEncoder {
+            talker_id: "AI".into(),
+            radio_channel: "A".into(),
+            max_payload_length: 60,
+            message_id: 0,
+        }
+    }
+
+    /// Set the talker ID of the encoded sentences (e.g. "AB" for base stations).
+    pub fn set_talker_id(&mut self, talker_id: &str) {
+        self.talker_id = talker_id.into();
+    }
+
+    /// Set the radio channel of the encoded sentences ("A" or "B"). Empty string leaves the field
+    /// empty.
+    pub fn set_radio_channel(&mut self, radio_channel: &str) {
+        self.radio_channel = radio_channel.into();
+    }
+
+    /// Set the maximum number of payload characters per sentence. The default of 60 characters
+    /// keeps the sentences within the 82 character limit of NMEA 0183.
+    pub fn set_max_payload_length(&mut self, max_payload_length: usize) {
+        self.max_payload_length = max(1, max_payload_length);
+    }
+
+    /// Encode the given data structure as VDM/VDO sentences. Data about own vessel is encoded
+    /// as VDO and other data as VDM.
+    pub fn encode<T: ToPayload>(&mut self, data: &T) -> Vec<String> {
+        let own_vessel = data.own_vessel();
+        data.to_payloads()
+            .iter()
+            .flat_map(|payload| self.encode_payload(payload, own_vessel))
+            .collect()
+    }
+
+    /// Encode the given raw message payload as VDM/VDO sentences.
+    pub fn encode_payload(&mut self, payload: &BitVec, own_vessel: bool) -> Vec<String> {
+        let (armored, fill_bits) = make_payload(payload);
+        let chars: Vec<char> = armored.chars().collect();
+        let chunks: Vec<String> = if chars.is_empty() {
+            vec![String::new()]
+        } else {
+            chars
+                .chunks(self.max_payload_length)
+                .map(|chunk| chunk.iter().collect())
+                .collect()
+        };
+
+        let message_id = if chunks.len() > 1 {
+            let id = self.message_id;
+            self.message_id = (self.message_id + 1) % 10;
+            format!("{}", id)
+        } else {
+            String::new()
+        };
+        let sentence_type = if own_vessel { "VDO" } else { "VDM" };
+
+        let fragment_count = chunks.len();
+        chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                make_sentence(
+                    '!',
+                    &format!(
+                        "{}{},{},{},{},{},{},{}",
+                        self.talker_id,
+                        sentence_type,
+                        fragment_count,
+                        i + 1,
+                        message_id,
+                        self.radio_channel,
+                        chunk,
+                        if i + 1 == fragment_count {
+                            fill_bits
+                        } else {
+                            0
+                        }
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_fragments() {
+        let mut bv = BitVec::new();
+        push_u64(&mut bv, 14, 6);
+        push_u64(&mut bv, 0, 2);
+        push_u64(&mut bv, 351809000, 30);
+        push_u64(&mut bv, 0, 2);
+        push_string(&mut bv, "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG", 43);
+
+        let mut e = VdmEncoder::new();
+        e.set_max_payload_length(20);
+        let sentences = e.encode_payload(&bv, false);
+        assert_eq!(sentences.len(), 3);
+        assert!(sentences[0].starts_with("!AIVDM,3,1,0,A,"));
+        assert!(sentences[1].starts_with("!AIVDM,3,2,0,A,"));
+        assert!(sentences[2].starts_with("!AIVDM,3,3,0,A,"));
+        assert!(sentences[0].contains(",0*"));
+        assert!(sentences[1].contains(",0*"));
+        assert!(sentences[2].contains(",2*"));
+
+        // Message ID is sequential for multi-fragment messages and empty for single ones
+        e.set_radio_channel("B");
+        assert!(e.encode_payload(&bv, true)[0].starts_with("!AIVDO,3,1,1,B,"));
+        e.set_max_payload_length(60);
+        assert!(e.encode_payload(&bv, false)[0].starts_with("!AIVDM,1,1,,B,"));
+
+        // Sentences parse back to the original message
+        let mut p = NmeaParser::new();
+        e.set_max_payload_length(20);
+        let mut result = None;
+        for s in e.encode_payload(&bv, false) {
+            result = Some(p.parse_sentence(&s));
+        }
+        match result {
+            Some(Ok(ParsedMessage::SafetyRelatedBroadcastMessage(srbm))) => {
+                assert_eq!(srbm.mmsi, 351809000);
+                assert_eq!(srbm.text, "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG");
+            }
+            Some(Ok(ps)) => panic!("Unexpected message: {:?}", ps),
+            Some(Err(e)) => panic!("Unexpected error: {}", e),
+            None => panic!("No sentences"),
+        }
+    }
+}
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 10: UTC/Date Inquiry
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UtcDateInquiry {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 12: Addressed Safety-Related Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AddressedSafetyRelatedMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -66,6 +67,28 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToPayload for AddressedSafetyRelatedMessage {
+    fn to_payloads(&self) -> Vec<BitVec> {
+        let text_len = min(self.text.chars().count(), 156);
+        let mut bv = BitVec::with_capacity(72 + 6 * text_len);
+        push_u64(&mut bv, 12, 6);
+        push_u64(&mut bv, 0, 2);
+        push_u64(&mut bv, self.source_mmsi as u64, 30);
+        push_u64(&mut bv, self.sequence_number as u64, 2);
+        push_u64(&mut bv, self.destination_mmsi as u64, 30);
+        push_u64(&mut bv, self.retransmit_flag as u64, 1);
+        push_u64(&mut bv, 0, 1);
+        push_string(&mut bv, &self.text, text_len);
+        vec![bv]
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -127,4 +150,35 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type12() {
+        let mut p = NmeaParser::new();
+        let asrm = match p.parse_sentence(
+            "!AIVDM,1,1,,A,<02:oP0kKcv0@<51C5PB5@?BDPD?P:?2?EB7PDB16693P381>>5<PikP,0*37",
+        ) {
+            Ok(ParsedMessage::AddressedSafetyRelatedMessage(asrm)) => asrm,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&asrm);
+        assert_eq!(
+            sentences,
+            vec!["!AIVDM,1,1,,A,<02:oP0kKcv0@<51C5PB5@?BDPD?P:?2?EB7PDB16693P381>>5<Pik,0*67"]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(
+            result,
+            Ok(ParsedMessage::AddressedSafetyRelatedMessage(asrm))
+        );
+    }
 }
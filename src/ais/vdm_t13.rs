@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 13: Safety-Related Acknowledgment
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SafetyRelatedAcknowledgement {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -63,21 +64,68 @@ pub(crate) fn handle(
     station: Station,
     own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
-    Ok(ParsedMessage::SafetyRelatedAcknowledgement(
-        SafetyRelatedAcknowledgement {
-            own_vessel: { own_vessel },
-            station: { station },
-            mmsi: { pick_u64(bv, 8, 30) as u32 },
-            mmsi1: { pick_u64(bv, 40, 30) as u32 },
-            mmsi1_seq: { pick_u64(bv, 70, 2) as u8 },
-            mmsi2: { pick_u64(bv, 72, 30) as u32 },
-            mmsi2_seq: { pick_u64(bv, 102, 2) as u8 },
-            mmsi3: { pick_u64(bv, 104, 30) as u32 },
-            mmsi3_seq: { pick_u64(bv, 134, 2) as u8 },
-            mmsi4: { pick_u64(bv, 136, 30) as u32 },
-            mmsi4_seq: { pick_u64(bv, 166, 2) as u8 },
-        },
-    ))
+    Ok(ParsedMessage::SafetyRelatedAcknowledgement(decode(
+        bv, station, own_vessel,
+    )))
+}
+
+/// Decode the acknowledgement of AIS VDM/VDO type 7 or 13.
+pub(crate) fn decode(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+) -> SafetyRelatedAcknowledgement {
+    SafetyRelatedAcknowledgement {
+        own_vessel: { own_vessel },
+        station: { station },
+        mmsi: { pick_u64(bv, 8, 30) as u32 },
+        mmsi1: { pick_u64(bv, 40, 30) as u32 },
+        mmsi1_seq: { pick_u64(bv, 70, 2) as u8 },
+        mmsi2: { pick_u64(bv, 72, 30) as u32 },
+        mmsi2_seq: { pick_u64(bv, 102, 2) as u8 },
+        mmsi3: { pick_u64(bv, 104, 30) as u32 },
+        mmsi3_seq: { pick_u64(bv, 134, 2) as u8 },
+        mmsi4: { pick_u64(bv, 136, 30) as u32 },
+        mmsi4_seq: { pick_u64(bv, 166, 2) as u8 },
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToPayload for SafetyRelatedAcknowledgement {
+    /// Encode the acknowledgement as type 13 payload. Trailing zero MMSI numbers are omitted.
+    fn to_payloads(&self) -> Vec<BitVec> {
+        vec![encode(self, 13)]
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+/// Encode the acknowledgement as payload of the given message type (7 or 13). Trailing zero MMSI
+/// numbers are omitted.
+pub(crate) fn encode(ack: &SafetyRelatedAcknowledgement, message_type: u64) -> BitVec {
+    let acks = [
+        (ack.mmsi1, ack.mmsi1_seq),
+        (ack.mmsi2, ack.mmsi2_seq),
+        (ack.mmsi3, ack.mmsi3_seq),
+        (ack.mmsi4, ack.mmsi4_seq),
+    ];
+    let ack_count = max(
+        1,
+        acks.iter().rposition(|(mmsi, _)| *mmsi != 0).unwrap_or(0) + 1,
+    );
+    let mut bv = BitVec::with_capacity(40 + 32 * ack_count);
+    push_u64(&mut bv, message_type, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, ack.mmsi as u64, 30);
+    push_u64(&mut bv, 0, 2);
+    for (mmsi, seq) in acks.iter().take(ack_count) {
+        push_u64(&mut bv, *mmsi as u64, 30);
+        push_u64(&mut bv, *seq as u64, 2);
+    }
+    bv
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -117,4 +165,27 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type13() {
+        let mut p = NmeaParser::new();
+        let sra = match p.parse_sentence("!AIVDM,1,1,,A,=39UOj0jFs9R,0*65") {
+            Ok(ParsedMessage::SafetyRelatedAcknowledgement(sra)) => sra,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&sra);
+        assert_eq!(sentences, vec!["!AIVDM,1,1,,A,=39UOj0jFs9R,0*65"]);
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::SafetyRelatedAcknowledgement(sra)));
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 14: Safety-Related Broadcast Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SafetyRelatedBroadcastMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -54,6 +55,25 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToPayload for SafetyRelatedBroadcastMessage {
+    fn to_payloads(&self) -> Vec<BitVec> {
+        let text_len = min(self.text.chars().count(), 161);
+        let mut bv = BitVec::with_capacity(40 + 6 * text_len);
+        push_u64(&mut bv, 14, 6);
+        push_u64(&mut bv, 0, 2);
+        push_u64(&mut bv, self.mmsi as u64, 30);
+        push_u64(&mut bv, 0, 2);
+        push_string(&mut bv, &self.text, text_len);
+        vec![bv]
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -128,4 +148,33 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type14() {
+        let mut p = NmeaParser::new();
+        let srbm = match p.parse_sentence("!AIVDM,1,1,,A,>5?Per18=HB1U:1@E=B0m<L,2*51") {
+            Ok(ParsedMessage::SafetyRelatedBroadcastMessage(srbm)) => srbm,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&srbm);
+        assert_eq!(
+            sentences,
+            vec!["!AIVDM,1,1,,A,>5?Per18=HB1U:1@E=B0m<L,2*51"]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(
+            result,
+            Ok(ParsedMessage::SafetyRelatedBroadcastMessage(srbm))
+        );
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 15: Interrogation
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Interrogation {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -60,6 +61,7 @@ pub struct Interrogation {
 
 /// The four cases of interrogation, depending on data length mostly.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterrogationCase {
     /// One station is interrogated for one message type.
     Case1,
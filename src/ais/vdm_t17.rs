@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 17: DGNSS Broadcast Binary Message.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DgnssBroadcastBinaryMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -37,6 +38,7 @@ pub struct DgnssBroadcastBinaryMessage {
     pub longitude: Option<f64>,
 
     /// Payload (80-815 bits). Note that it appears to be tied to the now obsolete RTCM2 protocol.
+    #[cfg_attr(feature = "serde", serde(with = "json_bit_vec"))]
     pub payload: BitVec,
 }
 
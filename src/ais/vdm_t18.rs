@@ -68,14 +68,14 @@ pub(crate) fn handle(
             }
         },
         timestamp_seconds: pick_u64(bv, 133, 6) as u8,
-        class_b_unit_flag: { None },
-        class_b_display: Some(pick_u64(bv, 141, 1) != 0),
-        class_b_dsc: Some(pick_u64(bv, 142, 1) != 0),
-        class_b_band_flag: Some(pick_u64(bv, 143, 1) != 0),
-        class_b_msg22_flag: Some(pick_u64(bv, 144, 1) != 0),
-        class_b_mode_flag: Some(pick_u64(bv, 145, 1) != 0),
-        raim_flag: pick_u64(bv, 141, 1) != 0,
-        class_b_css_flag: { None },
+        class_b_unit_flag: Some(pick_u64(bv, 141, 1) != 0),
+        class_b_display: Some(pick_u64(bv, 142, 1) != 0),
+        class_b_dsc: Some(pick_u64(bv, 143, 1) != 0),
+        class_b_band_flag: Some(pick_u64(bv, 144, 1) != 0),
+        class_b_msg22_flag: Some(pick_u64(bv, 145, 1) != 0),
+        class_b_mode_flag: Some(pick_u64(bv, 146, 1) != 0),
+        raim_flag: pick_u64(bv, 147, 1) != 0,
+        class_b_css_flag: Some(pick_u64(bv, 148, 1) != 0),
         radio_status: Some(pick_u64(bv, 149, 19) as u32),
         nav_status: NavigationStatus::NotDefined,
         rot: None,
@@ -86,10 +86,82 @@ pub(crate) fn handle(
     }))
 }
 
+// -------------------------------------------------------------------------------------------------
+
+/// Encode vessel dynamic data as AIS VDM/VDO type 18 payload.
+pub(crate) fn encode(vdd: &VesselDynamicData) -> BitVec {
+    let mut bv = BitVec::with_capacity(168);
+    push_u64(&mut bv, 18, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vdd.mmsi as u64, 30);
+    push_u64(&mut bv, 0, 8);
+    push_u64(
+        &mut bv,
+        vdd.sog_knots
+            .map(|sog| min((sog * 10.0).round() as u64, 1022))
+            .unwrap_or(1023),
+        10,
+    );
+    push_u64(&mut bv, vdd.high_position_accuracy as u64, 1);
+    push_longitude(&mut bv, vdd.longitude, 28, 600000);
+    push_latitude(&mut bv, vdd.latitude, 27, 600000);
+    push_u64(
+        &mut bv,
+        vdd.cog
+            .map(|cog| min((cog * 10.0).round() as u64, 3599))
+            .unwrap_or(0xE10),
+        12,
+    );
+    push_u64(
+        &mut bv,
+        vdd.heading_true
+            .map(|heading| min(heading.round() as u64, 359))
+            .unwrap_or(511),
+        9,
+    );
+    push_u64(&mut bv, vdd.timestamp_seconds as u64, 6);
+    push_u64(&mut bv, 0, 2);
+    for flag in [
+        vdd.class_b_unit_flag,
+        vdd.class_b_display,
+        vdd.class_b_dsc,
+        vdd.class_b_band_flag,
+        vdd.class_b_msg22_flag,
+        vdd.class_b_mode_flag,
+        Some(vdd.raim_flag),
+        vdd.class_b_css_flag,
+    ] {
+        push_u64(&mut bv, flag.unwrap_or(false) as u64, 1);
+    }
+    push_u64(&mut bv, vdd.radio_status.unwrap_or(0) as u64, 19);
+    bv
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_parse_vdm_type18_flags() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*4C") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => {
+                assert_eq!(vdd.class_b_unit_flag, Some(true));
+                assert_eq!(vdd.class_b_display, Some(false));
+                assert_eq!(vdd.class_b_dsc, Some(true));
+                assert_eq!(vdd.class_b_band_flag, Some(true));
+                assert_eq!(vdd.class_b_msg22_flag, Some(true));
+                assert_eq!(vdd.class_b_mode_flag, Some(false));
+                assert!(vdd.raim_flag);
+                assert_eq!(vdd.class_b_css_flag, Some(true));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
     #[test]
     fn test_parse_vdm_type18() {
         let mut p = NmeaParser::new();
@@ -126,4 +198,30 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type18() {
+        let mut p = NmeaParser::new();
+        let vdd = match p.parse_sentence("!AIVDM,1,1,,A,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*4C") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => vdd,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&vdd);
+        assert_eq!(
+            sentences,
+            vec!["!AIVDM,1,1,,A,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*4C"]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::VesselDynamicData(vdd)));
+    }
 }
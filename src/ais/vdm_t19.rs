@@ -15,24 +15,183 @@ limitations under the License.
 */
 use super::*;
 
+// -------------------------------------------------------------------------------------------------
+
+/// Type 19: Extended Class B Equipment Position Report. The message carries both dynamic and
+/// static data of the vessel.
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtendedClassBPositionReport {
+    /// Kinematic fields of the report
+    pub dynamic_data: VesselDynamicData,
+
+    /// Name, ship type, dimensions and EPFD fields of the report
+    pub static_data: VesselStaticData,
+}
+
+impl LatLon for ExtendedClassBPositionReport {
+    fn latitude(&self) -> Option<f64> {
+        self.dynamic_data.latitude
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        self.dynamic_data.longitude
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// AIS VDM/VDO type 19: Extended Class B Equipment Position Report
 pub(crate) fn handle(
-    _bv: &BitVec,
-    _station: Station,
-    _own_vessel: bool,
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
 ) -> Result<ParsedMessage, ParseError> {
-    // TODO: implementation (Class B)
-    Err(ParseError::UnsupportedSentenceType(
-        "Unsupported AIVDM message type: 19".into(),
+    Ok(ParsedMessage::ExtendedClassBPositionReport(
+        ExtendedClassBPositionReport {
+            dynamic_data: VesselDynamicData {
+                own_vessel: { own_vessel },
+                station: { station },
+                ais_type: { AisClass::ClassB },
+                mmsi: { pick_u64(bv, 8, 30) as u32 },
+                sog_knots: {
+                    let raw = pick_u64(bv, 46, 10);
+                    if raw < 1023 {
+                        Some((raw as f64) * 0.1)
+                    } else {
+                        None
+                    }
+                },
+                high_position_accuracy: pick_u64(bv, 56, 1) != 0,
+                longitude: {
+                    let lon_raw = pick_i64(bv, 57, 28) as i32;
+                    if lon_raw != 0x6791AC0 {
+                        Some((lon_raw as f64) / 600000.0)
+                    } else {
+                        None
+                    }
+                },
+                latitude: {
+                    let lat_raw = pick_i64(bv, 85, 27) as i32;
+                    if lat_raw != 0x3412140 {
+                        Some((lat_raw as f64) / 600000.0)
+                    } else {
+                        None
+                    }
+                },
+                cog: {
+                    let cog_raw = pick_u64(bv, 112, 12);
+                    if cog_raw != 0xE10 {
+                        Some(cog_raw as f64 * 0.1)
+                    } else {
+                        None
+                    }
+                },
+                heading_true: {
+                    let th_raw = pick_u64(bv, 124, 9);
+                    if th_raw != 511 {
+                        Some(th_raw as f64)
+                    } else {
+                        None
+                    }
+                },
+                timestamp_seconds: pick_u64(bv, 133, 6) as u8,
+                class_b_unit_flag: { None },
+                class_b_display: { None },
+                class_b_dsc: { None },
+                class_b_band_flag: { None },
+                class_b_msg22_flag: { None },
+                class_b_mode_flag: Some(pick_u64(bv, 307, 1) != 0),
+                raim_flag: pick_u64(bv, 305, 1) != 0,
+                class_b_css_flag: { None },
+                radio_status: { None },
+                nav_status: NavigationStatus::NotDefined,
+                rot: None,
+                rot_direction: None,
+                positioning_system_meta: None,
+                current_gnss_position: None,
+                special_manoeuvre: None,
+            },
+            static_data: VesselStaticData {
+                own_vessel,
+                ais_type: AisClass::ClassB,
+                mmsi: pick_u64(bv, 8, 30) as u32,
+                ais_version_indicator: 0,
+                imo_number: None,
+                call_sign: None,
+                name: {
+                    let raw = pick_string(bv, 143, 20);
+                    match raw.as_str() {
+                        "" => None,
+                        _ => Some(raw),
+                    }
+                },
+                ship_type: ShipType::new(pick_u64(bv, 263, 8) as u8),
+                cargo_type: CargoType::new(pick_u64(bv, 263, 8) as u8),
+                equipment_vendor_id: None,
+                equipment_model: None,
+                equipment_serial_number: None,
+                dimension_to_bow: Some(pick_u64(bv, 271, 9) as u16),
+                dimension_to_stern: Some(pick_u64(bv, 280, 9) as u16),
+                dimension_to_port: Some(pick_u64(bv, 289, 6) as u16),
+                dimension_to_starboard: Some(pick_u64(bv, 295, 6) as u16),
+                position_fix_type: {
+                    let raw = pick_u64(bv, 301, 4) as u8;
+                    match raw {
+                        0 => None,
+                        _ => Some(PositionFixType::new(raw)),
+                    }
+                },
+                eta: None,
+                draught10: None,
+                destination: None,
+                mothership_mmsi: None,
+            },
+        },
     ))
 }
 
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
-    //    use super::*;
+    use super::*;
+
+    #[test]
+    fn test_parse_vdm_type19() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,1,1,,B,C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220,0*0B",
+        ) {
+            Ok(ParsedMessage::ExtendedClassBPositionReport(report)) => {
+                let vdd = &report.dynamic_data;
+                assert_eq!(vdd.mmsi, 367059850);
+                assert_eq!(vdd.ais_type, AisClass::ClassB);
+                assert::close(vdd.sog_knots.unwrap_or(0.0), 8.7, 0.01);
+                assert!(!vdd.high_position_accuracy);
+                assert::close(vdd.latitude.unwrap_or(0.0), 29.543695, 0.00001);
+                assert::close(vdd.longitude.unwrap_or(0.0), -88.810392, 0.00001);
+                assert::close(vdd.cog.unwrap_or(0.0), 335.9, 0.01);
+                assert_eq!(vdd.heading_true, None);
+                assert_eq!(vdd.timestamp_seconds, 46);
+                assert!(!vdd.raim_flag);
+                assert_eq!(vdd.class_b_mode_flag, Some(false));
+
+                let vsd = &report.static_data;
+                assert_eq!(vsd.mmsi, 367059850);
+                assert_eq!(vsd.name, Some("CAPT.J.RIMES".into()));
+                assert_eq!(vsd.ship_type, ShipType::Cargo);
+                assert_eq!(vsd.cargo_type, CargoType::Undefined);
+                assert_eq!(vsd.dimension_to_bow, Some(5));
+                assert_eq!(vsd.dimension_to_stern, Some(21));
+                assert_eq!(vsd.dimension_to_port, Some(4));
+                assert_eq!(vsd.dimension_to_starboard, Some(4));
+                assert_eq!(vsd.position_fix_type, Some(PositionFixType::GPS));
 
-    //    #[test]
-    //    fn test_parse_vdm_type19() {
-    //        assert!(false);
-    //    }
+                assert_eq!(report.latitude(), vdd.latitude);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
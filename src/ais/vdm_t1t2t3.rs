@@ -132,6 +132,75 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+/// Encode vessel dynamic data as AIS VDM/VDO type 1 payload.
+pub(crate) fn encode(vdd: &VesselDynamicData) -> BitVec {
+    let mut bv = BitVec::with_capacity(168);
+    push_u64(&mut bv, 1, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vdd.mmsi as u64, 30);
+    push_u64(&mut bv, vdd.nav_status.to_value() as u64, 4);
+    push_i64(
+        &mut bv,
+        match (vdd.rot, vdd.rot_direction) {
+            (Some(rot), _) => {
+                let raw = min(
+                    (num_traits::Float::sqrt(rot.abs()) * 4.733 * 126.0 / 708.0).round() as i64,
+                    126,
+                );
+                if rot < 0.0 {
+                    -raw
+                } else {
+                    raw
+                }
+            }
+            (None, Some(RotDirection::Port)) => -127,
+            (None, Some(RotDirection::Starboard)) => 127,
+            (None, _) => -128,
+        },
+        8,
+    );
+    push_u64(
+        &mut bv,
+        vdd.sog_knots
+            .map(|sog| min((sog * 10.0).round() as u64, 1022))
+            .unwrap_or(1023),
+        10,
+    );
+    push_u64(&mut bv, vdd.high_position_accuracy as u64, 1);
+    push_longitude(&mut bv, vdd.longitude, 28, 600000);
+    push_latitude(&mut bv, vdd.latitude, 27, 600000);
+    push_u64(
+        &mut bv,
+        vdd.cog
+            .map(|cog| min((cog * 10.0).round() as u64, 3599))
+            .unwrap_or(0xE10),
+        12,
+    );
+    push_u64(
+        &mut bv,
+        vdd.heading_true
+            .map(|heading| min(heading.round() as u64, 359))
+            .unwrap_or(511),
+        9,
+    );
+    push_u64(&mut bv, vdd.timestamp_seconds as u64, 6);
+    push_u64(
+        &mut bv,
+        match vdd.special_manoeuvre {
+            Some(true) => 2,
+            Some(false) => 1,
+            None => 0,
+        },
+        2,
+    );
+    push_u64(&mut bv, 0, 3);
+    push_u64(&mut bv, vdd.raim_flag as u64, 1);
+    push_u64(&mut bv, vdd.radio_status.unwrap_or(0) as u64, 19);
+    bv
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -255,4 +324,30 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type1() {
+        let mut p = NmeaParser::new();
+        let vdd = match p.parse_sentence("!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A") {
+            Ok(ParsedMessage::VesselDynamicData(vdd)) => vdd,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&vdd);
+        assert_eq!(
+            sentences,
+            vec!["!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A"]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::VesselDynamicData(vdd)));
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 20: Data Link Management Message
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataLinkManagementMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 21: Aid-to-Navigation Report
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AidToNavigationReport {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -93,6 +94,7 @@ impl LatLon for AidToNavigationReport {
 
 /// Type of navigation aid
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NavAidType {
     /// Default, type not specified
     NotSpecified, // 0
@@ -226,9 +228,18 @@ impl NavAidType {
             29 => Ok(NavAidType::SafeWater),
             30 => Ok(NavAidType::SpecialMark),
             31 => Ok(NavAidType::LightVessel),
-            _ => Err(format!("Unrecognized Nav aid type code: {}", raw).into()),
+            _ => Err(ParseError::new(
+                ParseErrorKind::InvalidField,
+                format!("Unrecognized Nav aid type code: {}", raw),
+            )
+            .with_bit(38, "aid_type")
+            .with_raw_value(&raw.to_string())),
         }
     }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
 }
 
 impl Default for NavAidType {
@@ -326,7 +337,7 @@ pub(crate) fn handle(
             dimension_to_starboard: { Some(pick_u64(bv, 243, 6) as u16) },
             position_fix_type: { Some(PositionFixType::new(pick_u64(bv, 249, 4) as u8)) },
             timestamp_seconds: { pick_u64(bv, 253, 6) as u8 },
-            off_position_indicator: { pick_u64(bv, 243, 1) != 0 },
+            off_position_indicator: { pick_u64(bv, 259, 1) != 0 },
             regional: { pick_u64(bv, 260, 8) as u8 },
             raim_flag: { pick_u64(bv, 268, 1) != 0 },
             virtual_aid_flag: { pick_u64(bv, 269, 1) != 0 },
@@ -337,10 +348,69 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToPayload for AidToNavigationReport {
+    /// Encode the report as type 21 payload. Name characters beyond 20 are encoded into the name
+    /// extension field.
+    fn to_payloads(&self) -> Vec<BitVec> {
+        let name: Vec<char> = self.name.chars().collect();
+        let name_extension: String = name.iter().skip(20).take(14).collect();
+        let mut bv = BitVec::with_capacity(272 + 6 * name_extension.len());
+        push_u64(&mut bv, 21, 6);
+        push_u64(&mut bv, 0, 2);
+        push_u64(&mut bv, self.mmsi as u64, 30);
+        push_u64(&mut bv, self.aid_type.to_value() as u64, 5);
+        push_string(&mut bv, &self.name, 20);
+        push_u64(&mut bv, self.high_position_accuracy as u64, 1);
+        push_longitude(&mut bv, self.longitude, 28, 600000);
+        push_latitude(&mut bv, self.latitude, 27, 600000);
+        push_u64(&mut bv, self.dimension_to_bow.unwrap_or(0) as u64, 9);
+        push_u64(&mut bv, self.dimension_to_stern.unwrap_or(0) as u64, 9);
+        push_u64(&mut bv, self.dimension_to_port.unwrap_or(0) as u64, 6);
+        push_u64(&mut bv, self.dimension_to_starboard.unwrap_or(0) as u64, 6);
+        push_u64(
+            &mut bv,
+            self.position_fix_type.map(|t| t.to_value()).unwrap_or(0) as u64,
+            4,
+        );
+        push_u64(&mut bv, self.timestamp_seconds as u64, 6);
+        push_u64(&mut bv, self.off_position_indicator as u64, 1);
+        push_u64(&mut bv, self.regional as u64, 8);
+        push_u64(&mut bv, self.raim_flag as u64, 1);
+        push_u64(&mut bv, self.virtual_aid_flag as u64, 1);
+        push_u64(&mut bv, self.assigned_mode_flag as u64, 1);
+        push_u64(&mut bv, 0, 1);
+        push_string(&mut bv, &name_extension, name_extension.len());
+        vec![bv]
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_parse_vdm_type21_off_position() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,E1mg=5J1T4W0h97aRh6ba84<h2d;W:Te=eLvH50```qJD44QDlp0C1DU00,2*25",
+        ) {
+            Ok(ParsedMessage::AidToNavigationReport(atnr)) => {
+                assert_eq!(atnr.dimension_to_starboard, Some(5));
+                assert_eq!(atnr.timestamp_seconds, 50);
+                assert!(atnr.off_position_indicator);
+                assert_eq!(atnr.regional, 165);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
     #[test]
     fn test_parse_vdm_type21() {
         let mut p = NmeaParser::new();
@@ -394,4 +464,31 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type21() {
+        let mut p = NmeaParser::new();
+        let _ = p.parse_sentence("!AIVDM,2,1,5,B,E1mg=5J1T4W0h97aRh6ba84<h2d;W:Te=eLvH50```q,0*46");
+        let atnr = match p.parse_sentence("!AIVDM,2,2,5,B,:D44QDlp0C1DU00,2*36") {
+            Ok(ParsedMessage::AidToNavigationReport(atnr)) => atnr,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&atnr);
+        assert_eq!(
+            sentences,
+            vec!["!AIVDM,1,1,,A,E1mg=5J1T4W0h97aRh6ba84<h2d;W:Te=eLvH50```q:D44QDlp0C1DU0,4*63"]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::AidToNavigationReport(atnr)));
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 23: Group Assignment Command
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupAssignmentCommand {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -69,6 +70,7 @@ pub struct GroupAssignmentCommand {
 
 /// Station Type (for message type 23).
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StationType {
     /// All types of mobiles (default)
     AllTypes,
@@ -151,6 +153,7 @@ impl StationType {
 
 /// Station interval (for message type 23)
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StationInterval {
     /// As given by the autonomous mode
     Autonomous,
@@ -248,7 +251,14 @@ pub(crate) fn handle(
             ne_lon: { Some(pick_i64(bv, 40, 18) as f64 / 600.0) },
             sw_lat: { Some(pick_i64(bv, 93, 17) as f64 / 600.0) },
             sw_lon: { Some(pick_i64(bv, 75, 18) as f64 / 600.0) },
-            station_type: StationType::new(pick_u64(bv, 110, 4) as u8)?,
+            station_type: {
+                let val = pick_u64(bv, 110, 4) as u8;
+                StationType::new(val).map_err(|e| {
+                    ParseError::new(ParseErrorKind::InvalidField, e)
+                        .with_bit(110, "station_type")
+                        .with_raw_value(&val.to_string())
+                })?
+            },
             ship_type: ShipType::new(pick_u64(bv, 114, 8) as u8),
             cargo_type: CargoType::new(pick_u64(bv, 114, 8) as u8),
             txrx: {
@@ -256,10 +266,22 @@ pub(crate) fn handle(
                 if val < 4 {
                     val
                 } else {
-                    return Err(format!("Tx/Tr mode field out of range: {}", val).into());
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidField,
+                        format!("Tx/Tr mode field out of range: {}", val),
+                    )
+                    .with_bit(144, "txrx")
+                    .with_raw_value(&val.to_string()));
                 }
             },
-            interval: StationInterval::new(pick_u64(bv, 146, 4) as u8)?,
+            interval: {
+                let val = pick_u64(bv, 146, 4) as u8;
+                StationInterval::new(val).map_err(|e| {
+                    ParseError::new(ParseErrorKind::InvalidField, e)
+                        .with_bit(146, "interval")
+                        .with_raw_value(&val.to_string())
+                })?
+            },
             quiet: {
                 let val = pick_u64(bv, 150, 4) as u8;
                 match val {
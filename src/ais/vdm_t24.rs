@@ -27,12 +27,16 @@ pub(crate) fn handle(
     let (part_a, part_b) = match pick_u64(bv, 38, 2) {
         0 => (true, false),
         1 => (false, true),
-        _ => {
-            return Err(format!(
-                "AIVDM type 24 part number has unexpected value: {}",
-                pick_u64(bv, 38, 2)
+        part_number => {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidField,
+                format!(
+                    "AIVDM type 24 part number has unexpected value: {}",
+                    part_number
+                ),
             )
-            .into());
+            .with_bit(38, "part_number")
+            .with_raw_value(&part_number.to_string()));
         }
     };
 
@@ -153,27 +157,41 @@ pub(crate) fn handle(
 impl VesselStaticData {
     /// Merge two data structures together. This is used to combine part A and B
     /// of class B AIVDM type 24 messages.
-    fn merge(&self, other: &VesselStaticData) -> Result<VesselStaticData, String> {
+    fn merge(&self, other: &VesselStaticData) -> Result<VesselStaticData, ParseError> {
         if self.ais_type != other.ais_type {
-            Err(format!(
-                "Mismatching AIS types: {} != {}",
-                self.ais_type, other.ais_type
-            ))
+            Err(ParseError::new(
+                ParseErrorKind::InvalidField,
+                format!(
+                    "Mismatching AIS types: {} != {}",
+                    self.ais_type, other.ais_type
+                ),
+            )
+            .with_raw_value(&other.ais_type.to_string()))
         } else if self.mmsi != other.mmsi {
-            Err(format!(
-                "Mismatching MMSI numbers: {} != {}",
-                self.mmsi, other.mmsi
-            ))
+            Err(ParseError::new(
+                ParseErrorKind::InvalidField,
+                format!("Mismatching MMSI numbers: {} != {}", self.mmsi, other.mmsi),
+            )
+            .with_bit(8, "mmsi")
+            .with_raw_value(&other.mmsi.to_string()))
         } else if self.imo_number != other.imo_number {
-            Err(format!(
-                "Mismatching IMO numbers: {} != {}",
-                self.mmsi, other.mmsi
-            ))
+            Err(ParseError::new(
+                ParseErrorKind::InvalidField,
+                format!(
+                    "Mismatching IMO numbers: {:?} != {:?}",
+                    self.imo_number, other.imo_number
+                ),
+            )
+            .with_raw_value(&format!("{:?}", other.imo_number)))
         } else if self.ais_version_indicator != other.ais_version_indicator {
-            Err(format!(
-                "Mismatching AIS version indicators: {} != {}",
-                self.ais_version_indicator, other.ais_version_indicator
-            ))
+            Err(ParseError::new(
+                ParseErrorKind::InvalidField,
+                format!(
+                    "Mismatching AIS version indicators: {} != {}",
+                    self.ais_version_indicator, other.ais_version_indicator
+                ),
+            )
+            .with_raw_value(&other.ais_version_indicator.to_string()))
         } else {
             Ok(VesselStaticData {
                 own_vessel: self.own_vessel,
@@ -243,6 +261,60 @@ fn choose_some_string(a: &Option<String>, b: &Option<String>) -> Option<String>
 
 // -------------------------------------------------------------------------------------------------
 
+/// Encode vessel static data as AIS VDM/VDO type 24 part A and part B payloads.
+pub(crate) fn encode(vsd: &VesselStaticData) -> Vec<BitVec> {
+    let mut part_a = BitVec::with_capacity(160);
+    push_u64(&mut part_a, 24, 6);
+    push_u64(&mut part_a, 0, 2);
+    push_u64(&mut part_a, vsd.mmsi as u64, 30);
+    push_u64(&mut part_a, 0, 2);
+    push_string(&mut part_a, vsd.name.as_deref().unwrap_or(""), 20);
+
+    let mut part_b = BitVec::with_capacity(168);
+    push_u64(&mut part_b, 24, 6);
+    push_u64(&mut part_b, 0, 2);
+    push_u64(&mut part_b, vsd.mmsi as u64, 30);
+    push_u64(&mut part_b, 1, 2);
+    push_u64(
+        &mut part_b,
+        ship_and_cargo_type(vsd.ship_type, vsd.cargo_type) as u64,
+        8,
+    );
+    push_string(
+        &mut part_b,
+        vsd.equipment_vendor_id.as_deref().unwrap_or(""),
+        3,
+    );
+    push_u64(&mut part_b, vsd.equipment_model.unwrap_or(0) as u64, 4);
+    push_u64(
+        &mut part_b,
+        vsd.equipment_serial_number.unwrap_or(0) as u64,
+        20,
+    );
+    push_string(&mut part_b, vsd.call_sign.as_deref().unwrap_or(""), 7);
+    match vsd.mothership_mmsi {
+        // Auxiliary craft (MMSI 98XXXYYYY) report the mothership instead of dimensions
+        Some(mothership_mmsi) if vsd.mmsi / 10000000 == 98 => {
+            push_u64(&mut part_b, mothership_mmsi as u64, 30);
+        }
+        _ => {
+            push_u64(&mut part_b, vsd.dimension_to_bow.unwrap_or(0) as u64, 9);
+            push_u64(&mut part_b, vsd.dimension_to_stern.unwrap_or(0) as u64, 9);
+            push_u64(&mut part_b, vsd.dimension_to_port.unwrap_or(0) as u64, 6);
+            push_u64(
+                &mut part_b,
+                vsd.dimension_to_starboard.unwrap_or(0) as u64,
+                6,
+            );
+        }
+    }
+    push_u64(&mut part_b, 0, 6);
+
+    vec![part_a, part_b]
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -317,4 +389,34 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type24() {
+        let mut p = NmeaParser::new();
+        let _ = p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D");
+        let vsd = match p.parse_sentence("!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => vsd,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&vsd);
+        assert_eq!(
+            sentences,
+            vec![
+                "!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D",
+                "!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40",
+            ]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::VesselStaticData(vsd)));
+    }
 }
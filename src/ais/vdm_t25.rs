@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 25: Single Slot Binary Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SingleSlotBinaryMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -38,6 +39,7 @@ pub struct SingleSlotBinaryMessage {
     pub app_id: Option<u16>,
 
     /// Data field of length 0-128 bits.
+    #[cfg_attr(feature = "serde", serde(with = "json_bit_vec"))]
     pub data: BitVec,
 }
 
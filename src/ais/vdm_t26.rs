@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 26: Multiple Slot Binary Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MultipleSlotBinaryMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -38,6 +39,7 @@ pub struct MultipleSlotBinaryMessage {
     pub app_id: Option<u16>,
 
     /// Data field of length 0-1004 bits.
+    #[cfg_attr(feature = "serde", serde(with = "json_bit_vec"))]
     pub data: BitVec,
 
     /// Radio status
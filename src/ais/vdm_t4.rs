@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 4: Base Station Report
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BaseStationReport {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -31,6 +32,7 @@ pub struct BaseStationReport {
     pub mmsi: u32,
 
     /// Timestamp
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Position accuracy: true = high (<= 10 m), false = low (> 10 m)
@@ -79,14 +81,17 @@ pub(crate) fn handle(
         station: { station },
         mmsi: { pick_u64(bv, 8, 30) as u32 },
         timestamp: {
-            Some(parse_ymdhs(
-                pick_u64(bv, 38, 14) as i32,
-                pick_u64(bv, 52, 4) as u32,
-                pick_u64(bv, 56, 5) as u32,
-                pick_u64(bv, 61, 5) as u32,
-                pick_u64(bv, 66, 6) as u32,
-                pick_u64(bv, 72, 6) as u32,
-            )?)
+            Some(
+                parse_ymdhs(
+                    pick_u64(bv, 38, 14) as i32,
+                    pick_u64(bv, 52, 4) as u32,
+                    pick_u64(bv, 56, 5) as u32,
+                    pick_u64(bv, 61, 5) as u32,
+                    pick_u64(bv, 66, 6) as u32,
+                    pick_u64(bv, 72, 6) as u32,
+                )
+                .map_err(|e| e.with_bit(38, "timestamp"))?,
+            )
         },
         high_position_accuracy: { pick_u64(bv, 78, 1) != 0 },
         latitude: {
@@ -119,6 +124,50 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToPayload for BaseStationReport {
+    fn to_payloads(&self) -> Vec<BitVec> {
+        let mut bv = BitVec::with_capacity(168);
+        push_u64(&mut bv, 4, 6);
+        push_u64(&mut bv, 0, 2);
+        push_u64(&mut bv, self.mmsi as u64, 30);
+        let (year, month, day, hour, minute, second) = match self.timestamp {
+            Some(t) => (
+                t.year() as u64,
+                t.month() as u64,
+                t.day() as u64,
+                t.hour() as u64,
+                t.minute() as u64,
+                t.second() as u64,
+            ),
+            None => (0, 0, 0, 24, 60, 60),
+        };
+        push_u64(&mut bv, year, 14);
+        push_u64(&mut bv, month, 4);
+        push_u64(&mut bv, day, 5);
+        push_u64(&mut bv, hour, 5);
+        push_u64(&mut bv, minute, 6);
+        push_u64(&mut bv, second, 6);
+        push_u64(&mut bv, self.high_position_accuracy as u64, 1);
+        push_longitude(&mut bv, self.longitude, 28, 600000);
+        push_latitude(&mut bv, self.latitude, 27, 600000);
+        push_u64(
+            &mut bv,
+            self.position_fix_type.map(|t| t.to_value()).unwrap_or(0) as u64,
+            4,
+        );
+        push_u64(&mut bv, 0, 10);
+        push_u64(&mut bv, self.raim_flag as u64, 1);
+        push_u64(&mut bv, self.radio_status as u64, 19);
+        vec![bv]
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -156,4 +205,30 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type4() {
+        let mut p = NmeaParser::new();
+        let bsr = match p.parse_sentence("!AIVDM,1,1,,A,403OviQuMGCqWrRO9>E6fE700@GO,0*4D") {
+            Ok(ParsedMessage::BaseStationReport(bsr)) => bsr,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&bsr);
+        assert_eq!(
+            sentences,
+            vec!["!AIVDM,1,1,,A,403OviQuMGCqWrRO9>E6fE700@GO,0*4D"]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::BaseStationReport(bsr)));
+    }
 }
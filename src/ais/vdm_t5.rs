@@ -20,10 +20,11 @@ pub(crate) fn handle(
     bv: &BitVec,
     _station: Station,
     own_vessel: bool,
+    now: Option<DateTime<Utc>>,
 ) -> Result<ParsedMessage, ParseError> {
     Ok(ParsedMessage::VesselStaticData(VesselStaticData {
         own_vessel,
-        ais_type: AisClass::ClassB,
+        ais_type: AisClass::ClassA,
         mmsi: pick_u64(bv, 8, 30) as u32,
         ais_version_indicator: pick_u64(bv, 38, 2) as u8,
         imo_number: {
@@ -69,7 +70,7 @@ pub(crate) fn handle(
                 _ => Some(PositionFixType::new(raw)),
             }
         },
-        eta: pick_eta(bv, 274)?,
+        eta: pick_eta(bv, 274, now)?,
         draught10: Some(pick_u64(bv, 294, 8) as u8),
         destination: {
             let raw = pick_string(bv, 302, 20);
@@ -84,10 +85,58 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+/// Encode vessel static data as AIS VDM/VDO type 5 payload.
+pub(crate) fn encode(vsd: &VesselStaticData) -> BitVec {
+    let mut bv = BitVec::with_capacity(424);
+    push_u64(&mut bv, 5, 6);
+    push_u64(&mut bv, 0, 2);
+    push_u64(&mut bv, vsd.mmsi as u64, 30);
+    push_u64(&mut bv, vsd.ais_version_indicator as u64, 2);
+    push_u64(&mut bv, vsd.imo_number.unwrap_or(0) as u64, 30);
+    push_string(&mut bv, vsd.call_sign.as_deref().unwrap_or(""), 7);
+    push_string(&mut bv, vsd.name.as_deref().unwrap_or(""), 20);
+    push_u64(
+        &mut bv,
+        ship_and_cargo_type(vsd.ship_type, vsd.cargo_type) as u64,
+        8,
+    );
+    push_u64(&mut bv, vsd.dimension_to_bow.unwrap_or(0) as u64, 9);
+    push_u64(&mut bv, vsd.dimension_to_stern.unwrap_or(0) as u64, 9);
+    push_u64(&mut bv, vsd.dimension_to_port.unwrap_or(0) as u64, 6);
+    push_u64(&mut bv, vsd.dimension_to_starboard.unwrap_or(0) as u64, 6);
+    push_u64(
+        &mut bv,
+        vsd.position_fix_type.map(|t| t.to_value()).unwrap_or(0) as u64,
+        4,
+    );
+    push_eta(&mut bv, vsd.eta);
+    push_u64(&mut bv, vsd.draught10.unwrap_or(0) as u64, 8);
+    push_string(&mut bv, vsd.destination.as_deref().unwrap_or(""), 20);
+    push_u64(&mut bv, 0, 2);
+    bv
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_parse_vdm_type5_class() {
+        let mut p = NmeaParser::new();
+        let _ = p.parse_sentence(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        );
+        match p.parse_sentence("!AIVDM,2,2,1,A,88888888880,2*25") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => {
+                assert_eq!(vsd.ais_type, AisClass::ClassA);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
     #[test]
     fn test_parse_vdm_type5() {
         let mut p = NmeaParser::new();
@@ -213,4 +262,36 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vdm_type5() {
+        let mut p = NmeaParser::new();
+        let _ = p.parse_sentence(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        );
+        let vsd = match p.parse_sentence("!AIVDM,2,2,1,A,88888888880,2*25") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => vsd,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&vsd);
+        assert_eq!(
+            sentences,
+            vec![
+                "!AIVDM,2,1,0,A,55?MbV02;H;s<HtKP00EHE:0@T4@Dl0000000016L961O5Gf0NSQEp6ClRh0,0*0F",
+                "!AIVDM,2,2,0,A,00000000000,2*24",
+            ]
+        );
+
+        let mut p = NmeaParser::new();
+        let mut result = Err(ParseError::new(
+            ParseErrorKind::InvalidSentence,
+            "No sentences",
+        ));
+        for sentence in &sentences {
+            result = p.parse_sentence(sentence);
+        }
+        assert_eq!(result, Ok(ParsedMessage::VesselStaticData(vsd)));
+    }
 }
@@ -20,6 +20,7 @@ use super::*;
 
 /// Type 6: Binary Addressed Message
 #[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinaryAddressedMessage {
     /// True if the data is about own vessel, false if about other.
     pub own_vessel: bool,
@@ -44,28 +45,71 @@ pub struct BinaryAddressedMessage {
 
     /// Functional ID, FID (6 bits)
     pub fid: u8,
-    // TODO: data (depending on DAC and FID
+
+    /// Application data (max 920 bits)
+    #[cfg_attr(feature = "serde", serde(with = "json_bit_vec"))]
+    pub data: BitVec,
+
+    /// Decoded application data if the DAC and FID pair is a recognized one
+    pub application: Option<AddressedApplication>,
+}
+
+/// Decoded application data of a binary addressed message
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum AddressedApplication {
+    /// DAC 1, FID 25: Dangerous cargo indication
+    DangerousCargoIndication(DangerousCargoIndication),
+
+    /// DAC 1, FID 28: Route information
+    RouteInformation(RouteInformation),
+
+    /// DAC 1, FID 30: Text description
+    TextDescription(TextDescription),
+
+    /// DAC 1, FID 32: Tidal window
+    TidalWindow(TidalWindow),
+
+    /// DAC 1, FID 16 or FID 40: Number of persons on board
+    PersonsOnBoard(PersonsOnBoard),
+
+    /// DAC 200, FID 21: Inland ETA at lock, bridge or terminal
+    InlandEta(InlandEta),
+
+    /// DAC 200, FID 22: Inland RTA at lock, bridge or terminal
+    InlandRta(InlandRta),
 }
 
 impl LatLon for BinaryAddressedMessage {
     fn latitude(&self) -> Option<f64> {
-        None // TODO: depends on data
+        match &self.application {
+            Some(AddressedApplication::RouteInformation(ri)) => ri.latitude(),
+            Some(AddressedApplication::TidalWindow(tw)) => tw.latitude(),
+            _ => None,
+        }
     }
 
     fn longitude(&self) -> Option<f64> {
-        None // TODO: depends on data
+        match &self.application {
+            Some(AddressedApplication::RouteInformation(ri)) => ri.longitude(),
+            Some(AddressedApplication::TidalWindow(tw)) => tw.longitude(),
+            _ => None,
+        }
     }
 }
 
 // -------------------------------------------------------------------------------------------------
 
-/// AIS VDM/VDO type 6: Binary Addressed Message. Implementation of the 920-bit data field is
-/// unimplemented currently.
+/// AIS VDM/VDO type 6: Binary Addressed Message
 pub(crate) fn handle(
     bv: &BitVec,
     station: Station,
     own_vessel: bool,
+    now: Option<DateTime<Utc>>,
 ) -> Result<ParsedMessage, ParseError> {
+    let dac = pick_u64(bv, 72, 10) as u16;
+    let fid = pick_u64(bv, 82, 6) as u8;
     Ok(ParsedMessage::BinaryAddressedMessage(
         BinaryAddressedMessage {
             own_vessel: { own_vessel },
@@ -74,8 +118,29 @@ pub(crate) fn handle(
             sequence_number: { pick_u64(bv, 38, 2) as u8 },
             destination_mmsi: { pick_u64(bv, 40, 30) as u32 },
             retransmit_flag: { pick_u64(bv, 70, 1) != 0 },
-            dac: { pick_u64(bv, 72, 10) as u16 },
-            fid: { pick_u64(bv, 82, 6) as u8 }, // TODO: data (depending on DAC and FID
+            dac: { dac },
+            fid: { fid },
+            data: { BitVec::from_bitslice(&bv[min(88, bv.len())..]) },
+            application: match (dac, fid) {
+                (1, 25) => Some(AddressedApplication::DangerousCargoIndication(
+                    imo289_dangerous_cargo::decode(bv, 88),
+                )),
+                (1, 28) => application_or_none(imo289_route::decode(bv, 88, now))
+                    .map(AddressedApplication::RouteInformation),
+                (1, 30) => Some(AddressedApplication::TextDescription(imo289_text::decode(
+                    bv, 88,
+                ))),
+                (1, 32) => application_or_none(imo289_tidal_window::decode(bv, 88, now))
+                    .map(AddressedApplication::TidalWindow),
+                (1, 16) | (1, 40) => Some(AddressedApplication::PersonsOnBoard(
+                    imo289_persons_on_board::decode(bv, 88),
+                )),
+                (200, 21) => application_or_none(inland_eta::decode_eta(bv, 88, now))
+                    .map(AddressedApplication::InlandEta),
+                (200, 22) => application_or_none(inland_eta::decode_rta(bv, 88, now))
+                    .map(AddressedApplication::InlandRta),
+                _ => None,
+            },
         },
     ))
 }
@@ -100,7 +165,8 @@ mod test {
                         assert!(!bam.retransmit_flag);
                         assert_eq!(bam.dac, 669);
                         assert_eq!(bam.fid, 11);
-                        // TODO: check data
+                        assert_eq!(bam.data.len(), 136 - 88);
+                        assert_eq!(bam.application, None);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -114,5 +180,169 @@ mod test {
                 assert_eq!(e.to_string(), "OK");
             }
         }
+
+        // Dangerous cargo indication
+        match p.parse_sentence("!AIVDM,1,1,,B,63KMWfTr=1l005VO@Cp1T00,4*6F") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.mmsi, 230123450);
+                assert_eq!(bam.destination_mmsi, 244123456);
+                assert_eq!(bam.dac, 1);
+                assert_eq!(bam.fid, 25);
+                match bam.application {
+                    Some(AddressedApplication::DangerousCargoIndication(ref dci)) => {
+                        assert_eq!(dci.unit, CargoQuantityUnit::Tonnes);
+                        assert_eq!(dci.amount, Some(500));
+                        assert_eq!(
+                            dci.cargos,
+                            vec![
+                                DangerousCargo {
+                                    code: DangerousCargoCode::Imdg,
+                                    subtype: Some(31),
+                                },
+                                DangerousCargo {
+                                    code: DangerousCargoCode::MarpolAnnexI,
+                                    subtype: Some(2),
+                                },
+                            ]
+                        );
+                    }
+                    _ => panic!("Unexpected application: {:?}", bam.application),
+                }
+                assert_eq!(bam.latitude(), None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Route information
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfTr=1l005h2@D0Htwww269cD28;lP,0*55") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.fid, 28);
+                match bam.application {
+                    Some(AddressedApplication::RouteInformation(ref ri)) => {
+                        assert_eq!(ri.link_id, 9);
+                        assert_eq!(ri.route_type, RouteType::ShipRoutePlan);
+                        assert_eq!(ri.start_time, None);
+                        assert_eq!(ri.duration_minutes, None);
+                        assert_eq!(ri.waypoints.len(), 1);
+                    }
+                    _ => panic!("Unexpected application: {:?}", bam.application),
+                }
+                assert::close(bam.latitude().unwrap_or(0.0), 59.5, 0.0001);
+                assert::close(bam.longitude().unwrap_or(0.0), 21.5, 0.0001);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Malformed application data doesn't fail the message
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfTr=1l0063GQK;t3L5t50<N;@NaKGPDm0PiqSjlOp,3*3C")
+        {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.dac, 1);
+                assert_eq!(bam.fid, 32);
+                assert_eq!(bam.data.len(), 276 - 3 - 88);
+                assert_eq!(bam.application, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Tidal window
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfTr=1l0061WQK;t3L5t50<N;@NaKGPDm0PiqSjlOp,3*2E")
+        {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.fid, 32);
+                match bam.application {
+                    Some(AddressedApplication::TidalWindow(ref tw)) => {
+                        assert_eq!(tw.windows.len(), 2);
+                        let w = &tw.windows[0];
+                        assert_eq!(w.from, Utc.with_ymd_and_hms(2000, 6, 15, 10, 0, 0).single());
+                        assert_eq!(w.to, Utc.with_ymd_and_hms(2000, 6, 15, 12, 30, 0).single());
+                        assert_eq!(w.current_direction, Some(90));
+                        assert::close(w.current_speed_knots.unwrap_or(0.0), 1.5, 0.01);
+                        assert_eq!(
+                            tw.windows[1],
+                            TidalWindowEntry {
+                                latitude: None,
+                                longitude: None,
+                                from: None,
+                                to: None,
+                                current_direction: None,
+                                current_speed_knots: None,
+                            }
+                        );
+                    }
+                    _ => panic!("Unexpected application: {:?}", bam.application),
+                }
+                assert::close(bam.latitude().unwrap_or(0.0), 60.1, 0.0001);
+                assert::close(bam.longitude().unwrap_or(0.0), 24.9, 0.0001);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Number of persons on board
+        match p.parse_sentence("!AIVDM,1,1,,A,63KMWfTr=1l006PVT0,4*02") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.fid, 40);
+                assert_eq!(
+                    bam.application,
+                    Some(AddressedApplication::PersonsOnBoard(PersonsOnBoard {
+                        persons: Some(1234),
+                    }))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type6_inland() {
+        let mut p = NmeaParser::new();
+        let location = InlandLocation {
+            country_code: "DE".into(),
+            location_code: "DUI".into(),
+            fairway_section: "00123".into(),
+            terminal_code: "T0001".into(),
+            fairway_hectometre: "01234".into(),
+        };
+
+        // ETA at lock, bridge or terminal
+        match p.parse_sentence("!AIVDM,1,1,,A,639ed50jCVd4<QD@DADW337;=C333737;?B:>NqMh0,4*50") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.dac, 200);
+                assert_eq!(bam.fid, 21);
+                assert_eq!(
+                    bam.application,
+                    Some(AddressedApplication::InlandEta(InlandEta {
+                        location: location.clone(),
+                        eta: Utc.with_ymd_and_hms(2000, 8, 20, 14, 30, 0).single(),
+                        assisting_tugboats: None,
+                        air_draught_meters: Some(7.5),
+                    }))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // RTA at lock, bridge or terminal
+        match p.parse_sentence("!AIVDM,1,1,,A,639ed50jCVd4<QH@DADW337;=C333737;?B:?0@,2*01") {
+            Ok(ParsedMessage::BinaryAddressedMessage(bam)) => {
+                assert_eq!(bam.fid, 22);
+                assert_eq!(
+                    bam.application,
+                    Some(AddressedApplication::InlandRta(InlandRta {
+                        location,
+                        rta: Utc.with_ymd_and_hms(2000, 8, 20, 15, 0, 0).single(),
+                        status: Some(LockStatus::Limited),
+                    }))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
     }
 }
@@ -16,6 +16,95 @@ limitations under the License.
 
 use super::*;
 
-// Message type 13 is a receipt acknowledgement to senders of previous messages of type 12. 
-// The message layout is identical to a type 7 Binary Acknowledge.
+// -------------------------------------------------------------------------------------------------
 
+/// Type 7: Binary Acknowledge. The message layout is identical to type 13 Safety-Related
+/// Acknowledgement, whose fields are accessible through `Deref`.
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BinaryAcknowledge(pub SafetyRelatedAcknowledgement);
+
+impl core::ops::Deref for BinaryAcknowledge {
+    type Target = SafetyRelatedAcknowledgement;
+
+    fn deref(&self) -> &SafetyRelatedAcknowledgement {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for BinaryAcknowledge {
+    fn deref_mut(&mut self) -> &mut SafetyRelatedAcknowledgement {
+        &mut self.0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// AIS VDM/VDO type 7: Binary Acknowledge
+pub(crate) fn handle(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+) -> Result<ParsedMessage, ParseError> {
+    Ok(ParsedMessage::BinaryAcknowledge(BinaryAcknowledge(
+        vdm_t13::decode(bv, station, own_vessel),
+    )))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToPayload for BinaryAcknowledge {
+    /// Encode the acknowledgement as type 7 payload. Trailing zero MMSI numbers are omitted.
+    fn to_payloads(&self) -> Vec<BitVec> {
+        vec![vdm_t13::encode(self, 7)]
+    }
+
+    fn own_vessel(&self) -> bool {
+        self.own_vessel
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vdm_type7() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("!AIVDM,1,1,,A,702R5`hwCjq8,0*6B") {
+            Ok(ParsedMessage::BinaryAcknowledge(ba)) => {
+                assert_eq!(ba.mmsi, 2655651);
+                assert_eq!(ba.mmsi1, 265538450);
+                assert_eq!(ba.mmsi1_seq, 0);
+                assert_eq!(ba.mmsi2, 0);
+                assert_eq!(ba.mmsi2_seq, 0);
+                assert_eq!(ba.mmsi3, 0);
+                assert_eq!(ba.mmsi3_seq, 0);
+                assert_eq!(ba.mmsi4, 0);
+                assert_eq!(ba.mmsi4_seq, 0);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_vdm_type7() {
+        let mut p = NmeaParser::new();
+        let ba = match p.parse_sentence("!AIVDM,1,1,,A,702R5`hwCjq8,0*6B") {
+            Ok(ParsedMessage::BinaryAcknowledge(ba)) => ba,
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        };
+
+        let sentences = VdmEncoder::new().encode(&ba);
+        assert_eq!(sentences, vec!["!AIVDM,1,1,,A,702R5`hwCjq8,0*6B"]);
+        assert_eq!(
+            p.parse_sentence(&sentences[0]),
+            Ok(ParsedMessage::BinaryAcknowledge(ba))
+        );
+    }
+}
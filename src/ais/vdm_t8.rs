@@ -0,0 +1,469 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Type 8: Binary Broadcast Message
+#[derive(Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BinaryBroadcastMessage {
+    /// True if the data is about own vessel, false if about other.
+    pub own_vessel: bool,
+
+    /// AIS station type.
+    pub station: Station,
+
+    /// User ID (30 bits)
+    pub mmsi: u32,
+
+    /// Designated area code, DAC (10 bits)
+    pub dac: u16,
+
+    /// Functional ID, FID (6 bits)
+    pub fid: u8,
+
+    /// Application data (max 952 bits)
+    #[cfg_attr(feature = "serde", serde(with = "json_bit_vec"))]
+    pub data: BitVec,
+
+    /// Decoded application data if the DAC and FID pair is a recognized one
+    pub application: Option<BroadcastApplication>,
+}
+
+/// Decoded application data of a binary broadcast message
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum BroadcastApplication {
+    /// DAC 1, FID 22: Area notice
+    AreaNotice(AreaNotice),
+
+    /// DAC 1, FID 27: Route information
+    RouteInformation(RouteInformation),
+
+    /// DAC 1, FID 29: Text description
+    TextDescription(TextDescription),
+
+    /// DAC 1, FID 31: Meteorological and hydrographic data
+    MeteorologicalHydrographicData(Box<MeteorologicalHydrographicData>),
+
+    /// DAC 200, FID 10: Inland ship static and voyage related data
+    InlandStaticVoyageData(InlandStaticVoyageData),
+
+    /// DAC 200, FID 24: Inland water levels
+    InlandWaterLevels(InlandWaterLevels),
+
+    /// DAC 200, FID 55: Inland number of persons on board
+    InlandPersonsOnBoard(InlandPersonsOnBoard),
+}
+
+impl LatLon for BinaryBroadcastMessage {
+    fn latitude(&self) -> Option<f64> {
+        match &self.application {
+            Some(BroadcastApplication::AreaNotice(an)) => an.latitude(),
+            Some(BroadcastApplication::RouteInformation(ri)) => ri.latitude(),
+            Some(BroadcastApplication::MeteorologicalHydrographicData(mhd)) => mhd.latitude(),
+            _ => None,
+        }
+    }
+
+    fn longitude(&self) -> Option<f64> {
+        match &self.application {
+            Some(BroadcastApplication::AreaNotice(an)) => an.longitude(),
+            Some(BroadcastApplication::RouteInformation(ri)) => ri.longitude(),
+            Some(BroadcastApplication::MeteorologicalHydrographicData(mhd)) => mhd.longitude(),
+            _ => None,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// AIS VDM/VDO type 8: Binary Broadcast Message
+pub(crate) fn handle(
+    bv: &BitVec,
+    station: Station,
+    own_vessel: bool,
+    now: Option<DateTime<Utc>>,
+) -> Result<ParsedMessage, ParseError> {
+    let dac = pick_u64(bv, 40, 10) as u16;
+    let fid = pick_u64(bv, 50, 6) as u8;
+    Ok(ParsedMessage::BinaryBroadcastMessage(
+        BinaryBroadcastMessage {
+            own_vessel: { own_vessel },
+            station: { station },
+            mmsi: { pick_u64(bv, 8, 30) as u32 },
+            dac: { dac },
+            fid: { fid },
+            data: { BitVec::from_bitslice(&bv[min(56, bv.len())..]) },
+            application: match (dac, fid) {
+                (1, 22) => application_or_none(imo289_area_notice::decode(bv, 56, now))
+                    .map(BroadcastApplication::AreaNotice),
+                (1, 27) => application_or_none(imo289_route::decode(bv, 56, now))
+                    .map(BroadcastApplication::RouteInformation),
+                (1, 29) => Some(BroadcastApplication::TextDescription(imo289_text::decode(
+                    bv, 56,
+                ))),
+                (1, 31) => Some(BroadcastApplication::MeteorologicalHydrographicData(
+                    Box::new(imo289_met_hydro::decode(bv, 56)),
+                )),
+                (200, 10) => Some(BroadcastApplication::InlandStaticVoyageData(
+                    inland_static_voyage::decode(bv, 56),
+                )),
+                (200, 24) => Some(BroadcastApplication::InlandWaterLevels(
+                    inland_water_levels::decode(bv, 56),
+                )),
+                (200, 55) => Some(BroadcastApplication::InlandPersonsOnBoard(
+                    inland_persons_on_board::decode(bv, 56),
+                )),
+                _ => None,
+            },
+        },
+    ))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_vdm_type8() {
+        let mut p = NmeaParser::new();
+
+        // Unrecognized application
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,85Mwp`1Kf3aCnsNvBWLi=wQuNhA5t43N`5nCuI=p<IBfVqnMgPGs,0*47",
+        ) {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.mmsi, 366999712);
+                assert_eq!(bbm.dac, 366);
+                assert_eq!(bbm.fid, 56);
+                assert_eq!(bbm.data.len(), 312 - 56);
+                assert_eq!(bbm.application, None);
+                assert_eq!(bbm.latitude(), None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Route information
+        match p.parse_sentence("!AIVDM,1,1,,A,83KMWfP0Fh58VNip07P@LOfh8V>n0>CQh4CTf0,1*7C") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.mmsi, 230123450);
+                assert_eq!(bbm.dac, 1);
+                assert_eq!(bbm.fid, 27);
+                assert_eq!(bbm.data.len(), 228 - 1 - 56);
+                match bbm.application {
+                    Some(BroadcastApplication::RouteInformation(ref ri)) => {
+                        assert_eq!(ri.link_id, 5);
+                        assert_eq!(ri.sender_classification, 1);
+                        assert_eq!(ri.route_type, RouteType::Recommended);
+                        assert_eq!(
+                            ri.start_time,
+                            Utc.with_ymd_and_hms(2000, 6, 15, 12, 30, 0).single()
+                        );
+                        assert_eq!(ri.duration_minutes, Some(120));
+                        assert_eq!(ri.waypoints.len(), 2);
+                        let waypoint = ri.waypoints[1].unwrap_or_default();
+                        assert::close(waypoint.latitude, 60.2, 0.0001);
+                        assert::close(waypoint.longitude, 25.0, 0.0001);
+                    }
+                    _ => panic!("Unexpected application: {:?}", bbm.application),
+                }
+                assert::close(bbm.latitude().unwrap_or(0.0), 60.1, 0.0001);
+                assert::close(bbm.longitude().unwrap_or(0.0), 24.9, 0.0001);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Malformed application data doesn't fail the message
+        match p.parse_sentence("!AIVDM,1,1,,A,83KMWfP0Fh58eNip07P@LOfh8V>n0>CQh4CTf0,1*4F") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.mmsi, 230123450);
+                assert_eq!(bbm.dac, 1);
+                assert_eq!(bbm.fid, 27);
+                assert_eq!(bbm.data.len(), 228 - 1 - 56);
+                assert_eq!(bbm.application, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Waypoints of a truncated route information are dropped
+        match p.parse_sentence("!AIVDM,1,1,,A,83KMWfP0Fh58VNip07P@LOfh8V>n0>CQh0,4*3C") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => match bbm.application {
+                Some(BroadcastApplication::RouteInformation(ref ri)) => {
+                    assert_eq!(ri.waypoints.len(), 1);
+                    let waypoint = ri.waypoints[0].unwrap_or_default();
+                    assert::close(waypoint.latitude, 60.1, 0.0001);
+                    assert::close(waypoint.longitude, 24.9, 0.0001);
+                }
+                _ => panic!("Unexpected application: {:?}", bbm.application),
+            },
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Route information with an unavailable waypoint
+        match p.parse_sentence("!AIVDM,1,1,,A,83KMWfP0Fh58VNip07PHLOfh8V>n1WTJh6PT:079hp29jG0,0*5C")
+        {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => match bbm.application {
+                Some(BroadcastApplication::RouteInformation(ref ri)) => {
+                    assert_eq!(ri.waypoints.len(), 3);
+                    assert!(ri.waypoints[0].is_some());
+                    assert_eq!(ri.waypoints[1], None);
+                    let waypoint = ri.waypoints[2].unwrap_or_default();
+                    assert::close(waypoint.latitude, 60.2, 0.0001);
+                    assert::close(waypoint.longitude, 25.0, 0.0001);
+                }
+                _ => panic!("Unexpected application: {:?}", bbm.application),
+            },
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Text description
+        match p.parse_sentence("!AIVDM,1,1,,B,83KMWfP0G@785<<?PG?B<4,0*19") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.fid, 29);
+                assert_eq!(
+                    bbm.application,
+                    Some(BroadcastApplication::TextDescription(TextDescription {
+                        link_id: 7,
+                        text: "HELLO WORLD".into(),
+                    }))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Meteorological and hydrographic data
+        match p.parse_sentence(
+            "!AIVDM,1,1,,B,802<HH@0Ghee01f8m5sKAQ9hfKseGhVdtR3F2cCwe7hhe:3iVAwwnQ0l9400,0*3C",
+        ) {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.fid, 31);
+                match bbm.application {
+                    Some(BroadcastApplication::MeteorologicalHydrographicData(ref mhd)) => {
+                        assert!(mhd.high_position_accuracy);
+                        assert_eq!(mhd.day, Some(15));
+                        assert_eq!(mhd.hour, Some(13));
+                        assert_eq!(mhd.minute, Some(45));
+                        assert_eq!(mhd.wind_speed_knots, Some(12));
+                        assert_eq!(mhd.wind_gust_knots, Some(18));
+                        assert_eq!(mhd.wind_direction, Some(225));
+                        assert_eq!(mhd.wind_gust_direction, Some(230));
+                        assert::close(mhd.air_temperature_celsius.unwrap_or(0.0), -3.5, 0.01);
+                        assert_eq!(mhd.relative_humidity, Some(85));
+                        assert::close(mhd.dew_point_celsius.unwrap_or(0.0), -6.2, 0.01);
+                        assert_eq!(mhd.air_pressure_hpa, Some(1013));
+                        assert_eq!(mhd.air_pressure_tendency, Some(Tendency::Decreasing));
+                        assert::close(mhd.visibility_nm.unwrap_or(0.0), 10.0, 0.01);
+                        assert!(mhd.visibility_greater_than);
+                        assert::close(mhd.water_level_meters.unwrap_or(0.0), 0.5, 0.001);
+                        assert_eq!(mhd.water_level_trend, None);
+                        assert::close(mhd.surface_current.speed_knots.unwrap_or(0.0), 0.5, 0.01);
+                        assert_eq!(mhd.surface_current.direction, Some(180));
+                        assert_eq!(
+                            mhd.current2,
+                            WaterCurrent {
+                                speed_knots: None,
+                                direction: None,
+                                depth_meters: None,
+                            }
+                        );
+                        assert::close(mhd.current3.speed_knots.unwrap_or(0.0), 1.2, 0.01);
+                        assert_eq!(mhd.current3.direction, Some(90));
+                        assert_eq!(mhd.current3.depth_meters, Some(10));
+                        assert::close(mhd.wave_height_meters.unwrap_or(0.0), 1.5, 0.01);
+                        assert_eq!(mhd.wave_period_seconds, Some(6));
+                        assert_eq!(mhd.wave_direction, Some(200));
+                        assert_eq!(mhd.swell_height_meters, None);
+                        assert_eq!(mhd.swell_period_seconds, None);
+                        assert_eq!(mhd.swell_direction, None);
+                        assert_eq!(mhd.sea_state_beaufort, Some(4));
+                        assert::close(mhd.water_temperature_celsius.unwrap_or(0.0), 5.2, 0.01);
+                        assert_eq!(mhd.precipitation, Some(PrecipitationType::Rain));
+                        assert::close(mhd.salinity_permille.unwrap_or(0.0), 6.8, 0.01);
+                        assert_eq!(mhd.ice, Some(false));
+                    }
+                    _ => panic!("Unexpected application: {:?}", bbm.application),
+                }
+                assert::close(bbm.latitude().unwrap_or(0.0), 60.15, 0.0001);
+                assert::close(bbm.longitude().unwrap_or(0.0), 24.96, 0.0001);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Area notice
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,802<HH@0EP<AfAP00;@0eUv1f2v400000L0055`1E`01J0035`OBl00e00;@00aH`b41rbT3Aqhd5>?BD8000000000,0*60",
+        ) {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.fid, 22);
+                match bbm.application {
+                    Some(BroadcastApplication::AreaNotice(ref an)) => {
+                        assert_eq!(an.link_id, 12);
+                        assert_eq!(an.notice_type, 35);
+                        assert_eq!(an.notice_description(), "Restricted Area: Entry prohibited");
+                        assert_eq!(
+                            an.start_time,
+                            Utc.with_ymd_and_hms(2000, 7, 4, 12, 0, 0).single()
+                        );
+                        assert_eq!(
+                            an.end_time,
+                            Utc.with_ymd_and_hms(2000, 7, 4, 13, 30, 0).single()
+                        );
+                        assert_eq!(an.sub_areas.len(), 3);
+                        assert_eq!(
+                            an.sub_areas[0],
+                            SubArea::Circle {
+                                latitude: Some(60.1),
+                                longitude: Some(24.9),
+                                radius_meters: 0,
+                            }
+                        );
+                        match an.sub_areas[1] {
+                            SubArea::Polyline { ref points } => {
+                                assert_eq!(points.len(), 4);
+                                assert::close(points[0].latitude, 60.1, 0.00001);
+                                assert::close(points[1].latitude, 60.108993, 0.00001);
+                                assert::close(points[1].longitude, 24.9, 0.00001);
+                                assert::close(points[2].latitude, 60.108993, 0.0001);
+                                assert::close(points[2].longitude, 24.918016, 0.0001);
+                                assert::close(points[3].latitude, 60.104497, 0.0001);
+                                assert::close(points[3].longitude, 24.918016, 0.0001);
+                            }
+                            ref sa => panic!("Unexpected sub-area: {:?}", sa),
+                        }
+                        assert_eq!(
+                            an.sub_areas[2],
+                            SubArea::Text {
+                                text: "KEEP OUT ZONE NORTH".into()
+                            }
+                        );
+                    }
+                    _ => panic!("Unexpected application: {:?}", bbm.application),
+                }
+                assert::close(bbm.latitude().unwrap_or(0.0), 60.1, 0.0001);
+                assert::close(bbm.longitude().unwrap_or(0.0), 24.9, 0.0001);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Area notice polyline without a starting point is skipped
+        match p.parse_sentence(
+            "!AIVDM,1,1,,A,802<HH@0EP<AfAP00;CP00`e0:e00;@00He3rFP05`01J005;55@P?EDPJ?>5PairBQ0000000000,3*16",
+        ) {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => match bbm.application {
+                Some(BroadcastApplication::AreaNotice(ref an)) => {
+                    assert_eq!(
+                        an.sub_areas,
+                        vec![SubArea::Text {
+                            text: "KEEP OUT ZONE NORTH".into()
+                        }]
+                    );
+                }
+                _ => panic!("Unexpected application: {:?}", bbm.application),
+            },
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_vdm_type8_inland() {
+        let mut p = NmeaParser::new();
+
+        // Inland ship static and voyage related data
+        match p.parse_sentence("!AIVDM,1,1,,A,839ed50j2d<dtL==MR9Pq?ci8hp0,0*6E") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.mmsi, 211512340);
+                assert_eq!(bbm.dac, 200);
+                assert_eq!(bbm.fid, 10);
+                match bbm.application {
+                    Some(BroadcastApplication::InlandStaticVoyageData(ref isvd)) => {
+                        assert_eq!(isvd.eni, Some("02310456".into()));
+                        assert::close(isvd.length_meters.unwrap_or(0.0), 110.0, 0.01);
+                        assert::close(isvd.beam_meters.unwrap_or(0.0), 11.4, 0.01);
+                        assert_eq!(isvd.eri_ship_type, EriShipType::ContainerVessel);
+                        assert_eq!(isvd.ship_type, ShipType::Cargo);
+                        assert_eq!(isvd.hazardous_cargo, Some(HazardousCargo::OneBlueCone));
+                        assert::close(isvd.draught_meters.unwrap_or(0.0), 2.8, 0.001);
+                        assert_eq!(isvd.loaded, Some(true));
+                        assert!(isvd.high_speed_quality);
+                        assert!(isvd.high_course_quality);
+                        assert!(!isvd.high_heading_quality);
+                    }
+                    _ => panic!("Unexpected application: {:?}", bbm.application),
+                }
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Water levels
+        match p.parse_sentence("!AIVDM,1,1,,A,839ed50j611@jhO@IP1p00000000,0*59") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(
+                    bbm.application,
+                    Some(BroadcastApplication::InlandWaterLevels(InlandWaterLevels {
+                        country_code: "DE".into(),
+                        gauges: vec![
+                            GaugeWaterLevel {
+                                gauge_id: 101,
+                                water_level_cm: Some(250),
+                            },
+                            GaugeWaterLevel {
+                                gauge_id: 102,
+                                water_level_cm: Some(-30),
+                            },
+                        ],
+                    }))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Number of persons on board
+        match p.parse_sentence("!AIVDM,1,1,,A,839ed50j=h@3iwP00000000,2*10") {
+            Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                assert_eq!(bbm.data.len(), 138 - 2 - 56);
+                assert_eq!(
+                    bbm.application,
+                    Some(BroadcastApplication::InlandPersonsOnBoard(
+                        InlandPersonsOnBoard {
+                            crew: Some(4),
+                            passengers: Some(120),
+                            shipboard_personnel: None,
+                        }
+                    ))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+}
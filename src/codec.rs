@@ -0,0 +1,158 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Tokio codec for framing and parsing NMEA sentences
+
+use super::*;
+use alloc::collections::VecDeque;
+use bytes::BytesMut;
+use tokio_util::codec::Decoder;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Decoder driving an owned `NmeaParser`. Use it with `tokio_util::codec::FramedRead` for byte
+/// streams such as serial ports and TCP connections, or with `tokio_util::udp::UdpFramed` for UDP
+/// sockets, to get a `Stream` of parsed messages. The bytes are framed with
+/// `NmeaParser::push_bytes`, so each decoded item is the result of one complete message, and parse
+/// errors are returned as items without terminating the stream.
+pub struct NmeaCodec {
+    parser: NmeaParser,
+    results: VecDeque<Result<ParsedMessage, ParseError>>,
+}
+
+impl Default for NmeaCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NmeaCodec {
+    /// Construct a codec with a new `NmeaParser`.
+    pub fn new() -> NmeaCodec {
+        NmeaCodec::with_parser(NmeaParser::new())
+    }
+
+    /// Construct a codec using the given, possibly pre-configured, parser.
+    pub fn with_parser(parser: NmeaParser) -> NmeaCodec {
+        NmeaCodec {
+            parser,
+            results: VecDeque::new(),
+        }
+    }
+
+    /// Return reference to the parser.
+    pub fn parser(&self) -> &NmeaParser {
+        &self.parser
+    }
+
+    /// Return mutable reference to the parser, e.g. for setting the current time.
+    pub fn parser_mut(&mut self) -> &mut NmeaParser {
+        &mut self.parser
+    }
+}
+
+impl Decoder for NmeaCodec {
+    type Item = Result<ParsedMessage, ParseError>;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if !src.is_empty() {
+            self.results.extend(self.parser.push_bytes(src));
+            src.clear();
+        }
+        Ok(self.results.pop_front())
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        // Terminate the last line (or UDP datagram) in case it lacks a line terminator
+        self.results.extend(self.parser.push_bytes(src));
+        self.results.extend(self.parser.push_bytes(b"\n"));
+        src.clear();
+        Ok(self.results.pop_front())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::StreamExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::UdpSocket;
+    use tokio_util::codec::FramedRead;
+    use tokio_util::udp::UdpFramed;
+
+    #[tokio::test]
+    async fn test_nmea_codec_duplex() {
+        let (mut tx, rx) = tokio::io::duplex(16);
+        let writer = tokio::spawn(async move {
+            tx.write_all(b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n")
+                .await
+                .unwrap();
+            tx.write_all(b"garbage\n$IIHDT,15.0,T*17\n").await.unwrap();
+            tx.write_all(b"!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C\n")
+                .await
+                .unwrap();
+            tx.write_all(b"!AIVDM,2,2,1,A,88888888880,2*25")
+                .await
+                .unwrap();
+        });
+
+        let results: Vec<_> = FramedRead::new(rx, NmeaCodec::new())
+            .map(|r| r.unwrap())
+            .collect()
+            .await;
+        writer.await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], Ok(ParsedMessage::Gga(_))));
+        assert!(matches!(&results[1], Err(e) if e.kind() == ParseErrorKind::BadChecksum));
+        match &results[2] {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => assert_eq!(vsd.mmsi, 351759000),
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nmea_codec_udp() {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        let sender = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        sender
+            .send_to(b"!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A", addr)
+            .await
+            .unwrap();
+        sender
+            .send_to(b"$IIHDT,15.0,T*16\r\n$INMTW,17.9,C*1B\r\n", addr)
+            .await
+            .unwrap();
+
+        let mut stream = UdpFramed::new(socket, NmeaCodec::new());
+        let mut results = Vec::new();
+        while results.len() < 3 {
+            let (result, source) = stream.next().await.unwrap().unwrap();
+            assert_eq!(source, sender.local_addr().unwrap());
+            results.push(result);
+        }
+        assert!(matches!(
+            results[0],
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert!(matches!(results[1], Ok(ParsedMessage::Hdt(_))));
+        assert!(matches!(results[2], Ok(ParsedMessage::Mtw(_))));
+    }
+}
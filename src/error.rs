@@ -16,48 +16,277 @@ limitations under the License.
 
 use core::fmt;
 use core::num::{ParseIntError, ParseFloatError};
-use alloc::string::String;
+use alloc::string::{String, ToString};
 
-/// Parse error returned by `NmeaParser::parse_sentence()`. `String` data type is used instead of
-/// `static &str` because the error messages are expected to contain context-specific details.
+/// Parse error returned by `NmeaParser::parse_sentence()`. Besides the human-readable message the
+/// error carries a machine-readable kind and, when known, the sentence type, the location and the
+/// name of the offending field and its raw value.
 #[derive(Clone, Debug, PartialEq)]
-pub enum ParseError {
+pub struct ParseError {
+    kind: ParseErrorKind,
+    sentence_type: Option<String>,
+    location: Option<ErrorLocation>,
+    field_name: Option<&'static str>,
+    raw_value: Option<String>,
+    message: String,
+}
+
+/// Cause of a `ParseError`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
     /// Unsupported (or unimplemented) sentence type
-    UnsupportedSentenceType(String),
+    UnsupportedSentenceType,
+
+    /// Unsupported (or unimplemented) AIS VDM/VDO message type
+    UnsupportedMessageType,
 
     /// NMEA checksum doesn't match
-    CorruptedSentence(String),
+    BadChecksum,
+
+    /// Talker or AIS station identifier isn't valid
+    BadTalker,
+
+    /// Mandatory field is empty or missing
+    MissingField,
+
+    /// Field value can't be parsed
+    InvalidField,
+
+    /// Field value is parseable but outside of the valid range
+    OutOfRange,
 
     /// The sentence format isn't what expected
-    InvalidSentence(String),
+    InvalidSentence,
+
+    /// Reading the underlying data source failed
+    Io,
+
+    /// The sentence breaks the given rule of the strict mode
+    StrictViolation(StrictRule),
+}
+
+/// Rule checked in the strict mode of `NmeaParser`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrictRule {
+    /// The sentence has no checksum
+    MissingChecksum,
+
+    /// The checksum has lowercase hexadecimal digits
+    LowercaseChecksum,
+
+    /// The sentence is longer than 82 characters including the line terminator
+    SentenceLength,
+
+    /// The number of fields doesn't match the sentence type
+    FieldCount,
+
+    /// AIS fill bits aren't within 0-5, or they are non-zero in a non-final fragment
+    FillBits,
+
+    /// AIS latitude is outside of -90°..90° and isn't the "not available" value 91°
+    Latitude,
+
+    /// AIS longitude is outside of -180°..180° and isn't the "not available" value 181°
+    Longitude,
+
+    /// AIS true heading is within 360-510
+    Heading,
+
+    /// AIS navigation status is one of the reserved values 9-13
+    NavigationStatus,
+}
+
+/// Location of the offending data within a sentence
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorLocation {
+    /// Index of a comma-separated field. The sentence type is field 0.
+    Field(usize),
+
+    /// Bit offset within an AIS VDM/VDO payload
+    Bit(usize),
+}
+
+impl ParseError {
+    /// Create a new error of the given kind with a human-readable message.
+    pub fn new(kind: ParseErrorKind, message: impl Into<String>) -> ParseError {
+        ParseError {
+            kind,
+            sentence_type: None,
+            location: None,
+            field_name: None,
+            raw_value: None,
+            message: message.into(),
+        }
+    }
+
+    /// Set the sentence type (e.g. "GGA").
+    pub fn with_sentence_type(mut self, sentence_type: &str) -> ParseError {
+        self.sentence_type = Some(sentence_type.to_string());
+        self
+    }
+
+    /// Set the index and name of the offending comma-separated field.
+    pub fn with_field(mut self, index: usize, name: &'static str) -> ParseError {
+        self.location = Some(ErrorLocation::Field(index));
+        self.field_name = Some(name);
+        self
+    }
+
+    /// Set the bit offset and name of the offending AIS payload field.
+    pub fn with_bit(mut self, offset: usize, name: &'static str) -> ParseError {
+        self.location = Some(ErrorLocation::Bit(offset));
+        self.field_name = Some(name);
+        self
+    }
+
+    /// Set the raw value of the offending field.
+    pub fn with_raw_value(mut self, raw_value: &str) -> ParseError {
+        self.raw_value = Some(raw_value.to_string());
+        self
+    }
+
+    /// Machine-readable cause of the error
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Sentence type without the start character and the talker ID (e.g. "GGA" or "VDM"), if
+    /// known
+    pub fn sentence_type(&self) -> Option<&str> {
+        self.sentence_type.as_deref()
+    }
+
+    /// Location of the offending field, if known
+    pub fn location(&self) -> Option<ErrorLocation> {
+        self.location
+    }
+
+    /// Name of the offending field, if known
+    pub fn field_name(&self) -> Option<&'static str> {
+        self.field_name
+    }
+
+    /// Raw value of the offending field, if known
+    pub fn raw_value(&self) -> Option<&str> {
+        self.raw_value.as_deref()
+    }
+
+    /// Human-readable description of the error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
 
 impl From<String> for ParseError {
     fn from(s: String) -> Self {
-        ParseError::InvalidSentence(s)
+        ParseError::new(ParseErrorKind::InvalidSentence, s)
     }
 }
 
 impl From<ParseIntError> for ParseError {
     fn from(e: ParseIntError) -> Self {
-        ParseError::InvalidSentence(format!("{}", e))
+        ParseError::new(ParseErrorKind::InvalidField, format!("{}", e))
     }
 }
 
 impl From<ParseFloatError> for ParseError {
     fn from(e: ParseFloatError) -> Self {
-        ParseError::InvalidSentence(format!("{}", e))
+        ParseError::new(ParseErrorKind::InvalidField, format!("{}", e))
     }
 }
 
-impl fmt::Display for ParseError {
+impl fmt::Display for ParseErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ParseError::UnsupportedSentenceType(s) => {
-                write!(f, "Unsupported NMEA sentence type: {}", s)
-            }
-            ParseError::CorruptedSentence(s) => write!(f, "Corrupted NMEA sentence: {}", s),
-            ParseError::InvalidSentence(s) => write!(f, "Invalid NMEA sentence: {}", s),
+            ParseErrorKind::UnsupportedSentenceType => write!(f, "Unsupported NMEA sentence type"),
+            ParseErrorKind::UnsupportedMessageType => write!(f, "Unsupported AIS message type"),
+            ParseErrorKind::BadChecksum => write!(f, "Corrupted NMEA sentence"),
+            ParseErrorKind::BadTalker => write!(f, "Invalid talker identifier"),
+            ParseErrorKind::MissingField => write!(f, "Missing field"),
+            ParseErrorKind::InvalidField => write!(f, "Invalid field"),
+            ParseErrorKind::OutOfRange => write!(f, "Value out of range"),
+            ParseErrorKind::InvalidSentence => write!(f, "Invalid NMEA sentence"),
+            ParseErrorKind::Io => write!(f, "I/O error"),
+            ParseErrorKind::StrictViolation(rule) => write!(f, "Strict rule violated ({})", rule),
         }
     }
 }
+
+impl fmt::Display for StrictRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrictRule::MissingChecksum => write!(f, "missing checksum"),
+            StrictRule::LowercaseChecksum => write!(f, "lowercase checksum"),
+            StrictRule::SentenceLength => write!(f, "sentence length"),
+            StrictRule::FieldCount => write!(f, "field count"),
+            StrictRule::FillBits => write!(f, "fill bits"),
+            StrictRule::Latitude => write!(f, "latitude"),
+            StrictRule::Longitude => write!(f, "longitude"),
+            StrictRule::Heading => write!(f, "heading"),
+            StrictRule::NavigationStatus => write!(f, "navigation status"),
+        }
+    }
+}
+
+impl fmt::Display for ErrorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorLocation::Field(index) => write!(f, "field {}", index),
+            ErrorLocation::Bit(offset) => write!(f, "bit {}", offset),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(sentence_type) = &self.sentence_type {
+            write!(f, " in {}", sentence_type)?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " at {}", location)?;
+        }
+        if let Some(field_name) = self.field_name {
+            write!(f, " ({})", field_name)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        let e = ParseError::new(ParseErrorKind::InvalidField, "Failed to parse \"abc\"")
+            .with_sentence_type("GGA")
+            .with_field(9, "altitude")
+            .with_raw_value("abc");
+        assert_eq!(e.kind(), ParseErrorKind::InvalidField);
+        assert_eq!(e.sentence_type(), Some("GGA"));
+        assert_eq!(e.location(), Some(ErrorLocation::Field(9)));
+        assert_eq!(e.field_name(), Some("altitude"));
+        assert_eq!(e.raw_value(), Some("abc"));
+        assert_eq!(
+            e.to_string(),
+            "Invalid field in GGA at field 9 (altitude): Failed to parse \"abc\""
+        );
+
+        let e = ParseError::new(
+            ParseErrorKind::UnsupportedMessageType,
+            "Unsupported type: 63",
+        )
+        .with_bit(0, "message_type");
+        assert_eq!(
+            e.to_string(),
+            "Unsupported AIS message type at bit 0 (message_type): Unsupported type: 63"
+        );
+
+        let e: ParseError = String::from("No sentences").into();
+        assert_eq!(e.kind(), ParseErrorKind::InvalidSentence);
+        assert_eq!(e.to_string(), "Invalid NMEA sentence: No sentences");
+    }
+}
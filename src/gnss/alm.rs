@@ -14,12 +14,14 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
 /// ALM - GPS Almanac Data
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AlmData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -75,24 +77,52 @@ pub(crate) fn handle(
 
     Ok(ParsedMessage::Alm(AlmData {
         source: nav_system,
-        prn: pick_hex_field(&split, 3)?,
-        week_number: { pick_hex_field::<u16>(&split, 4)?.map(|wk| wk & 0x3ff) },
-        health_bits: pick_hex_field(&split, 5)?,
-        eccentricity: pick_hex_field(&split, 6)?,
-        reference_time: pick_hex_field(&split, 7)?,
-        sigma: pick_hex_field(&split, 8)?,
-        omega_dot: pick_hex_field(&split, 9)?,
-        root_a: pick_hex_field(&split, 10)?,
-        omega: pick_hex_field(&split, 11)?,
-        omega_o: pick_hex_field(&split, 12)?,
-        mo: pick_hex_field(&split, 13)?,
-        af0: pick_hex_field(&split, 14)?,
-        af1: pick_hex_field(&split, 15)?,
+        prn: pick_hex_field(&split, 3, "prn")?,
+        week_number: { pick_hex_field::<u16>(&split, 4, "week_number")?.map(|wk| wk & 0x3ff) },
+        health_bits: pick_hex_field(&split, 5, "health_bits")?,
+        eccentricity: pick_hex_field(&split, 6, "eccentricity")?,
+        reference_time: pick_hex_field(&split, 7, "reference_time")?,
+        sigma: pick_hex_field(&split, 8, "sigma")?,
+        omega_dot: pick_hex_field(&split, 9, "omega_dot")?,
+        root_a: pick_hex_field(&split, 10, "root_a")?,
+        omega: pick_hex_field(&split, 11, "omega")?,
+        omega_o: pick_hex_field(&split, 12, "omega_o")?,
+        mo: pick_hex_field(&split, 13, "mo")?,
+        af0: pick_hex_field(&split, 14, "af0")?,
+        af1: pick_hex_field(&split, 15, "af1")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for AlmData {
+    /// Encode the almanac as a stand-alone xxALM sentence (sentence 1 of 1).
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}ALM,1,1,{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                talker_id,
+                format_hex_field(self.prn, 2),
+                format_hex_field(self.week_number, 4),
+                format_hex_field(self.health_bits, 2),
+                format_hex_field(self.eccentricity, 4),
+                format_hex_field(self.reference_time, 2),
+                format_hex_field(self.sigma, 4),
+                format_hex_field(self.omega_dot, 4),
+                format_hex_field(self.root_a, 6),
+                format_hex_field(self.omega, 6),
+                format_hex_field(self.omega_o, 6),
+                format_hex_field(self.mo, 6),
+                format_hex_field(self.af0, 3),
+                format_hex_field(self.af1, 3),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -131,4 +161,20 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_alm() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GPALM,31,1,02,1617,00,50F6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,148,001*",
+        ) {
+            Ok(ParsedMessage::Alm(alm)) => {
+                let sentence = alm.to_sentence("GP");
+                assert_eq!(sentence, "$GPALM,1,1,02,0217,00,50F6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,148,001*0A");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Alm(alm)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -0,0 +1,235 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// APB - heading/track controller (autopilot) sentence B
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ApbData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// True = data valid, false = Loran-C blink or SNR warning
+    pub data_valid: Option<bool>,
+
+    /// True = cycle lock valid, false = Loran-C cycle lock warning
+    pub cycle_lock: Option<bool>,
+
+    /// Cross track error in nautical miles
+    pub cross_track_error: Option<f64>,
+
+    /// Direction to steer to correct the cross track error
+    pub steer_direction: Option<SteerDirection>,
+
+    /// Unit of the cross track error in the received sentence
+    pub cross_track_error_unit: Option<DistanceUnit>,
+
+    /// True = arrival circle entered
+    pub arrival_circle_entered: Option<bool>,
+
+    /// True = perpendicular passed at the destination waypoint
+    pub perpendicular_passed: Option<bool>,
+
+    /// Bearing from origin to destination in degrees (True)
+    pub bearing_origin_to_destination_true: Option<f64>,
+
+    /// Bearing from origin to destination in degrees (Magnetic)
+    pub bearing_origin_to_destination_magnetic: Option<f64>,
+
+    /// Destination waypoint ID
+    pub destination_waypoint_id: Option<String>,
+
+    /// Bearing from present position to destination in degrees (True)
+    pub bearing_to_destination_true: Option<f64>,
+
+    /// Bearing from present position to destination in degrees (Magnetic)
+    pub bearing_to_destination_magnetic: Option<f64>,
+
+    /// Heading to steer to destination in degrees (True)
+    pub heading_to_steer_true: Option<f64>,
+
+    /// Heading to steer to destination in degrees (Magnetic)
+    pub heading_to_steer_magnetic: Option<f64>,
+
+    /// FAA mode indicator (NMEA 2.3 and later)
+    pub faa_mode: Option<FaaMode>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxAPB: Heading/Track Controller (Autopilot) Sentence "B"
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let (bearing_origin_to_destination_true, bearing_origin_to_destination_magnetic) =
+        pick_bearing(&split, 8, "bearing_origin_to_destination")?;
+    let (bearing_to_destination_true, bearing_to_destination_magnetic) =
+        pick_bearing(&split, 11, "bearing_to_destination")?;
+    let (heading_to_steer_true, heading_to_steer_magnetic) =
+        pick_bearing(&split, 13, "heading_to_steer")?;
+
+    Ok(ParsedMessage::Apb(ApbData {
+        source: nav_system,
+        data_valid: pick_status_field(&split, 1),
+        cycle_lock: pick_status_field(&split, 2),
+        cross_track_error: xte::pick_cross_track_error(&split, 3)?,
+        steer_direction: SteerDirection::new(split.get(4).unwrap_or(&"")).ok(),
+        cross_track_error_unit: DistanceUnit::new(split.get(5).unwrap_or(&"")).ok(),
+        arrival_circle_entered: pick_status_field(&split, 6),
+        perpendicular_passed: pick_status_field(&split, 7),
+        bearing_origin_to_destination_true,
+        bearing_origin_to_destination_magnetic,
+        destination_waypoint_id: pick_string_field(&split, 10),
+        bearing_to_destination_true,
+        bearing_to_destination_magnetic,
+        heading_to_steer_true,
+        heading_to_steer_magnetic,
+        faa_mode: FaaMode::new(split.get(15).unwrap_or(&"")).ok(),
+    }))
+}
+
+/// Pick bearing field followed by its reference ("T" for true, "M" for magnetic). Return the
+/// bearing as a tuple of true and magnetic bearing.
+fn pick_bearing(
+    split: &[&str],
+    num: usize,
+    name: &'static str,
+) -> Result<(Option<f64>, Option<f64>), ParseError> {
+    let bearing = pick_number_field(split, num, name)?;
+    match *split.get(num + 1).unwrap_or(&"") {
+        "T" => Ok((bearing, None)),
+        "M" => Ok((None, bearing)),
+        s => {
+            if bearing.is_some() {
+                Err(ParseError::new(
+                    ParseErrorKind::InvalidField,
+                    format!("Invalid bearing reference: {}", s),
+                )
+                .with_field(num + 1, "bearing_reference")
+                .with_raw_value(s))
+            } else {
+                Ok((None, None))
+            }
+        }
+    }
+}
+
+/// Format bearing field and its reference from the true and magnetic bearing. True bearing is
+/// preferred if both are given.
+fn format_bearing(bearing_true: Option<f64>, bearing_magnetic: Option<f64>) -> String {
+    match (bearing_true, bearing_magnetic) {
+        (Some(b), _) => format!("{},T", format_number_field(Some(b), 1)),
+        (None, Some(b)) => format!("{},M", format_number_field(Some(b), 1)),
+        (None, None) => ",".into(),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for ApbData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let mut content = format!(
+            "{}APB,{},{},{},{},{},{},{},{},{}",
+            talker_id,
+            format_status_field(self.data_valid),
+            format_status_field(self.cycle_lock),
+            xte::format_cross_track_error(
+                self.cross_track_error,
+                self.steer_direction,
+                self.cross_track_error_unit
+            ),
+            format_status_field(self.arrival_circle_entered),
+            format_status_field(self.perpendicular_passed),
+            format_bearing(
+                self.bearing_origin_to_destination_true,
+                self.bearing_origin_to_destination_magnetic
+            ),
+            format_string_field(&self.destination_waypoint_id),
+            format_bearing(
+                self.bearing_to_destination_true,
+                self.bearing_to_destination_magnetic
+            ),
+            format_bearing(self.heading_to_steer_true, self.heading_to_steer_magnetic),
+        );
+        if let Some(faa_mode) = self.faa_mode {
+            content.push_str(&format!(",{}", faa_mode.to_value()));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpapb() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C") {
+            Ok(ParsedMessage::Apb(apb)) => {
+                assert_eq!(apb.source, NavigationSystem::Gps);
+                assert_eq!(apb.data_valid, Some(true));
+                assert_eq!(apb.cycle_lock, Some(true));
+                assert_eq!(apb.cross_track_error, Some(0.1));
+                assert_eq!(apb.steer_direction, Some(SteerDirection::Right));
+                assert_eq!(apb.arrival_circle_entered, Some(false));
+                assert_eq!(apb.perpendicular_passed, Some(false));
+                assert_eq!(apb.bearing_origin_to_destination_true, None);
+                assert_eq!(apb.bearing_origin_to_destination_magnetic, Some(11.0));
+                assert_eq!(apb.destination_waypoint_id, Some("DEST".into()));
+                assert_eq!(apb.bearing_to_destination_magnetic, Some(11.0));
+                assert_eq!(apb.heading_to_steer_magnetic, Some(11.0));
+                assert_eq!(apb.faa_mode, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_apb() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C") {
+            Ok(ParsedMessage::Apb(apb)) => {
+                let sentence = apb.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPAPB,A,A,0.1,R,N,V,V,11.0,M,DEST,11.0,M,11.0,M*22"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Apb(apb)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Cross track error is encoded in the received unit
+        let s = "$GPAPB,A,A,0.185,L,K,V,V,11.0,T,DEST,12.5,T,13.0,T*29";
+        match p.parse_sentence(s) {
+            Ok(ParsedMessage::Apb(apb)) => {
+                assert::close(apb.cross_track_error.unwrap_or(0.0), 0.1, 0.001);
+                assert_eq!(apb.cross_track_error_unit, Some(DistanceUnit::Kilometers));
+                assert_eq!(apb.to_sentence("GP"), s);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// BOD - bearing, origin to destination
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BodData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// Bearing from origin to destination in degrees (True)
+    pub bearing_true: Option<f64>,
+
+    /// Bearing from origin to destination in degrees (Magnetic)
+    pub bearing_magnetic: Option<f64>,
+
+    /// Destination waypoint ID
+    pub destination_waypoint_id: Option<String>,
+
+    /// Origin waypoint ID
+    pub origin_waypoint_id: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxBOD: Bearing - Origin to Destination
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Bod(BodData {
+        source: nav_system,
+        bearing_true: pick_number_field(&split, 1, "bearing_true")?,
+        bearing_magnetic: pick_number_field(&split, 3, "bearing_magnetic")?,
+        destination_waypoint_id: pick_string_field(&split, 5),
+        origin_waypoint_id: pick_string_field(&split, 6),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for BodData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}BOD,{},T,{},M,{},{}",
+                talker_id,
+                format_number_field(self.bearing_true, 1),
+                format_number_field(self.bearing_magnetic, 1),
+                format_string_field(&self.destination_waypoint_id),
+                format_string_field(&self.origin_waypoint_id),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpbod() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45") {
+            Ok(ParsedMessage::Bod(bod)) => {
+                assert_eq!(bod.source, NavigationSystem::Gps);
+                assert_eq!(bod.bearing_true, Some(99.3));
+                assert_eq!(bod.bearing_magnetic, Some(105.6));
+                assert_eq!(bod.destination_waypoint_id, Some("POINTB".into()));
+                assert_eq!(bod.origin_waypoint_id, Some("POINTA".into()));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_bod() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45") {
+            Ok(ParsedMessage::Bod(bod)) => {
+                let sentence = bod.to_sentence("GP");
+                assert_eq!(sentence, "$GPBOD,99.3,T,105.6,M,POINTB,POINTA*75");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Bod(bod)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+}
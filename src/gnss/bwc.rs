@@ -0,0 +1,201 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// BWC/BWR - bearing and distance to waypoint along great circle (BWC) or rhumb line (BWR)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BwcData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of observation
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Latitude of the waypoint in degrees
+    pub waypoint_latitude: Option<f64>,
+
+    /// Longitude of the waypoint in degrees
+    pub waypoint_longitude: Option<f64>,
+
+    /// Bearing to the waypoint in degrees (True)
+    pub bearing_true: Option<f64>,
+
+    /// Bearing to the waypoint in degrees (Magnetic)
+    pub bearing_magnetic: Option<f64>,
+
+    /// Distance to the waypoint in nautical miles
+    pub distance: Option<f64>,
+
+    /// Waypoint ID
+    pub waypoint_id: Option<String>,
+
+    /// FAA mode indicator (NMEA 2.3 and later)
+    pub faa_mode: Option<FaaMode>,
+}
+
+impl BwcData {
+    /// Encode the data as a complete BWR sentence. `ToSentence::to_sentence` encodes a BWC
+    /// sentence.
+    pub fn to_bwr_sentence(&self, talker_id: &str) -> String {
+        self.encode(talker_id, "BWR")
+    }
+
+    fn encode(&self, talker_id: &str, sentence_type: &str) -> String {
+        let (latitude, ns) = format_latitude_ddmm_mmm(self.waypoint_latitude);
+        let (longitude, ew) = format_longitude_dddmm_mmm(self.waypoint_longitude);
+        let mut content = format!(
+            "{}{},{},{},{},{},{},{},T,{},M,{},N,{}",
+            talker_id,
+            sentence_type,
+            format_hhmmss_ss(self.timestamp),
+            latitude,
+            ns,
+            longitude,
+            ew,
+            format_number_field(self.bearing_true, 1),
+            format_number_field(self.bearing_magnetic, 1),
+            format_number_field(self.distance, 1),
+            format_string_field(&self.waypoint_id),
+        );
+        if let Some(faa_mode) = self.faa_mode {
+            content.push_str(&format!(",{}", faa_mode.to_value()));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxBWC: Bearing & Distance to Waypoint - Great Circle, and xxBWR: Bearing and Distance to
+/// Waypoint - Rhumb Line
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    let data = BwcData {
+        source: nav_system,
+        timestamp: parse_hhmmss_ss(
+            split.get(1).unwrap_or(&""),
+            now.unwrap_or_else(default_reference_time),
+        )
+        .map(|t| nearest_day(t, now))
+        .ok(),
+        waypoint_latitude: parse_latitude_ddmm_mmm(
+            split.get(2).unwrap_or(&""),
+            split.get(3).unwrap_or(&""),
+        )
+        .map_err(|e| e.with_field(2, "waypoint_latitude"))?,
+        waypoint_longitude: parse_longitude_dddmm_mmm(
+            split.get(4).unwrap_or(&""),
+            split.get(5).unwrap_or(&""),
+        )
+        .map_err(|e| e.with_field(4, "waypoint_longitude"))?,
+        bearing_true: pick_number_field(&split, 6, "bearing_true")?,
+        bearing_magnetic: pick_number_field(&split, 8, "bearing_magnetic")?,
+        distance: pick_number_field(&split, 10, "distance")?,
+        waypoint_id: pick_string_field(&split, 12),
+        faa_mode: FaaMode::new(split.get(13).unwrap_or(&"")).ok(),
+    };
+
+    if split.first().unwrap_or(&"").ends_with("BWR") {
+        Ok(ParsedMessage::Bwr(data))
+    } else {
+        Ok(ParsedMessage::Bwc(data))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for BwcData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        self.encode(talker_id, "BWC")
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpbwc() {
+        let mut p = NmeaParser::new();
+        match p
+            .parse_sentence("$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21")
+        {
+            Ok(ParsedMessage::Bwc(bwc)) => {
+                assert_eq!(bwc.source, NavigationSystem::Gps);
+                assert_eq!(
+                    bwc.timestamp,
+                    Utc.with_ymd_and_hms(2000, 1, 1, 22, 5, 16).single()
+                );
+                assert::close(bwc.waypoint_latitude.unwrap_or(0.0), 51.500, 0.001);
+                assert::close(bwc.waypoint_longitude.unwrap_or(0.0), -0.772, 0.001);
+                assert_eq!(bwc.bearing_true, Some(213.8));
+                assert_eq!(bwc.bearing_magnetic, Some(218.0));
+                assert_eq!(bwc.distance, Some(4.6));
+                assert_eq!(bwc.waypoint_id, Some("EGLM".into()));
+                assert_eq!(bwc.faa_mode, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        match p
+            .parse_sentence("$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM,A*5D")
+        {
+            Ok(ParsedMessage::Bwr(bwr)) => {
+                assert_eq!(bwr.distance, Some(4.6));
+                assert_eq!(bwr.waypoint_id, Some("EGLM".into()));
+                assert_eq!(bwr.faa_mode, Some(FaaMode::Autonomous));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_bwc() {
+        let mut p = NmeaParser::new();
+        match p
+            .parse_sentence("$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21")
+        {
+            Ok(ParsedMessage::Bwc(bwc)) => {
+                let sentence = bwc.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPBWC,220516.00,5130.020,N,00046.340,W,213.8,T,218.0,M,4.6,N,EGLM*3F"
+                );
+                assert_eq!(
+                    p.parse_sentence(&sentence),
+                    Ok(ParsedMessage::Bwc(bwc.clone()))
+                );
+                assert_eq!(
+                    p.parse_sentence(&bwc.to_bwr_sentence("GP")),
+                    Ok(ParsedMessage::Bwr(bwc))
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+}
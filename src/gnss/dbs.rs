@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// DBS - Depth Below Surface
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DbsData {
     /// Water depth below surface, meters
     pub depth_meters: Option<f64>,
@@ -36,14 +37,31 @@ pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Dbs(DbsData {
-        depth_meters: pick_number_field(&split, 3)?,
-        depth_feet: pick_number_field(&split, 1)?,
-        depth_fathoms: pick_number_field(&split, 5)?,
+        depth_meters: pick_number_field(&split, 3, "depth_meters")?,
+        depth_feet: pick_number_field(&split, 1, "depth_feet")?,
+        depth_fathoms: pick_number_field(&split, 5, "depth_fathoms")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for DbsData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}DBS,{},f,{},M,{},F",
+                talker_id,
+                format_number_field(self.depth_feet, 2),
+                format_number_field(self.depth_meters, 2),
+                format_number_field(self.depth_fathoms, 2),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -70,4 +88,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_dbs() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$SDDBS,16.9,f,5.2,M,2.8,F*32") {
+            Ok(ParsedMessage::Dbs(dbs)) => {
+                let sentence = dbs.to_sentence("SD");
+                assert_eq!(sentence, "$SDDBS,16.9,f,5.2,M,2.8,F*32");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Dbs(dbs)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// DPT - Depth of Water
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DptData {
     /// Water depth relative to transducer, meters
     pub depth_relative_to_transducer: Option<f64>,
@@ -33,13 +34,29 @@ pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Dpt(DptData {
-        depth_relative_to_transducer: pick_number_field(&split, 1)?,
-        transducer_offset: pick_number_field(&split, 2)?,
+        depth_relative_to_transducer: pick_number_field(&split, 1, "depth_relative_to_transducer")?,
+        transducer_offset: pick_number_field(&split, 2, "transducer_offset")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for DptData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}DPT,{},{}",
+                talker_id,
+                format_number_field(self.depth_relative_to_transducer, 2),
+                format_number_field(self.transducer_offset, 2),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -65,4 +82,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_dpt() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$SDDPT,17.5,0.3*67") {
+            Ok(ParsedMessage::Dpt(dpt)) => {
+                let sentence = dpt.to_sentence("SD");
+                assert_eq!(sentence, "$SDDPT,17.5,0.3*67");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Dpt(dpt)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
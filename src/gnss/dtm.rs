@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// DTM - Datum being used
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DtmData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -54,15 +55,41 @@ pub(crate) fn handle(
         source: nav_system,
         datum_id: pick_string_field(&split, 1),
         datum_sub_id: pick_string_field(&split, 2),
-        lat_offset: parse_latitude_m_m(split.get(3).unwrap_or(&""), split.get(4).unwrap_or(&""))?,
-        lon_offset: parse_longitude_m_m(split.get(5).unwrap_or(&""), split.get(6).unwrap_or(&""))?,
-        alt_offset: pick_number_field(&split, 7)?,
+        lat_offset: parse_latitude_m_m(split.get(3).unwrap_or(&""), split.get(4).unwrap_or(&""))
+            .map_err(|e| e.with_field(3, "lat_offset"))?,
+        lon_offset: parse_longitude_m_m(split.get(5).unwrap_or(&""), split.get(6).unwrap_or(&""))
+            .map_err(|e| e.with_field(5, "lon_offset"))?,
+        alt_offset: pick_number_field(&split, 7, "alt_offset")?,
         ref_datum_id: pick_string_field(&split, 8),
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for DtmData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (lat_offset, ns) = format_latitude_m_m(self.lat_offset);
+        let (lon_offset, ew) = format_longitude_m_m(self.lon_offset);
+        make_sentence(
+            '$',
+            &format!(
+                "{}DTM,{},{},{},{},{},{},{},{}",
+                talker_id,
+                format_string_field(&self.datum_id),
+                format_string_field(&self.datum_sub_id),
+                lat_offset,
+                ns,
+                lon_offset,
+                ew,
+                format_number_field(self.alt_offset, 3),
+                format_string_field(&self.ref_datum_id),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -92,4 +119,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_dtm() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPDTM,999,,0.002,S,0.005,E,005.8,W84*1A") {
+            Ok(ParsedMessage::Dtm(dtm)) => {
+                let sentence = dtm.to_sentence("GP");
+                assert_eq!(sentence, "$GPDTM,999,,0.002,S,0.005,E,5.8,W84*1A");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Dtm(dtm)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
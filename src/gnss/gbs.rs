@@ -0,0 +1,183 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// GBS - GNSS satellite fault detection (RAIM)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GbsData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of the associated position fix
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Expected error in latitude (metres)
+    pub latitude_error: Option<f64>,
+
+    /// Expected error in longitude (metres)
+    pub longitude_error: Option<f64>,
+
+    /// Expected error in altitude (metres)
+    pub altitude_error: Option<f64>,
+
+    /// ID of the most likely failed satellite
+    pub failed_satellite_id: Option<u8>,
+
+    /// Probability of missed detection of the most likely failed satellite
+    pub missed_detection_probability: Option<f64>,
+
+    /// Estimate of the bias on the most likely failed satellite (metres)
+    pub bias_estimate: Option<f64>,
+
+    /// Standard deviation of the bias estimate (metres)
+    pub bias_std_dev: Option<f64>,
+
+    /// GNSS system of the failed satellite (NMEA 4.1 and later)
+    pub system: Option<NavigationSystem>,
+
+    /// GNSS signal of the failed satellite (NMEA 4.1 and later)
+    pub signal: Option<GnssSignal>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxGBS: GNSS Satellite Fault Detection
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+    let system = pick_hex_field(&split, 9, "system_id")?.and_then(NavigationSystem::from_system_id);
+
+    Ok(ParsedMessage::Gbs(GbsData {
+        source: nav_system,
+        timestamp: parse_hhmmss_ss(
+            split.get(1).unwrap_or(&""),
+            now.unwrap_or_else(default_reference_time),
+        )
+        .map(|t| nearest_day(t, now))
+        .ok(),
+        latitude_error: pick_number_field(&split, 2, "latitude_error")?,
+        longitude_error: pick_number_field(&split, 3, "longitude_error")?,
+        altitude_error: pick_number_field(&split, 4, "altitude_error")?,
+        failed_satellite_id: pick_number_field(&split, 5, "failed_satellite_id")?,
+        missed_detection_probability: pick_number_field(&split, 6, "missed_detection_probability")?,
+        bias_estimate: pick_number_field(&split, 7, "bias_estimate")?,
+        bias_std_dev: pick_number_field(&split, 8, "bias_std_dev")?,
+        system,
+        signal: pick_hex_field(&split, 10, "signal_id")?
+            .map(|id| GnssSignal::new(system.unwrap_or(NavigationSystem::Other), id)),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for GbsData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let mut content = format!(
+            "{}GBS,{},{},{},{},{},{},{},{}",
+            talker_id,
+            format_hhmmss_ss(self.timestamp),
+            format_number_field(self.latitude_error, 3),
+            format_number_field(self.longitude_error, 3),
+            format_number_field(self.altitude_error, 3),
+            format_integer_field(self.failed_satellite_id, 2),
+            format_number_field(self.missed_detection_probability, 3),
+            format_number_field(self.bias_estimate, 3),
+            format_number_field(self.bias_std_dev, 3),
+        );
+        if self.system.is_some() || self.signal.is_some() {
+            content.push_str(&format!(
+                ",{},{}",
+                format_hex_field(self.system.and_then(|s| s.system_id()), 1),
+                format_hex_field(self.signal.map(|s| s.to_value()), 1),
+            ));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpgbs() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972*4D") {
+            Ok(ParsedMessage::Gbs(gbs)) => {
+                assert_eq!(gbs.source, NavigationSystem::Gps);
+                assert_eq!(
+                    gbs.timestamp,
+                    Utc.with_ymd_and_hms(2000, 1, 1, 1, 55, 9).single()
+                );
+                assert_eq!(gbs.latitude_error, Some(-0.031));
+                assert_eq!(gbs.longitude_error, Some(-0.186));
+                assert_eq!(gbs.altitude_error, Some(0.219));
+                assert_eq!(gbs.failed_satellite_id, Some(19));
+                assert_eq!(gbs.missed_detection_probability, Some(0.0));
+                assert_eq!(gbs.bias_estimate, Some(-0.354));
+                assert_eq!(gbs.bias_std_dev, Some(6.972));
+                assert_eq!(gbs.system, None);
+                assert_eq!(gbs.signal, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_gngbs_nmea41() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GNGBS,235503.00,1.6,1.4,3.2,03,,-21.4,3.8,1,0*4D") {
+            Ok(ParsedMessage::Gbs(gbs)) => {
+                assert_eq!(gbs.source, NavigationSystem::Combination);
+                assert_eq!(gbs.failed_satellite_id, Some(3));
+                assert_eq!(gbs.missed_detection_probability, None);
+                assert_eq!(gbs.bias_estimate, Some(-21.4));
+                assert_eq!(gbs.system, Some(NavigationSystem::Gps));
+                assert_eq!(gbs.signal, Some(GnssSignal::All));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_gbs() {
+        let mut p = NmeaParser::new();
+        for s in [
+            "$GPGBS,015509.00,-0.031,-0.186,0.219,19,0.0,-0.354,6.972*4D",
+            "$GNGBS,235503.00,1.6,1.4,3.2,03,,-21.4,3.8,1,0*4D",
+        ] {
+            match p.parse_sentence(s) {
+                Ok(ParsedMessage::Gbs(gbs)) => {
+                    let sentence = gbs.to_sentence(&s[1..3]);
+                    assert_eq!(sentence, s);
+                    assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Gbs(gbs)));
+                }
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+    }
+}
@@ -17,13 +17,14 @@ limitations under the License.
 use super::*;
 
 /// GGA - time, position, and fix related data
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GgaData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// UTC of position fix
-    #[serde(with = "json_date_time_utc")]
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Latitude in degrees
@@ -65,7 +66,8 @@ impl LatLon for GgaData {
 }
 
 /// GGA GPS quality indicator
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GgaQualityIndicator {
     Invalid,                // 0
     GpsFix,                 // 1
@@ -93,6 +95,10 @@ impl GgaQualityIndicator {
             _ => GgaQualityIndicator::Invalid,
         }
     }
+
+    pub fn to_value(&self) -> u8 {
+        *self as u8
+    }
 }
 
 impl core::fmt::Display for GgaQualityIndicator {
@@ -119,33 +125,65 @@ impl core::fmt::Display for GgaQualityIndicator {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
 ) -> Result<ParsedMessage, ParseError> {
-    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Gga(GgaData {
         source: nav_system,
-        timestamp: parse_hhmmss_ss(split.get(1).unwrap_or(&""), now).ok(),
-        latitude: parse_latitude_ddmm_mmm(
-            split.get(2).unwrap_or(&""),
-            split.get(3).unwrap_or(&""),
-        )?,
+        timestamp: parse_hhmmss_ss(
+            split.get(1).unwrap_or(&""),
+            now.unwrap_or_else(default_reference_time),
+        )
+        .map(|t| nearest_day(t, now))
+        .ok(),
+        latitude: parse_latitude_ddmm_mmm(split.get(2).unwrap_or(&""), split.get(3).unwrap_or(&""))
+            .map_err(|e| e.with_field(2, "latitude"))?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(4).unwrap_or(&""),
             split.get(5).unwrap_or(&""),
-        )?,
-        quality: GgaQualityIndicator::new(pick_number_field(&split, 6)?.unwrap_or(0)),
-        satellite_count: pick_number_field(&split, 7)?,
-        hdop: pick_number_field(&split, 8)?,
-        altitude: pick_number_field(&split, 9)?,
-        geoid_separation: pick_number_field(&split, 11)?,
-        age_of_dgps: pick_number_field(&split, 13)?,
-        ref_station_id: pick_number_field(&split, 14)?,
+        )
+        .map_err(|e| e.with_field(4, "longitude"))?,
+        quality: GgaQualityIndicator::new(pick_number_field(&split, 6, "quality")?.unwrap_or(0)),
+        satellite_count: pick_number_field(&split, 7, "satellite_count")?,
+        hdop: pick_number_field(&split, 8, "hdop")?,
+        altitude: pick_number_field(&split, 9, "altitude")?,
+        geoid_separation: pick_number_field(&split, 11, "geoid_separation")?,
+        age_of_dgps: pick_number_field(&split, 13, "age_of_dgps")?,
+        ref_station_id: pick_number_field(&split, 14, "ref_station_id")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for GgaData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (latitude, ns) = format_latitude_ddmm_mmm(self.latitude);
+        let (longitude, ew) = format_longitude_dddmm_mmm(self.longitude);
+        make_sentence(
+            '$',
+            &format!(
+                "{}GGA,{},{},{},{},{},{},{},{},{},M,{},M,{},{}",
+                talker_id,
+                format_hhmmss_ss(self.timestamp),
+                latitude,
+                ns,
+                longitude,
+                ew,
+                self.quality.to_value(),
+                format_integer_field(self.satellite_count, 2),
+                format_number_field(self.hdop, 2),
+                format_number_field(self.altitude, 3),
+                format_number_field(self.geoid_separation, 3),
+                format_number_field(self.age_of_dgps, 1),
+                format_integer_field(self.ref_station_id, 4),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -248,4 +286,22 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_gga() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47")
+        {
+            Ok(ParsedMessage::Gga(gga)) => {
+                let sentence = gga.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPGGA,123519.00,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*69"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Gga(gga)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
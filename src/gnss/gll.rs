@@ -16,7 +16,8 @@ limitations under the License.
 use super::*;
 
 /// GLL - geographic Position - Latitude/Longitude
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GllData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -28,7 +29,7 @@ pub struct GllData {
     pub longitude: Option<f64>,
 
     /// UTC of position fix
-    #[serde(with = "json_date_time_utc")]
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// True = data valid, false = data invalid.
@@ -54,20 +55,19 @@ impl LatLon for GllData {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
 ) -> Result<ParsedMessage, ParseError> {
-    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Gll(GllData {
         source: nav_system,
-        latitude: parse_latitude_ddmm_mmm(
-            split.get(1).unwrap_or(&""),
-            split.get(2).unwrap_or(&""),
-        )?,
+        latitude: parse_latitude_ddmm_mmm(split.get(1).unwrap_or(&""), split.get(2).unwrap_or(&""))
+            .map_err(|e| e.with_field(1, "latitude"))?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(3).unwrap_or(&""),
             split.get(4).unwrap_or(&""),
-        )?,
+        )
+        .map_err(|e| e.with_field(3, "longitude"))?,
         timestamp: parse_hhmmss(split.get(5).unwrap_or(&""), now).ok(),
         data_valid: {
             match *split.get(6).unwrap_or(&"") {
@@ -82,6 +82,35 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for GllData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (latitude, ns) = format_latitude_ddmm_mmm(self.latitude);
+        let (longitude, ew) = format_longitude_dddmm_mmm(self.longitude);
+        make_sentence(
+            '$',
+            &format!(
+                "{}GLL,{},{},{},{},{},{},{}",
+                talker_id,
+                latitude,
+                ns,
+                longitude,
+                ew,
+                format_hhmmss_ss(self.timestamp),
+                match self.data_valid {
+                    Some(true) => "A",
+                    Some(false) => "V",
+                    None => "",
+                },
+                self.faa_mode
+                    .map(|m| m.to_value().to_string())
+                    .unwrap_or_default(),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -113,4 +142,30 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_gll_rtk_float() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGLL,4916.45,N,12311.12,W,225444,A,F*5B") {
+            Ok(ParsedMessage::Gll(gll)) => {
+                assert_eq!(gll.faa_mode, Some(FaaMode::RealTimeKinematicFloat));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_gll() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GAGLL,4916.45,N,12311.12,W,225444,A,D*48") {
+            Ok(ParsedMessage::Gll(gll)) => {
+                let sentence = gll.to_sentence("GA");
+                assert_eq!(sentence, "$GAGLL,4916.450,N,12311.120,W,225444.00,A,D*66");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Gll(gll)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -18,11 +18,13 @@ use super::*;
 
 /// GNS - GNSS fix data
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GnsData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// UTC of position fix
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Latitude in degrees
@@ -57,6 +59,9 @@ pub struct GnsData {
 
     /// Reference station ID, range 0000-4095
     pub ref_station_id: Option<u16>,
+
+    /// Navigational status (NMEA 4.1 and later)
+    pub nav_status: Option<NavigationalStatus>,
 }
 
 impl LatLon for GnsData {
@@ -71,6 +76,7 @@ impl LatLon for GnsData {
 
 /// GNS mode indicator
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GnsModeIndicator {
     /// Satellite system not used in position fix, or fix not valid
     Invalid,
@@ -111,6 +117,20 @@ impl GnsModeIndicator {
             _ => GnsModeIndicator::Invalid,
         }
     }
+
+    pub fn to_value(self) -> char {
+        match self {
+            GnsModeIndicator::Invalid => 'N',
+            GnsModeIndicator::Autonomous => 'A',
+            GnsModeIndicator::Differential => 'D',
+            GnsModeIndicator::Precise => 'P',
+            GnsModeIndicator::RealTimeKinematic => 'R',
+            GnsModeIndicator::RealTimeKinematicFloat => 'F',
+            GnsModeIndicator::DeadReckoning => 'E',
+            GnsModeIndicator::ManualInputMode => 'M',
+            GnsModeIndicator::SimulationMode => 'S',
+        }
+    }
 }
 
 impl core::fmt::Display for GnsModeIndicator {
@@ -137,22 +157,21 @@ impl core::fmt::Display for GnsModeIndicator {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
 ) -> Result<ParsedMessage, ParseError> {
-    let now: DateTime<Utc> = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap();
     let split: Vec<&str> = sentence.split(',').collect();
     let modes: Vec<char> = split.get(6).unwrap_or(&"").chars().collect();
 
     Ok(ParsedMessage::Gns(GnsData {
         source: nav_system,
         timestamp: parse_hhmmss(split.get(1).unwrap_or(&""), now).ok(),
-        latitude: parse_latitude_ddmm_mmm(
-            split.get(2).unwrap_or(&""),
-            split.get(3).unwrap_or(&""),
-        )?,
+        latitude: parse_latitude_ddmm_mmm(split.get(2).unwrap_or(&""), split.get(3).unwrap_or(&""))
+            .map_err(|e| e.with_field(2, "latitude"))?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(4).unwrap_or(&""),
             split.get(5).unwrap_or(&""),
-        )?,
+        )
+        .map_err(|e| e.with_field(4, "longitude"))?,
         gps_mode: GnsModeIndicator::new(*modes.first().unwrap_or(&' ')),
         glonass_mode: GnsModeIndicator::new(*modes.get(1).unwrap_or(&' ')),
         other_modes: modes
@@ -160,17 +179,52 @@ pub(crate) fn handle(
             .skip(2)
             .map(GnsModeIndicator::new)
             .collect(),
-        satellite_count: pick_number_field(&split, 7)?,
-        hdop: pick_number_field(&split, 8)?,
-        altitude: pick_number_field(&split, 9)?,
-        geoid_separation: pick_number_field(&split, 10)?,
-        age_of_dgps: pick_number_field(&split, 11)?,
-        ref_station_id: pick_number_field(&split, 12)?,
+        satellite_count: pick_number_field(&split, 7, "satellite_count")?,
+        hdop: pick_number_field(&split, 8, "hdop")?,
+        altitude: pick_number_field(&split, 9, "altitude")?,
+        geoid_separation: pick_number_field(&split, 10, "geoid_separation")?,
+        age_of_dgps: pick_number_field(&split, 11, "age_of_dgps")?,
+        ref_station_id: pick_number_field(&split, 12, "ref_station_id")?,
+        nav_status: NavigationalStatus::new(split.get(13).unwrap_or(&"")).ok(),
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for GnsData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (latitude, ns) = format_latitude_ddmm_mmm(self.latitude);
+        let (longitude, ew) = format_longitude_dddmm_mmm(self.longitude);
+        let modes: String = [self.gps_mode, self.glonass_mode]
+            .iter()
+            .chain(self.other_modes.iter())
+            .map(|m| m.to_value())
+            .collect();
+        let mut content = format!(
+            "{}GNS,{},{},{},{},{},{},{},{},{},{},{},{}",
+            talker_id,
+            format_hhmmss_ss(self.timestamp),
+            latitude,
+            ns,
+            longitude,
+            ew,
+            modes,
+            format_integer_field(self.satellite_count, 2),
+            format_number_field(self.hdop, 2),
+            format_number_field(self.altitude, 3),
+            format_number_field(self.geoid_separation, 3),
+            format_number_field(self.age_of_dgps, 1),
+            format_integer_field(self.ref_station_id, 4),
+        );
+        if let Some(nav_status) = self.nav_status {
+            content.push_str(&format!(",{}", nav_status.to_value()));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -200,6 +254,7 @@ mod test {
                         assert::close(gns.geoid_separation.unwrap_or(0.0), 47.0, 0.1);
                         assert_eq!(gns.age_of_dgps, None);
                         assert_eq!(gns.ref_station_id, None);
+                        assert_eq!(gns.nav_status, Some(NavigationalStatus::NotValid));
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -249,4 +304,23 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_gns() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GNGNS,090310.00,4806.891632,N,01134.134167,E,AAN,10,1.0,532.4,47.0,,,V*68",
+        ) {
+            Ok(ParsedMessage::Gns(gns)) => {
+                let sentence = gns.to_sentence("GN");
+                assert_eq!(
+                    sentence,
+                    "$GNGNS,090310.00,4806.891632,N,01134.134167,E,AAN,10,1.0,532.4,47.0,,,V*68"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Gns(gns)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -15,7 +15,8 @@ limitations under the License.
 */
 use super::*;
 /// GSA - GNSS dilution of position (DOP) and active satellites
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GsaData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -37,10 +38,15 @@ pub struct GsaData {
 
     /// Vertical dilution of precision
     pub vdop: Option<f64>,
+
+    /// GNSS system of the satellites, which identifies the constellation of combined (GN) GSA
+    /// sentences (NMEA 4.1 and later)
+    pub system: Option<NavigationSystem>,
 }
 
 /// GSA position fix type
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GsaFixMode {
     /// No fix.
     NotAvailable,
@@ -52,6 +58,16 @@ pub enum GsaFixMode {
     Fix3D,
 }
 
+impl GsaFixMode {
+    pub fn to_value(&self) -> u8 {
+        match self {
+            GsaFixMode::NotAvailable => 1,
+            GsaFixMode::Fix2D => 2,
+            GsaFixMode::Fix3D => 3,
+        }
+    }
+}
+
 impl core::fmt::Display for GsaFixMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -80,7 +96,12 @@ pub(crate) fn handle(
                 "A" => Some(true),
                 "" => None,
                 _ => {
-                    return Err(format!("Invalid GPGSA mode: {}", s).into());
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidField,
+                        format!("Invalid GPGSA mode: {}", s),
+                    )
+                    .with_field(1, "mode1_automatic")
+                    .with_raw_value(s));
                 }
             }
         },
@@ -92,7 +113,12 @@ pub(crate) fn handle(
                 "3" => Some(GsaFixMode::Fix3D),
                 "" => None,
                 _ => {
-                    return Err(format!("Invalid GPGSA fix type: {}", s).into());
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidField,
+                        format!("Invalid GPGSA fix type: {}", s),
+                    )
+                    .with_field(2, "mode2_3d")
+                    .with_raw_value(s));
                 }
             }
         },
@@ -100,21 +126,50 @@ pub(crate) fn handle(
             let mut v = Vec::with_capacity(12);
             for i in 3..15 {
                 if split.get(i).unwrap_or(&"") != &"" {
-                    if let Some(val) = pick_number_field(&split, i)? {
+                    if let Some(val) = pick_number_field(&split, i, "prn_numbers")? {
                         v.push(val);
                     }
                 }
             }
             v
         },
-        pdop: pick_number_field(&split, 15)?,
-        hdop: pick_number_field(&split, 16)?,
-        vdop: pick_number_field(&split, 17)?,
+        pdop: pick_number_field(&split, 15, "pdop")?,
+        hdop: pick_number_field(&split, 16, "hdop")?,
+        vdop: pick_number_field(&split, 17, "vdop")?,
+        system: pick_hex_field(&split, 18, "system_id")?.and_then(NavigationSystem::from_system_id),
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for GsaData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let prn_numbers: Vec<String> = (0..12)
+            .map(|i| format_integer_field(self.prn_numbers.get(i), 2))
+            .collect();
+        let mut content = format!(
+            "{}GSA,{},{},{},{},{},{}",
+            talker_id,
+            match self.mode1_automatic {
+                Some(true) => "A",
+                Some(false) => "M",
+                None => "",
+            },
+            format_integer_field(self.mode2_3d.map(|m| m.to_value()), 1),
+            prn_numbers.join(","),
+            format_number_field(self.pdop, 2),
+            format_number_field(self.hdop, 2),
+            format_number_field(self.vdop, 2),
+        );
+        if let Some(system_id) = self.system.and_then(|s| s.system_id()) {
+            content.push_str(&format!(",{:X}", system_id));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -147,4 +202,40 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_gngsa_system_id() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09") {
+            Ok(ParsedMessage::Gsa(gsa)) => {
+                assert_eq!(gsa.source, NavigationSystem::Combination);
+                assert_eq!(gsa.system, Some(NavigationSystem::Glonass));
+                assert_eq!(gsa.prn_numbers, vec![80, 71, 73, 79, 69]);
+                assert_eq!(gsa.vdop, Some(1.47));
+                assert_eq!(
+                    gsa.to_sentence("GN"),
+                    "$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09"
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_gsa() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGSA,A,3,19,28,14,18,27,22,31,39,,,,,1.7,1.0,1.3*34") {
+            Ok(ParsedMessage::Gsa(gsa)) => {
+                let sentence = gsa.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPGSA,A,3,19,28,14,18,27,22,31,39,,,,,1.7,1.0,1.3*34"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Gsa(gsa)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -0,0 +1,159 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use super::*;
+
+/// GST - GNSS pseudorange error statistics
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GstData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// UTC of the associated position fix
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// RMS value of the standard deviation of the range inputs to the navigation process
+    /// (metres)
+    pub rms: Option<f64>,
+
+    /// Standard deviation of semi-major axis of error ellipse (metres)
+    pub semi_major_std_dev: Option<f64>,
+
+    /// Standard deviation of semi-minor axis of error ellipse (metres)
+    pub semi_minor_std_dev: Option<f64>,
+
+    /// Orientation of semi-major axis of error ellipse (degrees from true north)
+    pub semi_major_orientation: Option<f64>,
+
+    /// Standard deviation of latitude error (metres)
+    pub latitude_std_dev: Option<f64>,
+
+    /// Standard deviation of longitude error (metres)
+    pub longitude_std_dev: Option<f64>,
+
+    /// Standard deviation of altitude error (metres)
+    pub altitude_std_dev: Option<f64>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxGST: GNSS Pseudorange Error Statistics
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Gst(GstData {
+        source: nav_system,
+        timestamp: parse_hhmmss_ss(
+            split.get(1).unwrap_or(&""),
+            now.unwrap_or_else(default_reference_time),
+        )
+        .map(|t| nearest_day(t, now))
+        .ok(),
+        rms: pick_number_field(&split, 2, "rms")?,
+        semi_major_std_dev: pick_number_field(&split, 3, "semi_major_std_dev")?,
+        semi_minor_std_dev: pick_number_field(&split, 4, "semi_minor_std_dev")?,
+        semi_major_orientation: pick_number_field(&split, 5, "semi_major_orientation")?,
+        latitude_std_dev: pick_number_field(&split, 6, "latitude_std_dev")?,
+        longitude_std_dev: pick_number_field(&split, 7, "longitude_std_dev")?,
+        altitude_std_dev: pick_number_field(&split, 8, "altitude_std_dev")?,
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for GstData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}GST,{},{},{},{},{},{},{},{}",
+                talker_id,
+                format_hhmmss_ss(self.timestamp),
+                format_number_field(self.rms, 3),
+                format_number_field(self.semi_major_std_dev, 3),
+                format_number_field(self.semi_minor_std_dev, 3),
+                format_number_field(self.semi_major_orientation, 1),
+                format_number_field(self.latitude_std_dev, 3),
+                format_number_field(self.longitude_std_dev, 3),
+                format_number_field(self.altitude_std_dev, 3),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpgst() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A") {
+            Ok(ParsedMessage::Gst(gst)) => {
+                assert_eq!(gst.source, NavigationSystem::Gps);
+                assert_eq!(
+                    gst.timestamp,
+                    Utc.with_ymd_and_hms(2000, 1, 1, 17, 28, 14).single()
+                );
+                assert_eq!(gst.rms, Some(0.006));
+                assert_eq!(gst.semi_major_std_dev, Some(0.023));
+                assert_eq!(gst.semi_minor_std_dev, Some(0.020));
+                assert_eq!(gst.semi_major_orientation, Some(273.6));
+                assert_eq!(gst.latitude_std_dev, Some(0.023));
+                assert_eq!(gst.longitude_std_dev, Some(0.020));
+                assert_eq!(gst.altitude_std_dev, Some(0.031));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        match p.parse_sentence("$GNGST,,,,,,,,*49") {
+            Ok(ParsedMessage::Gst(gst)) => {
+                assert_eq!(gst.source, NavigationSystem::Combination);
+                assert_eq!(gst.timestamp, None);
+                assert_eq!(gst.rms, None);
+                assert_eq!(gst.altitude_std_dev, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_gst() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A") {
+            Ok(ParsedMessage::Gst(gst)) => {
+                let sentence = gst.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPGST,172814.00,0.006,0.023,0.02,273.6,0.023,0.02,0.031*5A"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Gst(gst)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+}
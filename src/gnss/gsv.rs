@@ -16,7 +16,8 @@ limitations under the License.
 use super::*;
 
 /// GSV - satellite information
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GsvData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -32,6 +33,9 @@ pub struct GsvData {
 
     /// SNR, 0-99 dB, None when not tracking
     pub snr: Option<f32>,
+
+    /// GNSS signal which the elevation, azimuth and SNR relate to (NMEA 4.1 and later)
+    pub signal: Option<GnssSignal>,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -45,13 +49,22 @@ pub(crate) fn handle(
     let split: Vec<&str> = sentence.split(',').collect();
 
     let msg_type = split.first().unwrap_or(&"");
-    let msg_count = pick_number_field(&split, 1)?.unwrap_or(0);
-    let msg_num = pick_number_field(&split, 2)?.unwrap_or(0);
-    store.push_string(make_gsv_key(msg_type, msg_count, msg_num), sentence.into());
+    let msg_count = pick_number_field(&split, 1, "message_count")?.unwrap_or(0);
+    let msg_num = pick_number_field(&split, 2, "message_number")?.unwrap_or(0);
+    let signal_id = pick_signal_id(&split);
+    let signal = match signal_id {
+        Some(_) => pick_hex_field(&split, split.len() - 1, "signal_id")?
+            .map(|id| GnssSignal::new(nav_system, id)),
+        None => None,
+    };
+    store.push_string(
+        make_gsv_key(msg_type, signal_id, msg_count, msg_num),
+        sentence.into(),
+    );
 
     let mut found_count = 0;
     for i in 1..(msg_count + 1) {
-        if store.contains_key(make_gsv_key(msg_type, msg_count, i)) {
+        if store.contains_key(make_gsv_key(msg_type, signal_id, msg_count, i)) {
             found_count += 1;
         }
     }
@@ -59,25 +72,35 @@ pub(crate) fn handle(
     if found_count == msg_count {
         let mut v = Vec::new();
         for i in 1..(msg_count + 1) {
-            if let Some(sentence) = store.pull_string(make_gsv_key(msg_type, msg_count, i)) {
-                let split: Vec<&str> = sentence.split(',').collect();
+            if let Some(sentence) =
+                store.pull_string(make_gsv_key(msg_type, signal_id, msg_count, i))
+            {
+                let mut split: Vec<&str> = sentence.split(',').collect();
+                if signal_id.is_some() {
+                    split.pop();
+                }
                 for j in 0..4 {
-                    if let Some(prn) = pick_number_field(&split, 4 + 4 * j as usize)
+                    if let Some(prn) = pick_number_field(&split, 4 + 4 * j as usize, "prn_number")
                         .ok()
                         .unwrap_or(None)
                     {
                         v.push(GsvData {
                             source: nav_system,
                             prn_number: prn,
-                            elevation: pick_number_field(&split, 4 + 4 * j as usize + 1)
+                            elevation: pick_number_field(
+                                &split,
+                                4 + 4 * j as usize + 1,
+                                "elevation",
+                            )
+                            .ok()
+                            .unwrap_or(None),
+                            azimuth: pick_number_field(&split, 4 + 4 * j as usize + 2, "azimuth")
                                 .ok()
                                 .unwrap_or(None),
-                            azimuth: pick_number_field(&split, 4 + 4 * j as usize + 2)
-                                .ok()
-                                .unwrap_or(None),
-                            snr: pick_number_field(&split, 4 + 4 * j as usize + 3)
+                            snr: pick_number_field(&split, 4 + 4 * j as usize + 3, "snr")
                                 .ok()
                                 .unwrap_or(None),
+                            signal,
                         });
                     }
                 }
@@ -90,9 +113,86 @@ pub(crate) fn handle(
     }
 }
 
+/// Pick the signal ID which follows the satellite blocks in NMEA 4.1 and later.
+fn pick_signal_id<'a>(split: &[&'a str]) -> Option<&'a str> {
+    if split.len() > 4 && split.len() % 4 == 1 {
+        split.last().copied()
+    } else {
+        None
+    }
+}
+
 /// Make key for store
-fn make_gsv_key(sentence_type: &str, msg_count: u32, msg_num: u32) -> String {
-    format!("{},{},{}", sentence_type, msg_count, msg_num)
+fn make_gsv_key(
+    sentence_type: &str,
+    signal_id: Option<&str>,
+    msg_count: u32,
+    msg_num: u32,
+) -> String {
+    match signal_id {
+        Some(signal_id) => format!("{},{},{},{}", sentence_type, signal_id, msg_count, msg_num),
+        None => format!("{},{},{}", sentence_type, msg_count, msg_num),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Encode the given satellites as a group of xxGSV sentences with the given talker ID. Each
+/// sentence carries information of at most four satellites. Satellites of different signals are
+/// encoded as separate groups with the signal ID (NMEA 4.1 and later).
+pub fn encode_gsv_sentences(satellites: &[GsvData], talker_id: &str) -> Vec<String> {
+    let mut signals: Vec<Option<GnssSignal>> = Vec::new();
+    for sat in satellites {
+        if !signals.contains(&sat.signal) {
+            signals.push(sat.signal);
+        }
+    }
+    if signals.is_empty() {
+        signals.push(None);
+    }
+    signals
+        .iter()
+        .flat_map(|signal| {
+            let group: Vec<&GsvData> = satellites
+                .iter()
+                .filter(|sat| sat.signal == *signal)
+                .collect();
+            encode_gsv_group(&group, *signal, talker_id)
+        })
+        .collect()
+}
+
+/// Encode satellites of a single signal as a group of xxGSV sentences.
+fn encode_gsv_group(
+    satellites: &[&GsvData],
+    signal: Option<GnssSignal>,
+    talker_id: &str,
+) -> Vec<String> {
+    let msg_count = max(1, satellites.len().div_ceil(4));
+    (0..msg_count)
+        .map(|i| {
+            let mut content = format!(
+                "{}GSV,{},{},{:02}",
+                talker_id,
+                msg_count,
+                i + 1,
+                satellites.len()
+            );
+            for sat in satellites.iter().skip(4 * i).take(4) {
+                content.push_str(&format!(
+                    ",{:02},{},{},{}",
+                    sat.prn_number,
+                    format_integer_field(sat.elevation.map(|v| v.round() as i32), 2),
+                    format_integer_field(sat.azimuth.map(|v| v.round() as i32), 3),
+                    format_integer_field(sat.snr.map(|v| v.round() as i32), 2),
+                ));
+            }
+            if let Some(signal) = signal {
+                content.push_str(&format!(",{:X}", signal.to_value()));
+            }
+            make_sentence('$', &content)
+        })
+        .collect()
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -176,4 +276,93 @@ mod test {
         }
         assert_eq!(p.strings_count(), 0);
     }
+
+    #[test]
+    fn test_encode_gsv() {
+        let mut p = NmeaParser::new();
+        let mut satellites = Vec::new();
+        for sentence in [
+            "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74",
+            "$GPGSV,3,2,11,14,25,170,00,16,57,208,39,18,67,296,40,19,40,246,00*74",
+            "$GPGSV,3,3,11,22,42,067,42,24,14,311,43,27,05,244,00,,,,*4D",
+        ] {
+            if let Ok(ParsedMessage::Gsv(v)) = p.parse_sentence(sentence) {
+                satellites = v;
+            }
+        }
+        assert_eq!(satellites.len(), 11);
+
+        let sentences = encode_gsv_sentences(&satellites, "GP");
+        assert_eq!(
+            sentences,
+            vec![
+                "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74",
+                "$GPGSV,3,2,11,14,25,170,00,16,57,208,39,18,67,296,40,19,40,246,00*74",
+                "$GPGSV,3,3,11,22,42,067,42,24,14,311,43,27,05,244,00*4D",
+            ]
+        );
+        for (i, sentence) in sentences.iter().enumerate() {
+            match p.parse_sentence(sentence) {
+                Ok(ParsedMessage::Incomplete) => assert!(i < 2),
+                Ok(ParsedMessage::Gsv(v)) => assert_eq!(v, satellites),
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+        assert_eq!(encode_gsv_sentences(&[], "GP"), vec!["$GPGSV,1,1,00*79"]);
+    }
+
+    #[test]
+    fn test_parse_gsv_signal_id() {
+        // L1 C/A and L5-Q of the same satellites are kept apart
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPGSV,1,1,03,10,35,194,39,12,66,257,42,25,27,080,38,1*52") {
+            Ok(ParsedMessage::Gsv(v)) => {
+                assert_eq!(v.len(), 3);
+                assert_eq!(v[0].prn_number, 10);
+                assert_eq!(v[0].snr, Some(39.0));
+                assert_eq!(v[2].azimuth, Some(80.0));
+                assert_eq!(v[2].signal, Some(GnssSignal::GpsL1Ca));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        match p.parse_sentence("$GPGSV,1,1,02,10,35,194,31,12,66,257,33,8*65") {
+            Ok(ParsedMessage::Gsv(v)) => {
+                assert_eq!(v.len(), 2);
+                assert_eq!(v[1].prn_number, 12);
+                assert_eq!(v[1].snr, Some(33.0));
+                assert_eq!(v[1].signal, Some(GnssSignal::GpsL5Q));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Interleaved groups of different signals
+        let satellites: Vec<GsvData> = [GnssSignal::GpsL1Ca, GnssSignal::GpsL5Q]
+            .iter()
+            .flat_map(|signal| {
+                (1..=5).map(move |prn| GsvData {
+                    source: NavigationSystem::Gps,
+                    prn_number: prn,
+                    elevation: Some(10.0),
+                    azimuth: Some(100.0),
+                    snr: Some(signal.to_value() as f32),
+                    signal: Some(*signal),
+                })
+            })
+            .collect();
+        let sentences = encode_gsv_sentences(&satellites, "GP");
+        assert_eq!(sentences.len(), 4);
+        assert_eq!(sentences[3], "$GPGSV,2,2,05,05,10,100,08,8*55");
+        for i in [0, 2, 1, 3] {
+            match p.parse_sentence(&sentences[i]) {
+                Ok(ParsedMessage::Incomplete) => assert!(i == 0 || i == 2),
+                Ok(ParsedMessage::Gsv(v)) if i == 1 => assert_eq!(v, satellites[..5]),
+                Ok(ParsedMessage::Gsv(v)) if i == 3 => assert_eq!(v, satellites[5..]),
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+    }
 }
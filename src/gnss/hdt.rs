@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// HDT - Heading, true
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HdtData {
     /// Heading - true
     pub heading_true: Option<f64>,
@@ -30,12 +31,27 @@ pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Hdt(HdtData {
-        heading_true: pick_number_field(&split, 1)?,
+        heading_true: pick_number_field(&split, 1, "heading_true")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for HdtData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}HDT,{},T",
+                talker_id,
+                format_number_field(self.heading_true, 2)
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -56,4 +72,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_hdt() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$IIHDT,15.0,T*16") {
+            Ok(ParsedMessage::Hdt(hdt)) => {
+                let sentence = hdt.to_sentence("II");
+                assert_eq!(sentence, "$IIHDT,15.0,T*16");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Hdt(hdt)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
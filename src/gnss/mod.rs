@@ -35,15 +35,23 @@ pub(crate) mod mtw;
 pub(crate) mod vhw;
 pub(crate) mod hdt;
 pub(crate) mod mwv;
+pub(crate) mod gst;
+pub(crate) mod gbs;
+pub(crate) mod rmb;
+pub(crate) mod apb;
+pub(crate) mod xte;
+pub(crate) mod bod;
+pub(crate) mod bwc;
 
 use super::*;
 pub use gga::{GgaData, GgaQualityIndicator};
 pub use gll::GllData;
 pub use gns::GnsData;
 pub use gsa::{GsaData, GsaFixMode};
-pub use gsv::GsvData;
+pub use gsv::{encode_gsv_sentences, GsvData};
 pub use rmc::RmcData;
-use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 pub use vtg::VtgData;
 pub use alm::AlmData;
 pub use dtm::DtmData;
@@ -57,11 +65,28 @@ pub use mtw::MtwData;
 pub use vhw::VhwData;
 pub use hdt::HdtData;
 pub use mwv::MwvData;
+pub use gst::GstData;
+pub use gbs::GbsData;
+pub use rmb::RmbData;
+pub use apb::ApbData;
+pub use xte::XteData;
+pub use bod::BodData;
+pub use bwc::BwcData;
+
+// -------------------------------------------------------------------------------------------------
+
+/// Encoding of GNSS data structures back to NMEA 0183 sentences
+pub trait ToSentence {
+    /// Encode the data as a complete NMEA 0183 sentence, including the start character and the
+    /// checksum. The talker ID is the two-character prefix of the sentence type (e.g. "GP").
+    fn to_sentence(&self, talker_id: &str) -> String;
+}
 
 // -------------------------------------------------------------------------------------------------
 
 /// Navigation system, identified with NMEA GNSS sentence prefix (e.g. $BDGGA)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NavigationSystem {
     /// Combination of several satellite systems
     Combination, // GNxxx
@@ -107,22 +132,69 @@ impl core::fmt::Display for NavigationSystem {
     }
 }
 
+impl NavigationSystem {
+    /// Talker ID of the navigation system or `None` if the system has no standard talker ID.
+    pub fn talker_id(&self) -> Option<&'static str> {
+        match self {
+            NavigationSystem::Combination => Some("GN"),
+            NavigationSystem::Gps => Some("GP"),
+            NavigationSystem::Glonass => Some("GL"),
+            NavigationSystem::Galileo => Some("GA"),
+            NavigationSystem::Beidou => Some("BD"),
+            NavigationSystem::Navic => Some("GI"),
+            NavigationSystem::Qzss => Some("QZ"),
+            NavigationSystem::Proprietary => None,
+            NavigationSystem::Other => None,
+        }
+    }
+
+    /// Construct the navigation system from NMEA 4.1 GNSS system ID or return `None` if the ID
+    /// is unknown.
+    pub fn from_system_id(system_id: u8) -> Option<NavigationSystem> {
+        match system_id {
+            1 => Some(NavigationSystem::Gps),
+            2 => Some(NavigationSystem::Glonass),
+            3 => Some(NavigationSystem::Galileo),
+            4 => Some(NavigationSystem::Beidou),
+            5 => Some(NavigationSystem::Qzss),
+            6 => Some(NavigationSystem::Navic),
+            _ => None,
+        }
+    }
+
+    /// NMEA 4.1 GNSS system ID of the navigation system or `None` if the system has no ID.
+    pub fn system_id(&self) -> Option<u8> {
+        match self {
+            NavigationSystem::Gps => Some(1),
+            NavigationSystem::Glonass => Some(2),
+            NavigationSystem::Galileo => Some(3),
+            NavigationSystem::Beidou => Some(4),
+            NavigationSystem::Qzss => Some(5),
+            NavigationSystem::Navic => Some(6),
+            _ => None,
+        }
+    }
+}
+
 impl core::str::FromStr for NavigationSystem {
     type Err = ParseError;
 
     fn from_str(talker_id: &str) -> Result<Self, Self::Err> {
         if talker_id.is_empty() {
-            return Err(ParseError::InvalidSentence(
-                "Invalid talker identifier".to_string(),
-            ));
+            return Err(
+                ParseError::new(ParseErrorKind::BadTalker, "Invalid talker identifier")
+                    .with_raw_value(talker_id),
+            );
         }
         if &talker_id[0..1] == "P" {
             Ok(Self::Proprietary)
         } else {
             if talker_id.len() < 2 {
-                return Err(ParseError::InvalidSentence(
-                    "Invalid talker identifier".to_string(),
-                ));
+                return Err(ParseError::new(
+                    ParseErrorKind::BadTalker,
+                    "Invalid talker identifier",
+                )
+                .with_raw_value(talker_id));
             }
             match &talker_id[0..2] {
                 "GN" => Ok(Self::Combination),
@@ -139,8 +211,9 @@ impl core::str::FromStr for NavigationSystem {
 }
 
 // -------------------------------------------------------------------------------------------------
-/// VTG/GLL FAA mode (NMEA 2.3 standard has this information)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+/// RMC/VTG/GLL FAA mode (NMEA 2.3 standard has this information)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FaaMode {
     /// Autonomous mode (automatic 2D/3D)
     Autonomous,
@@ -156,6 +229,18 @@ pub enum FaaMode {
 
     /// Simulated data.
     Simulator,
+
+    /// Real time kinematic mode with floating integers.
+    RealTimeKinematicFloat,
+
+    /// Real time kinematic mode with fixed integers.
+    RealTimeKinematic,
+
+    /// Manual input mode.
+    ManualInput,
+
+    /// Precise mode (no deliberate degradation and higher resolution code).
+    Precise,
 }
 
 impl FaaMode {
@@ -165,19 +250,492 @@ impl FaaMode {
             "D" => Ok(FaaMode::Differential),
             "E" => Ok(FaaMode::Estimated),
             "N" => Ok(FaaMode::NotValid),
+            "S" => Ok(FaaMode::Simulator),
+            "F" => Ok(FaaMode::RealTimeKinematicFloat),
+            "R" => Ok(FaaMode::RealTimeKinematic),
+            "M" => Ok(FaaMode::ManualInput),
+            "P" => Ok(FaaMode::Precise),
             _ => Err(format!("Unrecognized FAA information value: {}", val)),
         }
     }
+
+    pub fn to_value(&self) -> char {
+        match self {
+            FaaMode::Autonomous => 'A',
+            FaaMode::Differential => 'D',
+            FaaMode::Estimated => 'E',
+            FaaMode::NotValid => 'N',
+            FaaMode::Simulator => 'S',
+            FaaMode::RealTimeKinematicFloat => 'F',
+            FaaMode::RealTimeKinematic => 'R',
+            FaaMode::ManualInput => 'M',
+            FaaMode::Precise => 'P',
+        }
+    }
 }
 
 impl core::fmt::Display for FaaMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_value())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// RMC/GNS navigational status (NMEA 4.1 standard has this information)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NavigationalStatus {
+    /// Safe: the position is within the integrity limits.
+    Safe,
+
+    /// Caution: the integrity is not available.
+    Caution,
+
+    /// Unsafe: the position is outside of the integrity limits.
+    Unsafe,
+
+    /// Navigational status not valid (equipment doesn't provide it).
+    NotValid,
+}
+
+impl NavigationalStatus {
+    pub fn new(val: &str) -> Result<NavigationalStatus, String> {
+        match val {
+            "S" => Ok(NavigationalStatus::Safe),
+            "C" => Ok(NavigationalStatus::Caution),
+            "U" => Ok(NavigationalStatus::Unsafe),
+            "V" => Ok(NavigationalStatus::NotValid),
+            _ => Err(format!("Unrecognized navigational status value: {}", val)),
+        }
+    }
+
+    pub fn to_value(&self) -> char {
+        match self {
+            NavigationalStatus::Safe => 'S',
+            NavigationalStatus::Caution => 'C',
+            NavigationalStatus::Unsafe => 'U',
+            NavigationalStatus::NotValid => 'V',
+        }
+    }
+}
+
+impl core::fmt::Display for NavigationalStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NavigationalStatus::Safe => write!(f, "safe"),
+            NavigationalStatus::Caution => write!(f, "caution"),
+            NavigationalStatus::Unsafe => write!(f, "unsafe"),
+            NavigationalStatus::NotValid => write!(f, "not valid"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Direction to steer to get back on the course (RMB, APB and XTE)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SteerDirection {
+    /// Steer left
+    Left,
+
+    /// Steer right
+    Right,
+}
+
+impl SteerDirection {
+    pub fn new(val: &str) -> Result<SteerDirection, String> {
+        match val {
+            "L" => Ok(SteerDirection::Left),
+            "R" => Ok(SteerDirection::Right),
+            _ => Err(format!("Unrecognized steer direction value: {}", val)),
+        }
+    }
+
+    pub fn to_value(&self) -> char {
+        match self {
+            SteerDirection::Left => 'L',
+            SteerDirection::Right => 'R',
+        }
+    }
+}
+
+impl core::fmt::Display for SteerDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SteerDirection::Left => write!(f, "left"),
+            SteerDirection::Right => write!(f, "right"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Unit of a speed field (MWV)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SpeedUnit {
+    /// Knots
+    Knots,
+
+    /// Meters per second
+    MetersPerSecond,
+
+    /// Kilometers per hour
+    KilometersPerHour,
+}
+
+impl SpeedUnit {
+    pub fn new(val: &str) -> Result<SpeedUnit, String> {
+        match val {
+            "N" => Ok(SpeedUnit::Knots),
+            "M" => Ok(SpeedUnit::MetersPerSecond),
+            "K" => Ok(SpeedUnit::KilometersPerHour),
+            _ => Err(format!("Unrecognized speed unit value: {}", val)),
+        }
+    }
+
+    pub fn to_value(&self) -> char {
+        match self {
+            SpeedUnit::Knots => 'N',
+            SpeedUnit::MetersPerSecond => 'M',
+            SpeedUnit::KilometersPerHour => 'K',
+        }
+    }
+}
+
+impl core::fmt::Display for SpeedUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SpeedUnit::Knots => write!(f, "knots"),
+            SpeedUnit::MetersPerSecond => write!(f, "m/s"),
+            SpeedUnit::KilometersPerHour => write!(f, "km/h"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Unit of a distance field (XTE and APB cross track error)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DistanceUnit {
+    /// Nautical miles
+    NauticalMiles,
+
+    /// Kilometers
+    Kilometers,
+}
+
+impl DistanceUnit {
+    pub fn new(val: &str) -> Result<DistanceUnit, String> {
+        match val {
+            "N" => Ok(DistanceUnit::NauticalMiles),
+            "K" => Ok(DistanceUnit::Kilometers),
+            _ => Err(format!("Unrecognized distance unit value: {}", val)),
+        }
+    }
+
+    pub fn to_value(&self) -> char {
+        match self {
+            DistanceUnit::NauticalMiles => 'N',
+            DistanceUnit::Kilometers => 'K',
+        }
+    }
+}
+
+impl core::fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DistanceUnit::NauticalMiles => write!(f, "nautical miles"),
+            DistanceUnit::Kilometers => write!(f, "kilometers"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// GNSS signal identified by NMEA 4.1 signal ID together with the navigation system
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GnssSignal {
+    /// All signals (signal ID 0)
+    All,
+
+    /// GPS L1 C/A
+    GpsL1Ca,
+
+    /// GPS L1 P(Y)
+    GpsL1Py,
+
+    /// GPS L1 M
+    GpsL1M,
+
+    /// GPS L2 P(Y)
+    GpsL2Py,
+
+    /// GPS L2C-M
+    GpsL2cM,
+
+    /// GPS L2C-L
+    GpsL2cL,
+
+    /// GPS L5-I
+    GpsL5I,
+
+    /// GPS L5-Q
+    GpsL5Q,
+
+    /// GLONASS G1 C/A
+    GlonassG1Ca,
+
+    /// GLONASS G1 P
+    GlonassG1P,
+
+    /// GLONASS G2 C/A
+    GlonassG2Ca,
+
+    /// GLONASS G2 P
+    GlonassG2P,
+
+    /// Galileo E5a
+    GalileoE5a,
+
+    /// Galileo E5b
+    GalileoE5b,
+
+    /// Galileo E5 a+b
+    GalileoE5ab,
+
+    /// Galileo E6-A
+    GalileoE6A,
+
+    /// Galileo E6-BC
+    GalileoE6Bc,
+
+    /// Galileo E1-A
+    GalileoE1A,
+
+    /// Galileo E1-BC
+    GalileoE1Bc,
+
+    /// BeiDou B1I
+    BeidouB1I,
+
+    /// BeiDou B1Q
+    BeidouB1Q,
+
+    /// BeiDou B1C
+    BeidouB1C,
+
+    /// BeiDou B1A
+    BeidouB1A,
+
+    /// BeiDou B2a
+    BeidouB2a,
+
+    /// BeiDou B2b
+    BeidouB2b,
+
+    /// BeiDou B2 a+b
+    BeidouB2ab,
+
+    /// BeiDou B3I
+    BeidouB3I,
+
+    /// BeiDou B3Q
+    BeidouB3Q,
+
+    /// BeiDou B3A
+    BeidouB3A,
+
+    /// BeiDou B2I
+    BeidouB2I,
+
+    /// BeiDou B2Q
+    BeidouB2Q,
+
+    /// QZSS L1 C/A
+    QzssL1Ca,
+
+    /// QZSS L1C (D)
+    QzssL1cD,
+
+    /// QZSS L1C (P)
+    QzssL1cP,
+
+    /// QZSS LIS
+    QzssLis,
+
+    /// QZSS L2C-M
+    QzssL2cM,
+
+    /// QZSS L2C-L
+    QzssL2cL,
+
+    /// QZSS L5-I
+    QzssL5I,
+
+    /// QZSS L5-Q
+    QzssL5Q,
+
+    /// QZSS L6D
+    QzssL6D,
+
+    /// QZSS L6E
+    QzssL6E,
+
+    /// NavIC L5-SPS
+    NavicL5Sps,
+
+    /// NavIC S-SPS
+    NavicSSps,
+
+    /// NavIC L5-RS
+    NavicL5Rs,
+
+    /// NavIC S-RS
+    NavicSRs,
+
+    /// NavIC L1-SPS
+    NavicL1Sps,
+
+    /// Signal ID which is unknown or reserved for the navigation system
+    Unknown(u8),
+}
+
+impl GnssSignal {
+    /// Construct the signal from NMEA 4.1 signal ID. The meaning of the ID depends on the
+    /// navigation system.
+    pub fn new(system: NavigationSystem, signal_id: u8) -> GnssSignal {
+        use GnssSignal::*;
+        match (system, signal_id) {
+            (_, 0) => All,
+            (NavigationSystem::Gps, 1) => GpsL1Ca,
+            (NavigationSystem::Gps, 2) => GpsL1Py,
+            (NavigationSystem::Gps, 3) => GpsL1M,
+            (NavigationSystem::Gps, 4) => GpsL2Py,
+            (NavigationSystem::Gps, 5) => GpsL2cM,
+            (NavigationSystem::Gps, 6) => GpsL2cL,
+            (NavigationSystem::Gps, 7) => GpsL5I,
+            (NavigationSystem::Gps, 8) => GpsL5Q,
+            (NavigationSystem::Glonass, 1) => GlonassG1Ca,
+            (NavigationSystem::Glonass, 2) => GlonassG1P,
+            (NavigationSystem::Glonass, 3) => GlonassG2Ca,
+            (NavigationSystem::Glonass, 4) => GlonassG2P,
+            (NavigationSystem::Galileo, 1) => GalileoE5a,
+            (NavigationSystem::Galileo, 2) => GalileoE5b,
+            (NavigationSystem::Galileo, 3) => GalileoE5ab,
+            (NavigationSystem::Galileo, 4) => GalileoE6A,
+            (NavigationSystem::Galileo, 5) => GalileoE6Bc,
+            (NavigationSystem::Galileo, 6) => GalileoE1A,
+            (NavigationSystem::Galileo, 7) => GalileoE1Bc,
+            (NavigationSystem::Beidou, 1) => BeidouB1I,
+            (NavigationSystem::Beidou, 2) => BeidouB1Q,
+            (NavigationSystem::Beidou, 3) => BeidouB1C,
+            (NavigationSystem::Beidou, 4) => BeidouB1A,
+            (NavigationSystem::Beidou, 5) => BeidouB2a,
+            (NavigationSystem::Beidou, 6) => BeidouB2b,
+            (NavigationSystem::Beidou, 7) => BeidouB2ab,
+            (NavigationSystem::Beidou, 8) => BeidouB3I,
+            (NavigationSystem::Beidou, 9) => BeidouB3Q,
+            (NavigationSystem::Beidou, 10) => BeidouB3A,
+            (NavigationSystem::Beidou, 11) => BeidouB2I,
+            (NavigationSystem::Beidou, 12) => BeidouB2Q,
+            (NavigationSystem::Qzss, 1) => QzssL1Ca,
+            (NavigationSystem::Qzss, 2) => QzssL1cD,
+            (NavigationSystem::Qzss, 3) => QzssL1cP,
+            (NavigationSystem::Qzss, 4) => QzssLis,
+            (NavigationSystem::Qzss, 5) => QzssL2cM,
+            (NavigationSystem::Qzss, 6) => QzssL2cL,
+            (NavigationSystem::Qzss, 7) => QzssL5I,
+            (NavigationSystem::Qzss, 8) => QzssL5Q,
+            (NavigationSystem::Qzss, 9) => QzssL6D,
+            (NavigationSystem::Qzss, 10) => QzssL6E,
+            (NavigationSystem::Navic, 1) => NavicL5Sps,
+            (NavigationSystem::Navic, 2) => NavicSSps,
+            (NavigationSystem::Navic, 3) => NavicL5Rs,
+            (NavigationSystem::Navic, 4) => NavicSRs,
+            (NavigationSystem::Navic, 5) => NavicL1Sps,
+            (_, id) => Unknown(id),
+        }
+    }
+
+    /// NMEA 4.1 signal ID of the signal
+    pub fn to_value(&self) -> u8 {
+        use GnssSignal::*;
+        match self {
+            All => 0,
+            GpsL1Ca | GlonassG1Ca | GalileoE5a | BeidouB1I | QzssL1Ca | NavicL5Sps => 1,
+            GpsL1Py | GlonassG1P | GalileoE5b | BeidouB1Q | QzssL1cD | NavicSSps => 2,
+            GpsL1M | GlonassG2Ca | GalileoE5ab | BeidouB1C | QzssL1cP | NavicL5Rs => 3,
+            GpsL2Py | GlonassG2P | GalileoE6A | BeidouB1A | QzssLis | NavicSRs => 4,
+            GpsL2cM | GalileoE6Bc | BeidouB2a | QzssL2cM | NavicL1Sps => 5,
+            GpsL2cL | GalileoE1A | BeidouB2b | QzssL2cL => 6,
+            GpsL5I | GalileoE1Bc | BeidouB2ab | QzssL5I => 7,
+            GpsL5Q | BeidouB3I | QzssL5Q => 8,
+            BeidouB3Q | QzssL6D => 9,
+            BeidouB3A | QzssL6E => 10,
+            BeidouB2I => 11,
+            BeidouB2Q => 12,
+            Unknown(id) => *id,
+        }
+    }
+}
+
+impl core::fmt::Display for GnssSignal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use GnssSignal::*;
         match self {
-            FaaMode::Autonomous => write!(f, "A"),
-            FaaMode::Differential => write!(f, "D"),
-            FaaMode::Estimated => write!(f, "E"),
-            FaaMode::NotValid => write!(f, "N"),
-            _ => write!(f, "?"),
+            All => write!(f, "all signals"),
+            GpsL1Ca => write!(f, "GPS L1 C/A"),
+            GpsL1Py => write!(f, "GPS L1 P(Y)"),
+            GpsL1M => write!(f, "GPS L1 M"),
+            GpsL2Py => write!(f, "GPS L2 P(Y)"),
+            GpsL2cM => write!(f, "GPS L2C-M"),
+            GpsL2cL => write!(f, "GPS L2C-L"),
+            GpsL5I => write!(f, "GPS L5-I"),
+            GpsL5Q => write!(f, "GPS L5-Q"),
+            GlonassG1Ca => write!(f, "GLONASS G1 C/A"),
+            GlonassG1P => write!(f, "GLONASS G1 P"),
+            GlonassG2Ca => write!(f, "GLONASS G2 C/A"),
+            GlonassG2P => write!(f, "GLONASS G2 P"),
+            GalileoE5a => write!(f, "Galileo E5a"),
+            GalileoE5b => write!(f, "Galileo E5b"),
+            GalileoE5ab => write!(f, "Galileo E5 a+b"),
+            GalileoE6A => write!(f, "Galileo E6-A"),
+            GalileoE6Bc => write!(f, "Galileo E6-BC"),
+            GalileoE1A => write!(f, "Galileo E1-A"),
+            GalileoE1Bc => write!(f, "Galileo E1-BC"),
+            BeidouB1I => write!(f, "BeiDou B1I"),
+            BeidouB1Q => write!(f, "BeiDou B1Q"),
+            BeidouB1C => write!(f, "BeiDou B1C"),
+            BeidouB1A => write!(f, "BeiDou B1A"),
+            BeidouB2a => write!(f, "BeiDou B2a"),
+            BeidouB2b => write!(f, "BeiDou B2b"),
+            BeidouB2ab => write!(f, "BeiDou B2 a+b"),
+            BeidouB3I => write!(f, "BeiDou B3I"),
+            BeidouB3Q => write!(f, "BeiDou B3Q"),
+            BeidouB3A => write!(f, "BeiDou B3A"),
+            BeidouB2I => write!(f, "BeiDou B2I"),
+            BeidouB2Q => write!(f, "BeiDou B2Q"),
+            QzssL1Ca => write!(f, "QZSS L1 C/A"),
+            QzssL1cD => write!(f, "QZSS L1C (D)"),
+            QzssL1cP => write!(f, "QZSS L1C (P)"),
+            QzssLis => write!(f, "QZSS LIS"),
+            QzssL2cM => write!(f, "QZSS L2C-M"),
+            QzssL2cL => write!(f, "QZSS L2C-L"),
+            QzssL5I => write!(f, "QZSS L5-I"),
+            QzssL5Q => write!(f, "QZSS L5-Q"),
+            QzssL6D => write!(f, "QZSS L6D"),
+            QzssL6E => write!(f, "QZSS L6E"),
+            NavicL5Sps => write!(f, "NavIC L5-SPS"),
+            NavicSSps => write!(f, "NavIC S-SPS"),
+            NavicL5Rs => write!(f, "NavIC L5-RS"),
+            NavicSRs => write!(f, "NavIC S-RS"),
+            NavicL1Sps => write!(f, "NavIC L1-SPS"),
+            Unknown(id) => write!(f, "unknown signal {:X}", id),
         }
     }
 }
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// MSS - Multiple Data ID
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MssData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -49,16 +50,35 @@ pub(crate) fn handle(
 
     Ok(ParsedMessage::Mss(MssData {
         source: nav_system,
-        ss: pick_number_field(&split, 1)?,
-        snr: pick_number_field(&split, 2)?,
-        frequency: pick_number_field(&split, 3)?,
-        bit_rate: pick_number_field(&split, 4)?,
-        channel: pick_number_field(&split, 5)?,
+        ss: pick_number_field(&split, 1, "ss")?,
+        snr: pick_number_field(&split, 2, "snr")?,
+        frequency: pick_number_field(&split, 3, "frequency")?,
+        bit_rate: pick_number_field(&split, 4, "bit_rate")?,
+        channel: pick_number_field(&split, 5, "channel")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for MssData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}MSS,{},{},{},{},{}",
+                talker_id,
+                format_integer_field(self.ss, 2),
+                format_integer_field(self.snr, 2),
+                format_number_field(self.frequency, 1),
+                format_integer_field(self.bit_rate, 3),
+                format_integer_field(self.channel, 1),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -87,4 +107,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_mss() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPMSS,55,27,318.0,100,1*57") {
+            Ok(ParsedMessage::Mss(mss)) => {
+                let sentence = mss.to_sentence("GP");
+                assert_eq!(sentence, "$GPMSS,55,27,318.0,100,1*57");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Mss(mss)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// MTW - Mean Temperature of Water
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MtwData {
     /// Water temperature in degrees Celsius
     pub temperature: Option<f64>,
@@ -30,12 +31,27 @@ pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Mtw(MtwData {
-        temperature: pick_number_field(&split, 1)?,
+        temperature: pick_number_field(&split, 1, "temperature")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for MtwData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}MTW,{},C",
+                talker_id,
+                format_number_field(self.temperature, 2)
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -60,4 +76,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_mtw() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$INMTW,17.9,C*1B") {
+            Ok(ParsedMessage::Mtw(mtw)) => {
+                let sentence = mtw.to_sentence("IN");
+                assert_eq!(sentence, "$INMTW,17.9,C*1B");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Mtw(mtw)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
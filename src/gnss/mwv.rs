@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// MWV - Wind speed and angle
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MwvData {
     /// wind angle, 0 to 359 degrees
     pub wind_angle: Option<f64>,
@@ -30,6 +31,9 @@ pub struct MwvData {
 
     /// Wind speed - km/h
     pub wind_speed_kmh: Option<f64>,
+
+    /// Unit of the wind speed in the received sentence
+    pub wind_speed_unit: Option<SpeedUnit>,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -39,61 +43,87 @@ pub struct MwvData {
 pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
+    let relative = pick_string_field(&split, 2).ok_or(
+        ParseError::new(ParseErrorKind::MissingField, "Reference missing")
+            .with_field(2, "reference"),
+    )?;
+    let unit = pick_string_field(&split, 4).ok_or(
+        ParseError::new(ParseErrorKind::MissingField, "Wind speed unit missing")
+            .with_field(4, "wind_speed_unit"),
+    )?;
+    let wind_speed = || -> Result<f64, ParseError> {
+        pick_number_field::<f64>(&split, 3, "wind_speed")?.ok_or(
+            ParseError::new(ParseErrorKind::MissingField, "Wind speed missing")
+                .with_field(3, "wind_speed"),
+        )
+    };
+
     Ok(ParsedMessage::Mwv(MwvData {
-        wind_angle: pick_number_field(&split, 1)?,
-        relative: match pick_string_field(&split, 2)
-            .ok_or(ParseError::CorruptedSentence(
-                "pick string for \"relative\" was None".to_string(),
-            ))?
-            .as_str()
-        {
+        wind_angle: pick_number_field(&split, 1, "wind_angle")?,
+        relative: match relative.as_str() {
             "R" => Some(true),
             "T" => Some(false),
             _ => None,
         },
-        wind_speed_knots: match pick_string_field(&split, 4)
-            .ok_or(ParseError::CorruptedSentence(
-                "pick string for \"wind_speed_knots\" was None".to_string(),
-            ))?
-            .as_str()
-        {
-            "N" => pick_number_field(&split, 3)?,
-            "M" => Some(
-                pick_number_field::<f64>(&split, 3)?.ok_or(ParseError::CorruptedSentence(
-                    "pick string for \"wind_speed_knots M\" was None".to_string(),
-                ))? * 1.943844,
-            ),
-            "K" => Some(
-                pick_number_field::<f64>(&split, 3)?.ok_or(ParseError::CorruptedSentence(
-                    "pick string for \"wind_speed_knots K\" was None".to_string(),
-                ))? * 0.539957,
-            ),
+        wind_speed_knots: match unit.as_str() {
+            "N" => pick_number_field(&split, 3, "wind_speed")?,
+            "M" => Some(wind_speed()? * 1.943844),
+            "K" => Some(wind_speed()? * 0.539957),
             _ => None,
         },
-        wind_speed_kmh: match pick_string_field(&split, 4)
-            .ok_or(ParseError::CorruptedSentence(
-                "pick string for \"wind_speed_kmh\" was None".to_string(),
-            ))?
-            .as_str()
-        {
-            "N" => Some(
-                pick_number_field::<f64>(&split, 3)?.ok_or(ParseError::CorruptedSentence(
-                    "pick string for \"wind_speed_kmh N\" was None".to_string(),
-                ))? * 1.852,
-            ),
-            "M" => Some(
-                pick_number_field::<f64>(&split, 3)?.ok_or(ParseError::CorruptedSentence(
-                    "pick string for \"wind_speed_kmh M\" was None".to_string(),
-                ))? * 3.6,
-            ),
-            "K" => pick_number_field(&split, 3)?,
+        wind_speed_kmh: match unit.as_str() {
+            "N" => Some(wind_speed()? * 1.852),
+            "M" => Some(wind_speed()? * 3.6),
+            "K" => pick_number_field(&split, 3, "wind_speed")?,
             _ => None,
         },
+        wind_speed_unit: SpeedUnit::new(&unit).ok(),
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for MwvData {
+    /// Encode the wind data using the unit of the received sentence. Without a unit knots are
+    /// used, or km/h if only that is available. Notice that the parser rejects MWV sentences
+    /// without wind speed.
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (wind_speed, unit) = match (
+            self.wind_speed_unit,
+            self.wind_speed_knots,
+            self.wind_speed_kmh,
+        ) {
+            (Some(SpeedUnit::KilometersPerHour), _, Some(kmh)) => (Some(kmh), "K"),
+            (Some(SpeedUnit::MetersPerSecond), Some(knots), _) => (Some(knots / 1.943844), "M"),
+            (_, Some(knots), _) => (Some(knots), "N"),
+            (_, None, Some(kmh)) => (Some(kmh), "K"),
+            (_, None, None) => (None, ""),
+        };
+        make_sentence(
+            '$',
+            &format!(
+                "{}MWV,{},{},{},{},{}",
+                talker_id,
+                format_number_field(self.wind_angle, 1),
+                match self.relative {
+                    Some(true) => "R",
+                    Some(false) => "T",
+                    None => "",
+                },
+                format_number_field(wind_speed, 2),
+                unit,
+                if self.wind_angle.is_some() && wind_speed.is_some() {
+                    "A"
+                } else {
+                    "V"
+                },
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -107,6 +137,7 @@ mod test {
                     assert_eq!(mwv.relative, Some(false));
                     assert_eq!(mwv.wind_speed_knots, Some(33.3));
                     assert_eq!(mwv.wind_speed_kmh, Some(33.3 * 1.852));
+                    assert_eq!(mwv.wind_speed_unit, Some(SpeedUnit::Knots));
                 }
                 _ => {
                     assert!(false);
@@ -117,4 +148,29 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_mwv() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$WIMWV,295.4,T,33.3,N,A*1C") {
+            Ok(ParsedMessage::Mwv(mwv)) => {
+                let sentence = mwv.to_sentence("WI");
+                assert_eq!(sentence, "$WIMWV,295.4,T,33.3,N,A*1C");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Mwv(mwv)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Wind speed is encoded in the received unit
+        for s in ["$WIMWV,214.8,R,0.1,K,A*28", "$WIMWV,214.8,R,5.2,M,A*28"] {
+            match p.parse_sentence(s) {
+                Ok(ParsedMessage::Mwv(mwv)) => {
+                    assert_eq!(mwv.to_sentence("WI"), s);
+                }
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+    }
 }
@@ -0,0 +1,178 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// RMB - recommended minimum navigation information
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RmbData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// True = data valid, false = navigation receiver warning
+    pub data_valid: Option<bool>,
+
+    /// Cross track error in nautical miles
+    pub cross_track_error: Option<f64>,
+
+    /// Direction to steer to correct the cross track error
+    pub steer_direction: Option<SteerDirection>,
+
+    /// Origin waypoint ID
+    pub origin_waypoint_id: Option<String>,
+
+    /// Destination waypoint ID
+    pub destination_waypoint_id: Option<String>,
+
+    /// Latitude of the destination waypoint in degrees
+    pub destination_latitude: Option<f64>,
+
+    /// Longitude of the destination waypoint in degrees
+    pub destination_longitude: Option<f64>,
+
+    /// Range to destination in nautical miles
+    pub range_to_destination: Option<f64>,
+
+    /// Bearing to destination in degrees (True)
+    pub bearing_to_destination: Option<f64>,
+
+    /// Destination closing velocity in knots
+    pub closing_velocity: Option<f64>,
+
+    /// True = arrival circle entered, false = not entered
+    pub arrival_circle_entered: Option<bool>,
+
+    /// FAA mode indicator (NMEA 2.3 and later)
+    pub faa_mode: Option<FaaMode>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxRMB: Recommended Minimum Navigation Information
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Rmb(RmbData {
+        source: nav_system,
+        data_valid: pick_status_field(&split, 1),
+        cross_track_error: pick_number_field(&split, 2, "cross_track_error")?,
+        steer_direction: SteerDirection::new(split.get(3).unwrap_or(&"")).ok(),
+        origin_waypoint_id: pick_string_field(&split, 4),
+        destination_waypoint_id: pick_string_field(&split, 5),
+        destination_latitude: parse_latitude_ddmm_mmm(
+            split.get(6).unwrap_or(&""),
+            split.get(7).unwrap_or(&""),
+        )
+        .map_err(|e| e.with_field(6, "destination_latitude"))?,
+        destination_longitude: parse_longitude_dddmm_mmm(
+            split.get(8).unwrap_or(&""),
+            split.get(9).unwrap_or(&""),
+        )
+        .map_err(|e| e.with_field(8, "destination_longitude"))?,
+        range_to_destination: pick_number_field(&split, 10, "range_to_destination")?,
+        bearing_to_destination: pick_number_field(&split, 11, "bearing_to_destination")?,
+        closing_velocity: pick_number_field(&split, 12, "closing_velocity")?,
+        arrival_circle_entered: pick_status_field(&split, 13),
+        faa_mode: FaaMode::new(split.get(14).unwrap_or(&"")).ok(),
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for RmbData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (latitude, ns) = format_latitude_ddmm_mmm(self.destination_latitude);
+        let (longitude, ew) = format_longitude_dddmm_mmm(self.destination_longitude);
+        let mut content = format!(
+            "{}RMB,{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            talker_id,
+            format_status_field(self.data_valid),
+            format_number_field(self.cross_track_error, 2),
+            self.steer_direction
+                .map(|d| d.to_value().to_string())
+                .unwrap_or_default(),
+            format_string_field(&self.origin_waypoint_id),
+            format_string_field(&self.destination_waypoint_id),
+            latitude,
+            ns,
+            longitude,
+            ew,
+            format_number_field(self.range_to_destination, 1),
+            format_number_field(self.bearing_to_destination, 1),
+            format_number_field(self.closing_velocity, 1),
+            format_status_field(self.arrival_circle_entered),
+        );
+        if let Some(faa_mode) = self.faa_mode {
+            content.push_str(&format!(",{}", faa_mode.to_value()));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gprmb() {
+        let mut p = NmeaParser::new();
+        match p
+            .parse_sentence("$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20")
+        {
+            Ok(ParsedMessage::Rmb(rmb)) => {
+                assert_eq!(rmb.source, NavigationSystem::Gps);
+                assert_eq!(rmb.data_valid, Some(true));
+                assert_eq!(rmb.cross_track_error, Some(0.66));
+                assert_eq!(rmb.steer_direction, Some(SteerDirection::Left));
+                assert_eq!(rmb.origin_waypoint_id, Some("003".into()));
+                assert_eq!(rmb.destination_waypoint_id, Some("004".into()));
+                assert::close(rmb.destination_latitude.unwrap_or(0.0), 49.287, 0.001);
+                assert::close(rmb.destination_longitude.unwrap_or(0.0), -123.160, 0.001);
+                assert_eq!(rmb.range_to_destination, Some(1.3));
+                assert_eq!(rmb.bearing_to_destination, Some(52.5));
+                assert_eq!(rmb.closing_velocity, Some(0.5));
+                assert_eq!(rmb.arrival_circle_entered, Some(false));
+                assert_eq!(rmb.faa_mode, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_encode_rmb() {
+        let mut p = NmeaParser::new();
+        match p
+            .parse_sentence("$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20")
+        {
+            Ok(ParsedMessage::Rmb(rmb)) => {
+                let sentence = rmb.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPRMB,A,0.66,L,003,004,4917.240,N,12309.570,W,1.3,52.5,0.5,V*10"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Rmb(rmb)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+}
@@ -16,13 +16,14 @@ limitations under the License.
 use super::*;
 
 /// RMC - position, velocity, and time (Recommended Minimum sentence C)
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RmcData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// Fix datetime based on HHMMSS and DDMMYY
-    #[serde(with = "json_date_time_utc")]
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Status: true = active, false = void.
@@ -40,8 +41,14 @@ pub struct RmcData {
     /// Track angle in degrees (True)
     pub bearing: Option<f64>,
 
-    /// Magnetic variation in degrees
+    /// Magnetic variation in degrees, positive to east and negative to west
     pub variation: Option<f64>,
+
+    /// FAA mode indicator (NMEA 2.3 and later)
+    pub faa_mode: Option<FaaMode>,
+
+    /// Navigational status (NMEA 4.1 and later)
+    pub nav_status: Option<NavigationalStatus>,
 }
 
 impl LatLon for RmcData {
@@ -60,13 +67,18 @@ impl LatLon for RmcData {
 pub(crate) fn handle(
     sentence: &str,
     nav_system: NavigationSystem,
+    now: Option<DateTime<Utc>>,
 ) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Rmc(RmcData {
         source: nav_system,
-        timestamp: parse_yymmdd_hhmmss(split.get(9).unwrap_or(&""), split.get(1).unwrap_or(&""))
-            .ok(),
+        timestamp: parse_yymmdd_hhmmss(
+            split.get(9).unwrap_or(&""),
+            split.get(1).unwrap_or(&""),
+            now,
+        )
+        .ok(),
         status_active: {
             let s = split.get(2).unwrap_or(&"");
             match *s {
@@ -75,39 +87,94 @@ pub(crate) fn handle(
                 "V" => Some(false),
                 "" => None,
                 _ => {
-                    return Err(format!("Invalid RMC navigation receiver status: {}", s).into());
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidField,
+                        format!("Invalid RMC navigation receiver status: {}", s),
+                    )
+                    .with_field(2, "status_active")
+                    .with_raw_value(s));
                 }
             }
         },
-        latitude: parse_latitude_ddmm_mmm(
-            split.get(3).unwrap_or(&""),
-            split.get(4).unwrap_or(&""),
-        )?,
+        latitude: parse_latitude_ddmm_mmm(split.get(3).unwrap_or(&""), split.get(4).unwrap_or(&""))
+            .map_err(|e| e.with_field(3, "latitude"))?,
         longitude: parse_longitude_dddmm_mmm(
             split.get(5).unwrap_or(&""),
             split.get(6).unwrap_or(&""),
-        )?,
-        sog_knots: pick_number_field(&split, 7)?,
-        bearing: pick_number_field(&split, 8)?,
+        )
+        .map_err(|e| e.with_field(5, "longitude"))?,
+        sog_knots: pick_number_field(&split, 7, "sog_knots")?,
+        bearing: pick_number_field(&split, 8, "bearing")?,
         variation: {
-            if let Some(val) = pick_number_field::<f64>(&split, 10)? {
+            if let Some(val) = pick_number_field::<f64>(&split, 10, "variation")? {
                 let side = split.get(11).unwrap_or(&"");
                 match *side {
                     "E" => Some(val),
                     "W" => Some(-val),
                     _ => {
-                        return Err(format!("Invalid RMC variation side: {}", side).into());
+                        return Err(ParseError::new(
+                            ParseErrorKind::InvalidField,
+                            format!("Invalid RMC variation side: {}", side),
+                        )
+                        .with_field(11, "variation_side")
+                        .with_raw_value(side));
                     }
                 }
             } else {
                 None
             }
         },
+        faa_mode: FaaMode::new(split.get(12).unwrap_or(&"")).ok(),
+        nav_status: NavigationalStatus::new(split.get(13).unwrap_or(&"")).ok(),
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for RmcData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (latitude, ns) = format_latitude_ddmm_mmm(self.latitude);
+        let (longitude, ew) = format_longitude_dddmm_mmm(self.longitude);
+        let mut content = format!(
+            "{}RMC,{},{},{},{},{},{},{},{},{},{},{}",
+            talker_id,
+            format_hhmmss_ss(self.timestamp),
+            match self.status_active {
+                Some(true) => "A",
+                Some(false) => "V",
+                None => "",
+            },
+            latitude,
+            ns,
+            longitude,
+            ew,
+            format_number_field(self.sog_knots, 3),
+            format_number_field(self.bearing, 2),
+            format_ddmmyy(self.timestamp),
+            format_number_field(self.variation.map(|v| v.abs()), 2),
+            match self.variation {
+                Some(v) if v < 0.0 => "W",
+                Some(_) => "E",
+                None => "",
+            },
+        );
+        if self.faa_mode.is_some() || self.nav_status.is_some() {
+            content.push_str(&format!(
+                ",{}",
+                self.faa_mode
+                    .map(|m| m.to_value().to_string())
+                    .unwrap_or_default()
+            ));
+        }
+        if let Some(nav_status) = self.nav_status {
+            content.push_str(&format!(",{}", nav_status.to_value()));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -157,6 +224,8 @@ mod test {
                         assert_eq!(rmc.sog_knots, None);
                         assert_eq!(rmc.bearing, None);
                         assert_eq!(rmc.variation, None);
+                        assert_eq!(rmc.faa_mode, None);
+                        assert_eq!(rmc.nav_status, None);
                     }
                     ParsedMessage::Incomplete => {
                         assert!(false);
@@ -171,4 +240,51 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_parse_rmc_mode_and_nav_status() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence(
+            "$GNRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,R,S*25",
+        ) {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(rmc.variation, None);
+                assert_eq!(rmc.faa_mode, Some(FaaMode::RealTimeKinematic));
+                assert_eq!(rmc.nav_status, Some(NavigationalStatus::Safe));
+
+                let sentence = rmc.to_sentence("GN");
+                assert_eq!(
+                    sentence,
+                    "$GNRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,R,S*25"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Rmc(rmc)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // All FAA mode indicators are recognized
+        for c in ["A", "D", "E", "N", "S", "F", "R", "M", "P"] {
+            assert_eq!(FaaMode::new(c).map(|m| m.to_string()), Ok(c.to_string()));
+        }
+        assert!(FaaMode::new("X").is_err());
+    }
+
+    #[test]
+    fn test_encode_rmc() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*67")
+        {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                let sentence = rmc.to_sentence("GP");
+                assert_eq!(
+                    sentence,
+                    "$GPRMC,225446.00,A,4916.450,N,12311.120,W,0.5,54.7,191120,20.3,E*49"
+                );
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Rmc(rmc)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// STN - MSK Receiver Signal
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StnData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -37,12 +38,27 @@ pub(crate) fn handle(
 
     Ok(ParsedMessage::Stn(StnData {
         source: nav_system,
-        talker_id: pick_number_field(&split, 1)?,
+        talker_id: pick_number_field(&split, 1, "talker_id")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for StnData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}STN,{}",
+                talker_id,
+                format_integer_field(self.talker_id, 2)
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -67,4 +83,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_stn() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPSTN,23") {
+            Ok(ParsedMessage::Stn(stn)) => {
+                let sentence = stn.to_sentence("GP");
+                assert_eq!(sentence, "$GPSTN,23*73");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Stn(stn)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
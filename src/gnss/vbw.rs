@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// VBW - Dual Ground/Water Speed
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VbwData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -52,8 +53,8 @@ pub(crate) fn handle(
 
     Ok(ParsedMessage::Vbw(VbwData {
         source: nav_system,
-        lon_water_speed_knots: pick_number_field(&split, 1)?,
-        tr_water_speed_knots: pick_number_field(&split, 2)?,
+        lon_water_speed_knots: pick_number_field(&split, 1, "lon_water_speed_knots")?,
+        tr_water_speed_knots: pick_number_field(&split, 2, "tr_water_speed_knots")?,
         water_speed_valid: {
             match *split.get(3).unwrap_or(&"") {
                 "A" => Some(true),
@@ -61,8 +62,8 @@ pub(crate) fn handle(
                 _ => Some(false),
             }
         },
-        lon_ground_speed_knots: pick_number_field(&split, 4)?,
-        tr_ground_speed_knots: pick_number_field(&split, 5)?,
+        lon_ground_speed_knots: pick_number_field(&split, 4, "lon_ground_speed_knots")?,
+        tr_ground_speed_knots: pick_number_field(&split, 5, "tr_ground_speed_knots")?,
         ground_speed_valid: {
             match *split.get(6).unwrap_or(&"") {
                 "A" => Some(true),
@@ -75,6 +76,31 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for VbwData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let status = |valid: Option<bool>| match valid {
+            Some(true) => "A",
+            Some(false) => "V",
+            None => "",
+        };
+        make_sentence(
+            '$',
+            &format!(
+                "{}VBW,{},{},{},{},{},{}",
+                talker_id,
+                format_number_field(self.lon_water_speed_knots, 2),
+                format_number_field(self.tr_water_speed_knots, 2),
+                status(self.water_speed_valid),
+                format_number_field(self.lon_ground_speed_knots, 2),
+                format_number_field(self.tr_ground_speed_knots, 2),
+                status(self.ground_speed_valid),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -104,4 +130,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vbw() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPVBW,2.0,1.5,A,2.1,1.6,X") {
+            Ok(ParsedMessage::Vbw(vbw)) => {
+                let sentence = vbw.to_sentence("GP");
+                assert_eq!(sentence, "$GPVBW,2.0,1.5,A,2.1,1.6,V*41");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Vbw(vbw)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -17,7 +17,8 @@ limitations under the License.
 use super::*;
 
 /// VHW - Water speed and heading
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VhwData {
     /// Heading - true
     pub heading_true: Option<f64>,
@@ -40,15 +41,33 @@ pub(crate) fn handle(sentence: &str) -> Result<ParsedMessage, ParseError> {
     let split: Vec<&str> = sentence.split(',').collect();
 
     Ok(ParsedMessage::Vhw(VhwData {
-        heading_true: pick_number_field(&split, 1)?,
-        heading_magnetic: pick_number_field(&split, 3)?,
-        speed_through_water_knots: pick_number_field(&split, 5)?,
-        speed_through_water_kmh: pick_number_field(&split, 7)?,
+        heading_true: pick_number_field(&split, 1, "heading_true")?,
+        heading_magnetic: pick_number_field(&split, 3, "heading_magnetic")?,
+        speed_through_water_knots: pick_number_field(&split, 5, "speed_through_water_knots")?,
+        speed_through_water_kmh: pick_number_field(&split, 7, "speed_through_water_kmh")?,
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for VhwData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}VHW,{},T,{},M,{},N,{},K",
+                talker_id,
+                format_number_field(self.heading_true, 2),
+                format_number_field(self.heading_magnetic, 2),
+                format_number_field(self.speed_through_water_knots, 3),
+                format_number_field(self.speed_through_water_kmh, 3),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -72,4 +91,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vhw() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$IIVHW,15.0,T,15.0,M,6.3,N,11.8,K*68") {
+            Ok(ParsedMessage::Vhw(vhw)) => {
+                let sentence = vhw.to_sentence("II");
+                assert_eq!(sentence, "$IIVHW,15.0,T,15.0,M,6.3,N,11.8,K*68");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Vhw(vhw)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
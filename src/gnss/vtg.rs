@@ -16,7 +16,8 @@ limitations under the License.
 use super::*;
 
 /// VTG - track made good and speed over ground
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VtgData {
     /// Navigation system
     pub source: NavigationSystem,
@@ -48,16 +49,43 @@ pub(crate) fn handle(
 
     Ok(ParsedMessage::Vtg(VtgData {
         source: nav_system,
-        cog_true: pick_number_field(&split, 1).ok().unwrap_or(None),
-        cog_magnetic: pick_number_field(&split, 3).ok().unwrap_or(None),
-        sog_knots: pick_number_field(&split, 5).ok().unwrap_or(None),
-        sog_kph: pick_number_field(&split, 7).ok().unwrap_or(None),
+        cog_true: pick_number_field(&split, 1, "cog_true")
+            .ok()
+            .unwrap_or(None),
+        cog_magnetic: pick_number_field(&split, 3, "cog_magnetic")
+            .ok()
+            .unwrap_or(None),
+        sog_knots: pick_number_field(&split, 5, "sog_knots")
+            .ok()
+            .unwrap_or(None),
+        sog_kph: pick_number_field(&split, 7, "sog_kph").ok().unwrap_or(None),
         faa_mode: FaaMode::new(split.get(9).unwrap_or(&"")).ok(),
     }))
 }
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for VtgData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        make_sentence(
+            '$',
+            &format!(
+                "{}VTG,{},T,{},M,{},N,{},K,{}",
+                talker_id,
+                format_number_field(self.cog_true, 2),
+                format_number_field(self.cog_magnetic, 2),
+                format_number_field(self.sog_knots, 3),
+                format_number_field(self.sog_kph, 3),
+                self.faa_mode
+                    .map(|m| m.to_value().to_string())
+                    .unwrap_or_default(),
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -87,4 +115,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_vtg() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$BDVTG,054.7,T,034.4,M,005.5,N,010.2,K,D*31") {
+            Ok(ParsedMessage::Vtg(vtg)) => {
+                let sentence = vtg.to_sentence("BD");
+                assert_eq!(sentence, "$BDVTG,54.7,T,34.4,M,5.5,N,10.2,K,D*01");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Vtg(vtg)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
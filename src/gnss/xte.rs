@@ -0,0 +1,182 @@
+/*
+Copyright 2021 Linus Eing
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+use super::*;
+
+/// XTE - cross-track error, measured
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct XteData {
+    /// Navigation system
+    pub source: NavigationSystem,
+
+    /// True = data valid, false = Loran-C blink or SNR warning
+    pub data_valid: Option<bool>,
+
+    /// True = cycle lock valid, false = Loran-C cycle lock warning
+    pub cycle_lock: Option<bool>,
+
+    /// Cross track error in nautical miles
+    pub cross_track_error: Option<f64>,
+
+    /// Direction to steer to correct the cross track error
+    pub steer_direction: Option<SteerDirection>,
+
+    /// Unit of the cross track error in the received sentence
+    pub cross_track_error_unit: Option<DistanceUnit>,
+
+    /// FAA mode indicator (NMEA 2.3 and later)
+    pub faa_mode: Option<FaaMode>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// xxXTE: Cross-Track Error, Measured
+pub(crate) fn handle(
+    sentence: &str,
+    nav_system: NavigationSystem,
+) -> Result<ParsedMessage, ParseError> {
+    let split: Vec<&str> = sentence.split(',').collect();
+
+    Ok(ParsedMessage::Xte(XteData {
+        source: nav_system,
+        data_valid: pick_status_field(&split, 1),
+        cycle_lock: pick_status_field(&split, 2),
+        cross_track_error: pick_cross_track_error(&split, 3)?,
+        steer_direction: SteerDirection::new(split.get(4).unwrap_or(&"")).ok(),
+        cross_track_error_unit: DistanceUnit::new(split.get(5).unwrap_or(&"")).ok(),
+        faa_mode: FaaMode::new(split.get(6).unwrap_or(&"")).ok(),
+    }))
+}
+
+/// Pick cross track error which is followed by steer direction and unit ("N" for nautical miles,
+/// "K" for kilometres) fields, and convert it to nautical miles.
+pub(crate) fn pick_cross_track_error(
+    split: &[&str],
+    num: usize,
+) -> Result<Option<f64>, ParseError> {
+    let xte = pick_number_field::<f64>(split, num, "cross_track_error")?;
+    match *split.get(num + 2).unwrap_or(&"") {
+        "K" => Ok(xte.map(|v| v / 1.852)),
+        _ => Ok(xte),
+    }
+}
+
+/// Format cross track error given in nautical miles in the given unit, followed by the steer
+/// direction and unit fields. Nautical miles are used if the unit is not known.
+pub(crate) fn format_cross_track_error(
+    xte: Option<f64>,
+    steer_direction: Option<SteerDirection>,
+    unit: Option<DistanceUnit>,
+) -> String {
+    let unit = unit.unwrap_or(DistanceUnit::NauticalMiles);
+    let xte = match unit {
+        DistanceUnit::NauticalMiles => xte,
+        DistanceUnit::Kilometers => xte.map(|v| v * 1.852),
+    };
+    format!(
+        "{},{},{}",
+        format_number_field(xte, 3),
+        steer_direction
+            .map(|d| d.to_value().to_string())
+            .unwrap_or_default(),
+        unit.to_value()
+    )
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl ToSentence for XteData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let mut content = format!(
+            "{}XTE,{},{},{}",
+            talker_id,
+            format_status_field(self.data_valid),
+            format_status_field(self.cycle_lock),
+            format_cross_track_error(
+                self.cross_track_error,
+                self.steer_direction,
+                self.cross_track_error_unit
+            ),
+        );
+        if let Some(faa_mode) = self.faa_mode {
+            content.push_str(&format!(",{}", faa_mode.to_value()));
+        }
+        make_sentence('$', &content)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpxte() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPXTE,A,A,0.67,L,N*6F") {
+            Ok(ParsedMessage::Xte(xte)) => {
+                assert_eq!(xte.source, NavigationSystem::Gps);
+                assert_eq!(xte.data_valid, Some(true));
+                assert_eq!(xte.cycle_lock, Some(true));
+                assert_eq!(xte.cross_track_error, Some(0.67));
+                assert_eq!(xte.steer_direction, Some(SteerDirection::Left));
+                assert_eq!(
+                    xte.cross_track_error_unit,
+                    Some(DistanceUnit::NauticalMiles)
+                );
+                assert_eq!(xte.faa_mode, None);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Kilometres are converted to nautical miles
+        let split: Vec<&str> = "$GPXTE,A,A,1.852,R,K,D".split(',').collect();
+        assert::close(
+            pick_cross_track_error(&split, 3).unwrap().unwrap_or(0.0),
+            1.0,
+            0.001,
+        );
+    }
+
+    #[test]
+    fn test_encode_xte() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPXTE,A,A,0.67,L,N,D*07") {
+            Ok(ParsedMessage::Xte(xte)) => {
+                assert_eq!(xte.faa_mode, Some(FaaMode::Differential));
+                let sentence = xte.to_sentence("GP");
+                assert_eq!(sentence, "$GPXTE,A,A,0.67,L,N,D*07");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Xte(xte)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Cross track error is encoded in the received unit
+        for s in ["$GPXTE,A,A,0.1,R,K,D*2C", "$GPXTE,A,A,1.852,R,K,D*23"] {
+            match p.parse_sentence(s) {
+                Ok(ParsedMessage::Xte(xte)) => {
+                    assert_eq!(xte.cross_track_error_unit, Some(DistanceUnit::Kilometers));
+                    assert_eq!(xte.to_sentence("GP"), s);
+                }
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+    }
+}
@@ -17,17 +17,18 @@ limitations under the License.
 use super::*;
 
 /// ZDA - Time and date
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ZdaData {
     /// Navigation system
     pub source: NavigationSystem,
 
     /// UTC
-    #[serde(with = "json_date_time_utc")]
+    #[cfg_attr(feature = "serde", serde(with = "json_date_time_utc"))]
     pub timestamp_utc: Option<DateTime<Utc>>,
 
     /// Local time zone offset
-    #[serde(with = "json_fixed_offset")]
+    #[cfg_attr(feature = "serde", serde(with = "json_fixed_offset"))]
     pub timezone_local: Option<FixedOffset>,
 }
 
@@ -53,6 +54,40 @@ pub(crate) fn handle(
 
 // -------------------------------------------------------------------------------------------------
 
+impl ToSentence for ZdaData {
+    fn to_sentence(&self, talker_id: &str) -> String {
+        let (tz_hours, tz_minutes) = match self.timezone_local {
+            Some(tz) => {
+                let seconds = tz.local_minus_utc();
+                (
+                    format!(
+                        "{}{:02}",
+                        if seconds < 0 { "-" } else { "" },
+                        seconds.abs() / 3600
+                    ),
+                    format!("{:02}", (seconds.abs() % 3600) / 60),
+                )
+            }
+            None => ("".into(), "".into()),
+        };
+        make_sentence(
+            '$',
+            &format!(
+                "{}ZDA,{},{},{},{},{},{}",
+                talker_id,
+                format_hhmmss_ss(self.timestamp_utc),
+                format_integer_field(self.timestamp_utc.map(|t| t.day()), 2),
+                format_integer_field(self.timestamp_utc.map(|t| t.month()), 2),
+                format_integer_field(self.timestamp_utc.map(|t| t.year()), 4),
+                tz_hours,
+                tz_minutes,
+            ),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -81,4 +116,18 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_zda() {
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPZDA,072914.00,31,05,2018,-03,00") {
+            Ok(ParsedMessage::Zda(zda)) => {
+                let sentence = zda.to_sentence("GP");
+                assert_eq!(sentence, "$GPZDA,072914.00,31,05,2018,-03,00*4D");
+                assert_eq!(p.parse_sentence(&sentence), Ok(ParsedMessage::Zda(zda)));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
 }
@@ -0,0 +1,20 @@
+use alloc::string::String;
+use bitvec::prelude::*;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(bv: &BitVec, serializer: S) -> Result<S::Ok, S::Error> {
+    let bits: String = bv.iter().map(|b| if *b { '1' } else { '0' }).collect();
+    bits.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BitVec, D::Error> {
+    let bits: String = String::deserialize(deserializer)?;
+
+    bits.chars()
+        .map(|c| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            _ => Err(D::Error::custom("Invalid bit character")),
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
+use alloc::string::String;
+use chrono::{DateTime, Utc};
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 pub fn serialize<S: Serializer>(
@@ -13,13 +14,13 @@ pub fn serialize<S: Serializer>(
 
 pub fn deserialize<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> Result<Option<NaiveDateTime>, D::Error> {
-    let time: Option<&str> = Option::deserialize(deserializer)?;
+) -> Result<Option<DateTime<Utc>>, D::Error> {
+    let time: Option<String> = Option::deserialize(deserializer)?;
 
     match time {
         Some(t) => {
             let dt = DateTime::parse_from_rfc3339(&t).map_err(D::Error::custom)?;
-            Ok(Some(dt.naive_utc()))
+            Ok(Some(dt.with_timezone(&Utc)))
         }
         None => Ok(None),
     }
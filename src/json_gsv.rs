@@ -0,0 +1,26 @@
+use crate::gnss::GsvData;
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Internally tagged enums can't hold sequences directly, so GSV satellites are wrapped in an
+// object with a single field.
+#[derive(Serialize)]
+struct GsvRef<'a> {
+    satellites: &'a Vec<GsvData>,
+}
+
+#[derive(Deserialize)]
+struct GsvOwned {
+    satellites: Vec<GsvData>,
+}
+
+pub fn serialize<S: Serializer>(
+    satellites: &Vec<GsvData>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    GsvRef { satellites }.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<GsvData>, D::Error> {
+    Ok(GsvOwned::deserialize(deserializer)?.satellites)
+}
@@ -21,10 +21,13 @@ limitations under the License.
 //! GLONASS, Galileo, BeiDou, NavIC and QZSS satellite systems.
 //!
 //! Usage in a `#[no_std]` environment is also possible though an allocator is required
+//!
+//! Serialization and deserialization of the parsed messages with `serde` is enabled with the
+//! optional `serde` feature.
 
 #![forbid(unsafe_code)]
 #![allow(dead_code)]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 #[macro_use]
 extern crate log;
@@ -34,34 +37,59 @@ extern crate num_traits;
 #[macro_use]
 extern crate alloc;
 
+use alloc::boxed::Box;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use bitvec::prelude::*;
 pub use chrono;
 use chrono::prelude::*;
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, Duration, TimeZone};
 use hashbrown::HashMap;
-use core::cmp::max;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use core::cmp::{max, min};
 use core::str::FromStr;
 
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "std")))]
 use num_traits::float::FloatCore;
 
 pub mod ais;
 mod error;
 pub mod gnss;
+mod strict;
+mod tag_block;
 mod util;
+#[cfg(feature = "serde")]
+mod json_bit_vec;
+#[cfg(feature = "serde")]
 mod json_date_time_utc;
+#[cfg(feature = "serde")]
 mod json_fixed_offset;
-
-pub use error::ParseError;
+#[cfg(feature = "serde")]
+mod json_gsv;
+#[cfg(feature = "tokio")]
+mod codec;
+#[cfg(feature = "std")]
+mod reader;
+
+pub use error::{ErrorLocation, ParseError, ParseErrorKind, StrictRule};
+#[cfg(feature = "tokio")]
+pub use codec::NmeaCodec;
+#[cfg(feature = "std")]
+pub use reader::NmeaReader;
+pub use tag_block::{TagBlock, TagGroup};
 use util::*;
 
 // -------------------------------------------------------------------------------------------------
 
 /// Result from function `NmeaParser::parse_sentence()`. If the given sentence represents only a
 /// partial message `ParsedMessage::Incomplete` is returned.
+///
+/// With serde the message is internally tagged, i.e. the variant name is stored in `type` field
+/// next to the fields of the contained data structure.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum ParsedMessage {
     /// The given sentence is only part of multi-sentence message and we need more data to
     /// create the actual result. State is stored in `NmeaParser` object.
@@ -78,12 +106,12 @@ pub enum ParsedMessage {
 
     /// AIS VDM/VDO type 6
     BinaryAddressedMessage(ais::BinaryAddressedMessage),
-    //
-    //    /// AIS VDM/VDO type 7
-    //    BinaryAcknowledge(ais::BinaryAcknowledge),
-    //
-    //    /// AIS VDM/VDO type 8
-    //    BinaryBroadcastMessage(ais::BinaryBroadcastMessage),
+
+    /// AIS VDM/VDO type 7
+    BinaryAcknowledge(ais::BinaryAcknowledge),
+
+    /// AIS VDM/VDO type 8
+    BinaryBroadcastMessage(ais::BinaryBroadcastMessage),
 
     // AIS VDM/VDO type 9
     StandardSarAircraftPositionReport(ais::StandardSarAircraftPositionReport),
@@ -112,6 +140,9 @@ pub enum ParsedMessage {
     // AIS VDM/VRO type 17
     DgnssBroadcastBinaryMessage(ais::DgnssBroadcastBinaryMessage),
 
+    /// AIS VDM/VDO type 19
+    ExtendedClassBPositionReport(ais::ExtendedClassBPositionReport),
+
     // AIS VDM/VRO type 20
     DataLinkManagementMessage(ais::DataLinkManagementMessage),
 
@@ -143,7 +174,7 @@ pub enum ParsedMessage {
     Gsa(gnss::GsaData),
 
     /// GSV
-    Gsv(Vec<gnss::GsvData>),
+    Gsv(#[cfg_attr(feature = "serde", serde(with = "json_gsv"))] Vec<gnss::GsvData>),
 
     /// VTG
     Vtg(gnss::VtgData),
@@ -186,6 +217,30 @@ pub enum ParsedMessage {
 
     /// MWV
     Mwv(gnss::MwvData),
+
+    /// GST
+    Gst(gnss::GstData),
+
+    /// GBS
+    Gbs(gnss::GbsData),
+
+    /// RMB
+    Rmb(gnss::RmbData),
+
+    /// APB
+    Apb(gnss::ApbData),
+
+    /// XTE
+    Xte(gnss::XteData),
+
+    /// BOD
+    Bod(gnss::BodData),
+
+    /// BWC
+    Bwc(gnss::BwcData),
+
+    /// BWR
+    Bwr(gnss::BwcData),
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -203,13 +258,128 @@ pub trait LatLon {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Default maximum number of pending sentence fragments in `NmeaParser`
+pub const DEFAULT_MAX_FRAGMENTS: usize = 1000;
+
+/// Default maximum number of pending AIS type 24 parts in `NmeaParser`
+pub const DEFAULT_MAX_VSDS: usize = 10000;
+
+/// Maximum length of a line buffered by `NmeaParser::push_bytes`. Longer lines are discarded.
+pub const MAX_LINE_LENGTH: usize = 1024;
+
+/// Source of the current time for `NmeaParser::set_clock`. Implemented for closures returning
+/// `DateTime<Utc>` which are `Clone` and `Send`, so a clock may keep state of its own, e.g. the
+/// timestamp of the log record being replayed.
+pub trait Clock: Send {
+    /// Return the current time
+    fn now(&mut self) -> DateTime<Utc>;
+
+    /// Clone the clock for a cloned parser
+    fn clone_box(&self) -> Box<dyn Clock>;
+}
+
+impl<F> Clock for F
+where
+    F: FnMut() -> DateTime<Utc> + Clone + Send + 'static,
+{
+    fn now(&mut self) -> DateTime<Utc> {
+        self()
+    }
+
+    fn clone_box(&self) -> Box<dyn Clock> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Clock> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 /// NMEA sentence parser which keeps multi-sentence state between `parse_sentence` calls.
 /// The parser tries to be as permissible as possible about the field formats because some NMEA
-/// encoders don't follow the standards strictly.
+/// encoders don't follow the standards strictly. In lenient mode (see `set_lenient`) even
+/// unparseable fields are tolerated, whereas strict mode (see `set_strict`) enforces the standard.
+///
+/// The amount of pending multi-sentence state is bounded. When a limit is exceeded the oldest
+/// entry is evicted. If the current time is given with `set_time`, entries older than the
+/// configured maximum age are expired too. Evicted and expired sentence fragments can be
+/// inspected with `orphaned_fragments`.
 #[derive(Clone)]
 pub struct NmeaParser {
-    saved_fragments: HashMap<String, String>,
-    saved_vsds: HashMap<u32, ais::VesselStaticData>,
+    saved_fragments: HashMap<String, SavedFragment>,
+    saved_vsds: HashMap<u32, SavedVsd>,
+    saved_tag_blocks: HashMap<u32, SavedTagBlock>,
+    orphaned_fragments: Vec<OrphanedFragment>,
+    max_fragments: usize,
+    max_vsds: usize,
+    max_fragment_age: Option<Duration>,
+    max_vsd_age: Option<Duration>,
+    now: Option<DateTime<Utc>>,
+    clock: Option<Box<dyn Clock>>,
+    seq: u64,
+    line_buffer: Vec<u8>,
+    line_overflow: bool,
+    lenient: bool,
+    strict: bool,
+    warnings: Vec<ParseError>,
+}
+
+/// Sentence fragment waiting for the rest of the fragments
+#[derive(Clone)]
+struct SavedFragment {
+    data: String,
+    fill_bits: usize,
+    seq: u64,
+    received: Option<DateTime<Utc>>,
+}
+
+/// AIS type 24 part waiting for the other part
+#[derive(Clone)]
+struct SavedVsd {
+    vsd: ais::VesselStaticData,
+    seq: u64,
+    received: Option<DateTime<Utc>>,
+}
+
+/// Combined TAG block of a sentence group waiting for the rest of the group
+#[derive(Clone)]
+struct SavedTagBlock {
+    tag_block: TagBlock,
+    sentences_seen: u64,
+    seq: u64,
+    received: Option<DateTime<Utc>>,
+}
+
+/// Sentence fragment which was dropped from `NmeaParser` before the rest of the fragments
+/// arrived.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrphanedFragment {
+    /// Key identifying the sentence type, fragment count and fragment number (and message ID and
+    /// radio channel for AIS)
+    pub key: String,
+
+    /// Payload (AIS) or the whole sentence (GNSS) of the fragment
+    pub data: String,
+
+    /// Time when the fragment was received if the time was set with `NmeaParser::set_time`
+    pub received: Option<DateTime<Utc>>,
+
+    /// Why the fragment was dropped
+    pub reason: OrphanReason,
+}
+
+/// Reason for dropping a sentence fragment
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrphanReason {
+    /// The maximum number of pending fragments was exceeded
+    Evicted,
+
+    /// The fragment was older than the maximum age
+    Expired,
 }
 
 impl Default for NmeaParser {
@@ -224,6 +394,20 @@ impl NmeaParser {
         NmeaParser {
             saved_fragments: HashMap::new(),
             saved_vsds: HashMap::new(),
+            saved_tag_blocks: HashMap::new(),
+            orphaned_fragments: Vec::new(),
+            max_fragments: DEFAULT_MAX_FRAGMENTS,
+            max_vsds: DEFAULT_MAX_VSDS,
+            max_fragment_age: Some(Duration::seconds(60)),
+            max_vsd_age: Some(Duration::minutes(15)),
+            now: None,
+            clock: None,
+            seq: 0,
+            line_buffer: Vec::new(),
+            line_overflow: false,
+            lenient: false,
+            strict: false,
+            warnings: Vec::new(),
         }
     }
 
@@ -232,16 +416,124 @@ impl NmeaParser {
     pub fn reset(&mut self) {
         self.saved_fragments.clear();
         self.saved_vsds.clear();
+        self.saved_tag_blocks.clear();
+        self.orphaned_fragments.clear();
+    }
+
+    /// Set the maximum number of pending sentence fragments. The default is
+    /// `DEFAULT_MAX_FRAGMENTS`.
+    pub fn set_max_fragments(&mut self, max: usize) {
+        self.max_fragments = max;
+        self.evict_fragments();
+    }
+
+    /// Set the maximum number of pending AIS type 24 parts. The default is `DEFAULT_MAX_VSDS`.
+    pub fn set_max_vsds(&mut self, max: usize) {
+        self.max_vsds = max;
+        self.evict_vsds();
+    }
+
+    /// Set the maximum age of pending sentence fragments or `None` for no limit. The default is
+    /// 60 seconds. The age is only checked when the time is given with `set_time`.
+    pub fn set_max_fragment_age(&mut self, age: Option<Duration>) {
+        self.max_fragment_age = age;
+    }
+
+    /// Set the maximum age of pending AIS type 24 parts or `None` for no limit. The default is
+    /// 15 minutes. The age is only checked when the time is given with `set_time`.
+    pub fn set_max_vsd_age(&mut self, age: Option<Duration>) {
+        self.max_vsd_age = age;
+    }
+
+    /// Set the current time. Sentences parsed after this call are considered to be received at
+    /// the given time and pending state older than the maximum age is expired. The time is also
+    /// used as the reference for inferring the missing date of GGA, GLL and GNS timestamps, the
+    /// century of RMC dates and the year of AIS ETA and other month-day-time fields. Without the
+    /// time 2000-01-01 is used as the reference. When replaying recorded data, call this with the
+    /// reception time of each sentence.
+    pub fn set_time(&mut self, now: DateTime<Utc>) {
+        self.now = Some(now);
+        self.expire();
+    }
+
+    /// Set a clock which is called to set the current time (as with `set_time`) before each
+    /// sentence is parsed, or `None` to stop using the clock. A clock reading the system time
+    /// makes the parser track real time without calling `set_time`, and a stateful clock can
+    /// follow the timestamps of replayed data. No clock is used by default.
+    pub fn set_clock(&mut self, clock: Option<Box<dyn Clock>>) {
+        self.clock = clock;
+    }
+
+    /// Enable or disable lenient mode. In lenient mode a field which fails to parse is treated as
+    /// empty (i.e. usually `None`) instead of failing the whole sentence, and the error is
+    /// reported as a warning by `parse_sentence_with_warnings`. Only comma-separated fields of
+    /// `$` sentences are covered; missing mandatory fields and AIS payload errors still fail.
+    /// Lenient mode is disabled by default.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    /// Enable or disable strict mode for standards compliance testing. In strict mode sentences
+    /// are rejected if they lack the checksum, have lowercase checksum digits, are longer than 82
+    /// characters, have unexpected number of fields or invalid AIS fill bits, or if AIS position
+    /// reports have out-of-range position or heading or a reserved navigation status. The broken
+    /// rule is reported with `ParseErrorKind::StrictViolation`. Strict mode is disabled by
+    /// default.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Return number of pending sentence fragments.
+    pub fn pending_fragments(&self) -> usize {
+        self.saved_fragments.len()
+    }
+
+    /// Return number of pending AIS type 24 parts.
+    pub fn pending_vsds(&self) -> usize {
+        self.saved_vsds.len()
+    }
+
+    /// Return the sentence fragments which were evicted or expired before the rest of the
+    /// fragments arrived. At most the maximum number of pending fragments is kept.
+    pub fn orphaned_fragments(&self) -> &[OrphanedFragment] {
+        &self.orphaned_fragments
+    }
+
+    /// Take the orphaned sentence fragments out of the parser.
+    pub fn drain_orphaned_fragments(&mut self) -> Vec<OrphanedFragment> {
+        core::mem::take(&mut self.orphaned_fragments)
     }
 
     /// Push string-to-string mapping to store.
     fn push_string(&mut self, key: String, value: String) {
-        self.saved_fragments.insert(key, value);
+        self.push_fragment(key, value, 0);
     }
 
     /// Pull string-to-string mapping by key from store.
     fn pull_string(&mut self, key: String) -> Option<String> {
-        self.saved_fragments.remove(&key)
+        self.pull_fragment(key).map(|(data, _)| data)
+    }
+
+    /// Push AIS sentence fragment payload and its fill bit count to store.
+    fn push_fragment(&mut self, key: String, value: String, fill_bits: usize) {
+        self.seq += 1;
+        self.saved_fragments.insert(
+            key,
+            SavedFragment {
+                data: value,
+                fill_bits,
+                seq: self.seq,
+                received: self.now,
+            },
+        );
+        self.evict_fragments();
+    }
+
+    /// Pull AIS sentence fragment payload and its fill bit count by key from store.
+    fn pull_fragment(&mut self, key: String) -> Option<(String, usize)> {
+        self.saved_fragments
+            .remove(&key)
+            .map(|f| (f.data, f.fill_bits))
     }
 
     /// Tests whether the given string-to-string mapping exists in the store.
@@ -256,12 +548,21 @@ impl NmeaParser {
 
     /// Push MMSI-to-VesselStaticData mapping to store.
     fn push_vsd(&mut self, mmsi: u32, vsd: ais::VesselStaticData) {
-        self.saved_vsds.insert(mmsi, vsd);
+        self.seq += 1;
+        self.saved_vsds.insert(
+            mmsi,
+            SavedVsd {
+                vsd,
+                seq: self.seq,
+                received: self.now,
+            },
+        );
+        self.evict_vsds();
     }
 
     /// Pull MMSI-to-VesselStaticData mapping from store.
     fn pull_vsd(&mut self, mmsi: u32) -> Option<ais::VesselStaticData> {
-        self.saved_vsds.remove(&mmsi)
+        self.saved_vsds.remove(&mmsi).map(|v| v.vsd)
     }
 
     /// Return number of MMSI-to-VesselStaticData mappings in store.
@@ -269,23 +570,255 @@ impl NmeaParser {
         self.saved_vsds.len()
     }
 
+    /// Evict the oldest fragments until the number of fragments is within the limit.
+    fn evict_fragments(&mut self) {
+        while self.saved_fragments.len() > self.max_fragments {
+            let oldest = self
+                .saved_fragments
+                .iter()
+                .min_by_key(|(_, f)| f.seq)
+                .map(|(k, _)| k.clone());
+            if let Some(key) = oldest {
+                self.orphan_fragment(key, OrphanReason::Evicted);
+            }
+        }
+        while self.saved_tag_blocks.len() > self.max_fragments {
+            let oldest = self
+                .saved_tag_blocks
+                .iter()
+                .min_by_key(|(_, t)| t.seq)
+                .map(|(k, _)| *k);
+            if let Some(group_id) = oldest {
+                debug!("Evicting pending TAG block of group {}", group_id);
+                self.saved_tag_blocks.remove(&group_id);
+            }
+        }
+    }
+
+    /// Evict the oldest type 24 parts until the number of them is within the limit.
+    fn evict_vsds(&mut self) {
+        while self.saved_vsds.len() > self.max_vsds {
+            let oldest = self
+                .saved_vsds
+                .iter()
+                .min_by_key(|(_, v)| v.seq)
+                .map(|(k, _)| *k);
+            if let Some(mmsi) = oldest {
+                debug!("Evicting pending type 24 part of MMSI {}", mmsi);
+                self.saved_vsds.remove(&mmsi);
+            }
+        }
+    }
+
+    /// Expire fragments and type 24 parts older than the maximum age.
+    fn expire(&mut self) {
+        let now = match self.now {
+            Some(now) => now,
+            None => return,
+        };
+        let is_expired = |received: Option<DateTime<Utc>>, max_age: Option<Duration>| match (
+            received, max_age,
+        ) {
+            (Some(received), Some(max_age)) => now - received > max_age,
+            _ => false,
+        };
+
+        let mut expired: Vec<(u64, String)> = self
+            .saved_fragments
+            .iter()
+            .filter(|(_, f)| is_expired(f.received, self.max_fragment_age))
+            .map(|(k, f)| (f.seq, k.clone()))
+            .collect();
+        expired.sort();
+        for (_, key) in expired {
+            self.orphan_fragment(key, OrphanReason::Expired);
+        }
+
+        let max_fragment_age = self.max_fragment_age;
+        self.saved_tag_blocks
+            .retain(|_, t| !is_expired(t.received, max_fragment_age));
+
+        let max_vsd_age = self.max_vsd_age;
+        self.saved_vsds
+            .retain(|_, v| !is_expired(v.received, max_vsd_age));
+    }
+
+    /// Move the given fragment to the orphaned fragments.
+    fn orphan_fragment(&mut self, key: String, reason: OrphanReason) {
+        if let Some(f) = self.saved_fragments.remove(&key) {
+            debug!("Orphaned sentence fragment {} ({:?})", key, reason);
+            self.orphaned_fragments.push(OrphanedFragment {
+                key,
+                data: f.data,
+                received: f.received,
+                reason,
+            });
+            if self.orphaned_fragments.len() > self.max_fragments {
+                let excess = self.orphaned_fragments.len() - self.max_fragments;
+                self.orphaned_fragments.drain(0..excess);
+            }
+        }
+    }
+
     /// Parse NMEA sentence into `ParsedMessage` enum. If the given sentence is part of
     /// a multipart message the related state is saved into the parser and
     /// `ParsedMessage::Incomplete` is returned. The actual result is returned when all the parts
     /// have been sent to the parser.
     pub fn parse_sentence(&mut self, sentence: &str) -> Result<ParsedMessage, ParseError> {
+        self.parse_sentence_with_tag_block(sentence)
+            .map(|(_, message)| message)
+    }
+
+    /// Parse NMEA sentence like `parse_sentence` and return also the field-level warnings about
+    /// the invalid fields ignored in lenient mode. Without lenient mode the warnings are always
+    /// empty.
+    pub fn parse_sentence_with_warnings(
+        &mut self,
+        sentence: &str,
+    ) -> Result<(ParsedMessage, Vec<ParseError>), ParseError> {
+        self.warnings.clear();
+        let message = self.parse_sentence(sentence)?;
+        Ok((message, core::mem::take(&mut self.warnings)))
+    }
+
+    /// Parse NMEA sentence optionally prefixed with an NMEA 4.x TAG block. Return the TAG block
+    /// (if any) together with the `ParsedMessage`. The TAG blocks of a sentence group (`g`) are
+    /// combined, and the group ID is used for reassembling AIS fragments lacking a sequential
+    /// message ID.
+    pub fn parse_sentence_with_tag_block(
+        &mut self,
+        sentence: &str,
+    ) -> Result<(Option<TagBlock>, ParsedMessage), ParseError> {
+        let (tag_block, sentence) = tag_block::split_tag_block(sentence)?;
+        let group = match tag_block.as_ref().and_then(|t| t.group) {
+            Some(g) if g.sentence_count > 1 => g,
+            _ => {
+                return self
+                    .parse_sentence_body(sentence, None)
+                    .map(|message| (tag_block, message));
+            }
+        };
+
+        // Combine the TAG block with the earlier ones of the group
+        let mut tag_block = tag_block.unwrap_or_default();
+        if let Some(saved) = self.saved_tag_blocks.get(&group.group_id) {
+            tag_block.merge(&saved.tag_block);
+        }
+        let sentences_seen = self
+            .saved_tag_blocks
+            .get(&group.group_id)
+            .map(|t| t.sentences_seen)
+            .unwrap_or(0)
+            | 1u64.checked_shl(group.sentence_number).unwrap_or(0);
+        let group_complete = (1..=group.sentence_count)
+            .all(|i| sentences_seen & 1u64.checked_shl(i).unwrap_or(0) != 0);
+
+        // Record the sentence as seen only if it could be parsed
+        let message = self.parse_sentence_body(sentence, Some(group.group_id as u64))?;
+        if group_complete {
+            self.saved_tag_blocks.remove(&group.group_id);
+        } else {
+            self.seq += 1;
+            self.saved_tag_blocks.insert(
+                group.group_id,
+                SavedTagBlock {
+                    tag_block: tag_block.clone(),
+                    sentences_seen,
+                    seq: self.seq,
+                    received: self.now,
+                },
+            );
+            self.evict_fragments();
+        }
+        Ok((Some(tag_block), message))
+    }
+
+    /// Push bytes received from a byte stream (e.g. a serial port) and parse the sentences
+    /// completed by them. The bytes may be split into chunks arbitrarily, and the sentences may be
+    /// terminated with CR, LF or CRLF. Bytes preceding the start of a sentence (or its TAG block)
+    /// are skipped, lines without a sentence are ignored and `Incomplete` results are dropped.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Vec<Result<ParsedMessage, ParseError>> {
+        let mut results = Vec::new();
+        for &b in bytes {
+            if b == b'\r' || b == b'\n' {
+                let line = core::mem::take(&mut self.line_buffer);
+                if !self.line_overflow {
+                    if let Some(result) = self.parse_line(&line) {
+                        results.push(result);
+                    }
+                }
+                self.line_overflow = false;
+            } else if self.line_buffer.len() < MAX_LINE_LENGTH {
+                self.line_buffer.push(b);
+            } else {
+                debug!("Discarding line longer than {} bytes", MAX_LINE_LENGTH);
+                self.line_buffer.clear();
+                self.line_overflow = true;
+            }
+        }
+        results
+    }
+
+    /// Parse a line received with `push_bytes`. Sentences (and TAG blocks) containing non-ASCII
+    /// bytes are rejected. A backslash which doesn't start a TAG block terminated before the
+    /// sentence is considered garbage.
+    fn parse_line(&mut self, line: &[u8]) -> Option<Result<ParsedMessage, ParseError>> {
+        let mut start_idx = line
+            .iter()
+            .position(|&b| b == b'\\' || b == b'$' || b == b'!')?;
+        if line[start_idx] == b'\\' {
+            let rest = &line[(start_idx + 1)..];
+            let tag_end = rest.iter().position(|&b| b == b'\\');
+            let sentence_start = rest.iter().position(|&b| b == b'$' || b == b'!');
+            match (tag_end, sentence_start) {
+                (Some(e), Some(s)) if e < s => {}
+                (_, Some(s)) => start_idx += 1 + s,
+                (_, None) => {}
+            }
+        }
+        let line = &line[start_idx..];
+        if !line.is_ascii() {
+            return Some(Err(ParseError::new(
+                ParseErrorKind::InvalidSentence,
+                "Non-ASCII characters in sentence",
+            )
+            .with_raw_value(&String::from_utf8_lossy(line))));
+        }
+        let line = core::str::from_utf8(line).ok()?;
+        match self.parse_sentence_with_tag_block(line) {
+            Ok((_, ParsedMessage::Incomplete)) => None,
+            result => Some(result.map(|(_, message)| message)),
+        }
+    }
+
+    /// Parse NMEA sentence without a TAG block. Argument `group_id` is used as the AIS message
+    /// ID if the sentence lacks one.
+    fn parse_sentence_body(
+        &mut self,
+        sentence: &str,
+        group_id: Option<u64>,
+    ) -> Result<ParsedMessage, ParseError> {
+        if let Some(clock) = self.clock.as_mut() {
+            let now = clock.now();
+            self.set_time(now);
+        }
+
         // Shed characters prefixing the message if they exist
         let sentence = {
             if let Some(start_idx) = sentence.find(['$', '!']) {
                 &sentence[start_idx..]
             } else {
-                return Err(ParseError::InvalidSentence(format!(
-                    "Invalid NMEA sentence: {}",
-                    sentence
-                )));
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidSentence,
+                    format!("No start character found: {}", sentence),
+                ));
             }
         };
 
+        if self.strict {
+            strict::check_sentence_length(sentence)?;
+        }
+
         // Calculate NMEA checksum and compare it to the given one. Also, remove the checksum part
         // from the sentence to simplify next processing steps.
         let mut checksum = 0;
@@ -294,7 +827,10 @@ impl NmeaParser {
                 if pos + 3 <= sentence.len() {
                     (
                         sentence[0..pos].to_string(),
-                        sentence[(pos + 1)..(pos + 3)].to_string(),
+                        sentence
+                            .get((pos + 1)..(pos + 3))
+                            .unwrap_or(&sentence[(pos + 1)..])
+                            .to_string(),
                     )
                 } else {
                     debug!("Invalid checksum found for sentence: {}", sentence);
@@ -309,11 +845,18 @@ impl NmeaParser {
             checksum ^= c as u8;
         }
         let checksum_hex_calculated = format!("{:02X?}", checksum);
+        if self.strict {
+            strict::check_checksum(&checksum_hex_given)?;
+        }
         if checksum_hex_calculated != checksum_hex_given && !checksum_hex_given.is_empty() {
-            return Err(ParseError::CorruptedSentence(format!(
-                "Corrupted NMEA sentence: {:02X?} != {:02X?}",
-                checksum_hex_calculated, checksum_hex_given
-            )));
+            return Err(ParseError::new(
+                ParseErrorKind::BadChecksum,
+                format!(
+                    "Checksum mismatch: {:02X?} != {:02X?}",
+                    checksum_hex_calculated, checksum_hex_given
+                ),
+            )
+            .with_raw_value(&checksum_hex_given));
         }
 
         // Pick sentence type
@@ -321,10 +864,10 @@ impl NmeaParser {
             if let Some(i) = sentence.find(',') {
                 &sentence[0..i]
             } else {
-                return Err(ParseError::InvalidSentence(format!(
-                    "Invalid NMEA sentence: {}",
-                    sentence
-                )));
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidSentence,
+                    format!("No fields found: {}", sentence),
+                ));
             }
         };
 
@@ -333,27 +876,26 @@ impl NmeaParser {
             .chars()
             .all(|c| c.is_ascii_alphanumeric() || c == '$' || c == '!')
         {
-            return Err(ParseError::InvalidSentence(format!(
-                "Invalid characters in sentence type: {}",
-                sentence_type
-            )));
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidSentence,
+                format!("Invalid characters in sentence type: {}", sentence_type),
+            )
+            .with_field(0, "sentence_type")
+            .with_raw_value(sentence_type));
         }
 
         let (nav_system, station, sentence_type) = if sentence_type.starts_with('$') {
             // Identify GNSS system by talker ID.
-            let nav_system = gnss::NavigationSystem::from_str(
-                sentence_type
-                    .get(1..)
-                    .ok_or(ParseError::CorruptedSentence("Empty String".to_string()))?,
-            )?;
+            let nav_system = gnss::NavigationSystem::from_str(sentence_type.get(1..).ok_or(
+                ParseError::new(ParseErrorKind::BadTalker, "Empty talker identifier"),
+            )?)?;
             let sentence_type = if !sentence_type.starts_with('P') && sentence_type.len() == 6 {
                 format!(
                     "${}",
-                    sentence_type
-                        .get(3..6)
-                        .ok_or(ParseError::InvalidSentence(format!(
-                            "{sentence_type} is too short."
-                        )))?
+                    sentence_type.get(3..6).ok_or(ParseError::new(
+                        ParseErrorKind::InvalidSentence,
+                        format!("{sentence_type} is too short."),
+                    ))?
                 )
             } else {
                 String::from(sentence_type)
@@ -361,19 +903,16 @@ impl NmeaParser {
             (nav_system, ais::Station::Other, sentence_type)
         } else if sentence_type.starts_with('!') {
             // Identify AIS station
-            let station = ais::Station::from_str(
-                sentence_type
-                    .get(1..)
-                    .ok_or(ParseError::CorruptedSentence("Empty String".to_string()))?,
-            )?;
+            let station = ais::Station::from_str(sentence_type.get(1..).ok_or(
+                ParseError::new(ParseErrorKind::BadTalker, "Empty talker identifier"),
+            )?)?;
             let sentence_type = if sentence_type.len() == 6 {
                 format!(
                     "!{}",
-                    sentence_type
-                        .get(3..6)
-                        .ok_or(ParseError::InvalidSentence(format!(
-                            "{sentence_type} is too short."
-                        )))?
+                    sentence_type.get(3..6).ok_or(ParseError::new(
+                        ParseErrorKind::InvalidSentence,
+                        format!("{sentence_type} is too short."),
+                    ))?
                 )
             } else {
                 String::from(sentence_type)
@@ -387,43 +926,90 @@ impl NmeaParser {
             )
         };
 
+        if self.strict {
+            strict::check_field_count(&sentence_type, sentence.split(',').count())
+                .map_err(|e| e.with_sentence_type(&sentence_type[1..]))?;
+        }
+
+        // Handle the sentence. In lenient mode invalid fields are cleared one by one and the
+        // sentence is handled again until it succeeds or fails for another reason.
+        self.warnings.clear();
+        let mut sentence = sentence;
+        loop {
+            let result = self
+                .handle_sentence(&sentence, &sentence_type, nav_system, station, group_id)
+                .map_err(|e| e.with_sentence_type(&sentence_type[1..]));
+            match result {
+                Err(e) if self.lenient && sentence_type.starts_with('$') => {
+                    let num = match (e.kind(), e.location()) {
+                        (
+                            ParseErrorKind::InvalidField | ParseErrorKind::OutOfRange,
+                            Some(ErrorLocation::Field(num)),
+                        ) if num > 0 => num,
+                        _ => return Err(e),
+                    };
+                    let mut split: Vec<&str> = sentence.split(',').collect();
+                    match split.get_mut(num) {
+                        Some(field) if !field.is_empty() => *field = "",
+                        _ => return Err(e),
+                    }
+                    let cleared = split.join(",");
+                    debug!("Ignoring invalid field in lenient mode: {}", e);
+                    self.warnings.push(e);
+                    sentence = cleared;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Handle NMEA sentence without a checksum by its type (e.g. "$GGA" or "!VDM").
+    fn handle_sentence(
+        &mut self,
+        sentence: &str,
+        sentence_type: &str,
+        nav_system: gnss::NavigationSystem,
+        station: ais::Station,
+        group_id: Option<u64>,
+    ) -> Result<ParsedMessage, ParseError> {
         // Handle sentence types
-        match sentence_type.as_str() {
+        match sentence_type {
             // $xxGGA - Global Positioning System Fix Data
-            "$GGA" => gnss::gga::handle(sentence.as_str(), nav_system),
+            "$GGA" => gnss::gga::handle(sentence, nav_system, self.now),
             // $xxRMC - Recommended minimum specific GPS/Transit data
-            "$RMC" => gnss::rmc::handle(sentence.as_str(), nav_system),
+            "$RMC" => gnss::rmc::handle(sentence, nav_system, self.now),
             // $xxGNS - GNSS fix data
-            "$GNS" => gnss::gns::handle(sentence.as_str(), nav_system),
+            "$GNS" => gnss::gns::handle(sentence, nav_system, self.now),
             // $xxGSA - GPS DOP and active satellites
-            "$GSA" => gnss::gsa::handle(sentence.as_str(), nav_system),
+            "$GSA" => gnss::gsa::handle(sentence, nav_system),
             // $xxGSV - GPS Satellites in view
-            "$GSV" => gnss::gsv::handle(sentence.as_str(), nav_system, self),
+            "$GSV" => gnss::gsv::handle(sentence, nav_system, self),
             // $xxVTG - Track made good and ground speed
-            "$VTG" => gnss::vtg::handle(sentence.as_str(), nav_system),
+            "$VTG" => gnss::vtg::handle(sentence, nav_system),
             // $xxGLL - Geographic position, latitude / longitude
-            "$GLL" => gnss::gll::handle(sentence.as_str(), nav_system),
+            "$GLL" => gnss::gll::handle(sentence, nav_system, self.now),
             // $xxALM - Almanac Data
-            "$ALM" => gnss::alm::handle(sentence.as_str(), nav_system),
+            "$ALM" => gnss::alm::handle(sentence, nav_system),
             // $xxDTM - Datum reference
-            "$DTM" => gnss::dtm::handle(sentence.as_str(), nav_system),
+            "$DTM" => gnss::dtm::handle(sentence, nav_system),
             // $xxMSS - MSK receiver signal
-            "$MSS" => gnss::mss::handle(sentence.as_str(), nav_system),
+            "$MSS" => gnss::mss::handle(sentence, nav_system),
             // $xxSTN - Multiple Data ID
-            "$STN" => gnss::stn::handle(sentence.as_str(), nav_system),
+            "$STN" => gnss::stn::handle(sentence, nav_system),
             // $xxVBW - MSK Receiver Signal
-            "$VBW" => gnss::vbw::handle(sentence.as_str(), nav_system),
+            "$VBW" => gnss::vbw::handle(sentence, nav_system),
             // $xxZDA - Date and time
-            "$ZDA" => gnss::zda::handle(sentence.as_str(), nav_system),
+            "$ZDA" => gnss::zda::handle(sentence, nav_system),
 
             // Received AIS data from other or own vessel
             "!VDM" | "!VDO" => {
-                let own_vessel = sentence_type.as_str() == "!VDO";
+                let own_vessel = sentence_type == "!VDO";
                 let mut fragment_count = 0;
                 let mut fragment_number = 0;
                 let mut message_id = None;
                 let mut radio_channel_code = None;
                 let mut payload_string: String = "".into();
+                let mut fill_bits = "";
                 for (num, s) in sentence.split(',').enumerate() {
                     match num {
                         1 => {
@@ -432,10 +1018,12 @@ impl NmeaParser {
                                     fragment_count = i;
                                 }
                                 Err(_) => {
-                                    return Err(ParseError::InvalidSentence(format!(
-                                        "Failed to parse fragment count: {}",
-                                        s
-                                    )));
+                                    return Err(ParseError::new(
+                                        ParseErrorKind::InvalidField,
+                                        format!("Failed to parse fragment count: {}", s),
+                                    )
+                                    .with_field(1, "fragment_count")
+                                    .with_raw_value(s));
                                 }
                             };
                         }
@@ -445,10 +1033,12 @@ impl NmeaParser {
                                     fragment_number = i;
                                 }
                                 Err(_) => {
-                                    return Err(ParseError::InvalidSentence(format!(
-                                        "Failed to parse fragment count: {}",
-                                        s
-                                    )));
+                                    return Err(ParseError::new(
+                                        ParseErrorKind::InvalidField,
+                                        format!("Failed to parse fragment number: {}", s),
+                                    )
+                                    .with_field(2, "fragment_number")
+                                    .with_raw_value(s));
                                 }
                             };
                         }
@@ -463,74 +1053,74 @@ impl NmeaParser {
                             payload_string = s.to_string();
                         }
                         6 => {
-                            // fill bits
+                            fill_bits = s;
                         }
                         _ => {}
                     }
                 }
 
+                if self.strict {
+                    strict::check_fill_bits(fill_bits, fragment_number, fragment_count)?;
+                }
+
+                let message_id = message_id.or(group_id);
+                let fill_bits = fill_bits.parse::<usize>().unwrap_or(0);
+
                 // Try parse the payload
                 let mut bv: Option<BitVec> = None;
                 match fragment_count {
-                    1 => bv = parse_payload(&payload_string).ok(),
-                    2 => {
+                    0 => {
+                        warn!("Invalid NMEA sentence fragment count: 0");
+                    }
+                    1 => bv = parse_payload(&payload_string, fill_bits).ok(),
+                    _ => {
                         if let Some(msg_id) = message_id {
-                            let key1 = make_fragment_key(
-                                &sentence_type.to_string(),
-                                msg_id,
-                                fragment_count,
-                                1,
-                                radio_channel_code.unwrap_or(""),
-                            );
-                            let key2 = make_fragment_key(
-                                &sentence_type.to_string(),
-                                msg_id,
-                                fragment_count,
-                                2,
-                                radio_channel_code.unwrap_or(""),
-                            );
-                            match fragment_number {
-                                1 => {
-                                    if let Some(p) = self.pull_string(key2) {
-                                        let mut payload_string_combined = payload_string;
-                                        payload_string_combined.push_str(p.as_str());
-                                        bv = parse_payload(&payload_string_combined).ok();
-                                    } else {
-                                        self.push_string(key1, payload_string);
+                            if fragment_number >= 1 && fragment_number <= fragment_count {
+                                // Save the fragment and combine the payload when all of the
+                                // fragments have been received, regardless of their order
+                                let key = |num| {
+                                    make_fragment_key(
+                                        sentence_type,
+                                        msg_id,
+                                        fragment_count,
+                                        num,
+                                        radio_channel_code.unwrap_or(""),
+                                    )
+                                };
+                                self.push_fragment(key(fragment_number), payload_string, fill_bits);
+                                if (1..=fragment_count).all(|num| self.contains_key(key(num))) {
+                                    // Only the fill bits of the last fragment are meaningful
+                                    let mut payload_string_combined = String::new();
+                                    let mut fill_bits_combined = 0;
+                                    for num in 1..=fragment_count {
+                                        if let Some((p, f)) = self.pull_fragment(key(num)) {
+                                            payload_string_combined.push_str(p.as_str());
+                                            fill_bits_combined = f;
+                                        }
                                     }
+                                    bv =
+                                        parse_payload(&payload_string_combined, fill_bits_combined)
+                                            .ok();
                                 }
-                                2 => {
-                                    if let Some(p) = self.pull_string(key1) {
-                                        let mut payload_string_combined = p;
-                                        payload_string_combined.push_str(payload_string.as_str());
-                                        bv = parse_payload(&payload_string_combined).ok();
-                                    } else {
-                                        self.push_string(key2, payload_string);
-                                    }
-                                }
-                                _ => {
-                                    warn!(
-                                        "Unexpected NMEA fragment number: {}/{}",
-                                        fragment_number, fragment_count
-                                    );
-                                }
+                            } else {
+                                warn!(
+                                    "Unexpected NMEA fragment number: {}/{}",
+                                    fragment_number, fragment_count
+                                );
                             }
                         } else {
                             warn!(
-                                "NMEA message_id missing from {} than supported 2",
+                                "NMEA message_id missing from multi-fragment {}",
                                 sentence_type
                             );
                         }
                     }
-                    _ => {
-                        warn!(
-                            "NMEA sentence fragment count greater ({}) than supported 2",
-                            fragment_count
-                        );
-                    }
                 }
 
                 if let Some(bv) = bv {
+                    if self.strict {
+                        strict::check_ais_ranges(&bv)?;
+                    }
                     let message_type = pick_u64(&bv, 0, 6);
                     match message_type {
                         // Position report with SOTDMA/ITDMA
@@ -538,25 +1128,13 @@ impl NmeaParser {
                         // Base station report
                         4 => ais::vdm_t4::handle(&bv, station, own_vessel),
                         // Ship static voyage related data
-                        5 => ais::vdm_t5::handle(&bv, station, own_vessel),
+                        5 => ais::vdm_t5::handle(&bv, station, own_vessel, self.now),
                         // Addressed binary message
-                        6 => ais::vdm_t6::handle(&bv, station, own_vessel),
+                        6 => ais::vdm_t6::handle(&bv, station, own_vessel, self.now),
                         // Binary acknowledge
-                        7 => {
-                            // TODO: implementation
-                            Err(ParseError::UnsupportedSentenceType(format!(
-                                "Unsupported {} message type: {}",
-                                sentence_type, message_type
-                            )))
-                        }
+                        7 => ais::vdm_t7::handle(&bv, station, own_vessel),
                         // Binary broadcast message
-                        8 => {
-                            // TODO: implementation
-                            Err(ParseError::UnsupportedSentenceType(format!(
-                                "Unsupported {} message type: {}",
-                                sentence_type, message_type
-                            )))
-                        }
+                        8 => ais::vdm_t8::handle(&bv, station, own_vessel, self.now),
                         // Standard SAR aircraft position report
                         9 => ais::vdm_t9::handle(&bv, station, own_vessel),
                         // UTC and Date inquiry
@@ -595,25 +1173,37 @@ impl NmeaParser {
                         26 => ais::vdm_t26::handle(&bv, station, own_vessel),
                         // Long range AIS broadcast message
                         27 => ais::vdm_t27::handle(&bv, station, own_vessel),
-                        _ => Err(ParseError::UnsupportedSentenceType(format!(
-                            "Unsupported {} message type: {}",
-                            sentence_type, message_type
-                        ))),
+                        _ => Err(ParseError::new(
+                            ParseErrorKind::UnsupportedMessageType,
+                            format!(
+                                "Unsupported {} message type: {}",
+                                sentence_type, message_type
+                            ),
+                        )
+                        .with_bit(0, "message_type")
+                        .with_raw_value(&message_type.to_string())),
                     }
                 } else {
                     Ok(ParsedMessage::Incomplete)
                 }
             }
-            "$DPT" => gnss::dpt::handle(sentence.as_str()),
-            "$DBS" => gnss::dbs::handle(sentence.as_str()),
-            "$MTW" => gnss::mtw::handle(sentence.as_str()),
-            "$VHW" => gnss::vhw::handle(sentence.as_str()),
-            "$HDT" => gnss::hdt::handle(sentence.as_str()),
-            "$MWV" => gnss::mwv::handle(sentence.as_str()),
-            _ => Err(ParseError::UnsupportedSentenceType(format!(
-                "Unsupported sentence type: {}",
-                sentence_type
-            ))),
+            "$DPT" => gnss::dpt::handle(sentence),
+            "$DBS" => gnss::dbs::handle(sentence),
+            "$MTW" => gnss::mtw::handle(sentence),
+            "$VHW" => gnss::vhw::handle(sentence),
+            "$HDT" => gnss::hdt::handle(sentence),
+            "$MWV" => gnss::mwv::handle(sentence),
+            "$GST" => gnss::gst::handle(sentence, nav_system, self.now),
+            "$GBS" => gnss::gbs::handle(sentence, nav_system, self.now),
+            "$RMB" => gnss::rmb::handle(sentence, nav_system),
+            "$APB" => gnss::apb::handle(sentence, nav_system),
+            "$XTE" => gnss::xte::handle(sentence, nav_system),
+            "$BOD" => gnss::bod::handle(sentence, nav_system),
+            "$BWC" | "$BWR" => gnss::bwc::handle(sentence, nav_system, self.now),
+            _ => Err(ParseError::new(
+                ParseErrorKind::UnsupportedSentenceType,
+                format!("Unsupported sentence type: {}", sentence_type),
+            )),
         }
     }
 }
@@ -626,27 +1216,32 @@ mod test {
         let mut p = NmeaParser::new();
         assert_eq!(
             p.parse_sentence("$޴GAGSV,,"),
-            Err(ParseError::InvalidSentence(
-                "Invalid characters in sentence type: $\u{7b4}GAGSV".to_string()
-            ))
+            Err(ParseError::new(
+                ParseErrorKind::InvalidSentence,
+                "Invalid characters in sentence type: $\u{7b4}GAGSV"
+            )
+            .with_field(0, "sentence_type")
+            .with_raw_value("$\u{7b4}GAGSV"))
         );
         assert_eq!(
             p.parse_sentence("$WIMWV,295.4,T,"),
-            Err(ParseError::CorruptedSentence(
-                "pick string for \"wind_speed_knots\" was None".to_string()
-            ))
+            Err(
+                ParseError::new(ParseErrorKind::MissingField, "Wind speed unit missing")
+                    .with_sentence_type("MWV")
+                    .with_field(4, "wind_speed_unit")
+            )
         );
         assert_eq!(
             p.parse_sentence("!AIVDM,not,a,valid,nmea,string,0*00"),
-            Err(ParseError::CorruptedSentence(
-                "Corrupted NMEA sentence: \"17\" != \"00\"".to_string()
-            ))
+            Err(ParseError::new(
+                ParseErrorKind::BadChecksum,
+                "Checksum mismatch: \"17\" != \"00\""
+            )
+            .with_raw_value("00"))
         );
         assert_eq!(
-            p.parse_sentence("!"),
-            Err(ParseError::InvalidSentence(
-                "Invalid NMEA sentence: !".to_string()
-            ))
+            p.parse_sentence("!").map_err(|e| e.kind()),
+            Err(ParseErrorKind::InvalidSentence)
         );
     }
     #[test]
@@ -685,9 +1280,12 @@ mod test {
         let mut p = NmeaParser::new();
         assert_eq!(
             p.parse_sentence("!AIVDM,1,1,,B,4028iqT47wP00wGiNbH8H0700`2H,0*13"),
-            Err(ParseError::InvalidSentence(String::from(
+            Err(ParseError::new(
+                ParseErrorKind::OutOfRange,
                 "Failed to parse Utc Date from y:4161 m:15 d:31 h:0 m:0 s:0"
-            )))
+            )
+            .with_sentence_type("VDM")
+            .with_bit(38, "timestamp"))
         );
     }
 
@@ -698,42 +1296,530 @@ mod test {
                 let mut p = NmeaParser::new();
                 assert_eq!(
                     p.parse_sentence("$PGRME,15.0,M,45.0,M,25.0,M*1C"),
-                    Err(ParseError::UnsupportedSentenceType(String::from(
+                    Err(ParseError::new(
+                        ParseErrorKind::UnsupportedSentenceType,
                         "Unsupported sentence type: $PGRME"
-                    )))
+                    )
+                    .with_sentence_type("PGRME"))
                 );
                 // Try a proprietary sentence with four characters
                 assert_eq!(
                     p.parse_sentence("$PGRM,00,1,,,*15"),
-                    Err(ParseError::UnsupportedSentenceType(String::from(
+                    Err(ParseError::new(
+                        ParseErrorKind::UnsupportedSentenceType,
                         "Unsupported sentence type: $PGRM"
-                    )))
+                    )
+                    .with_sentence_type("PGRM"))
                 );
         */
     }
 
+    #[test]
+    fn test_parse_error_location() {
+        let mut p = NmeaParser::new();
+        let e = p
+            .parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,5x5.4,M,46.9,M,,")
+            .unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::InvalidField);
+        assert_eq!(e.sentence_type(), Some("GGA"));
+        assert_eq!(e.location(), Some(ErrorLocation::Field(9)));
+        assert_eq!(e.field_name(), Some("altitude"));
+        assert_eq!(e.raw_value(), Some("5x5.4"));
+        assert_eq!(
+            e.to_string(),
+            "Invalid field in GGA at field 9 (altitude): Failed to parse number: 5x5.4"
+        );
+
+        let e = p
+            .parse_sentence("$GPRMC,225446,A,49x6.45,N,12311.12,W,000.5,054.7,191194,020.3,E")
+            .unwrap_err();
+        assert_eq!(e.location(), Some(ErrorLocation::Field(3)));
+        assert_eq!(e.field_name(), Some("latitude"));
+
+        let e = p
+            .parse_sentence("$GPRMC,225446,X,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E")
+            .unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::InvalidField);
+        assert_eq!(e.location(), Some(ErrorLocation::Field(2)));
+        assert_eq!(e.field_name(), Some("status_active"));
+        assert_eq!(e.raw_value(), Some("X"));
+
+        let e = p
+            .parse_sentence("$GPGSA,X,3,19,28,14,18,27,22,31,39,,,,,1.7,1.0,1.3")
+            .unwrap_err();
+        assert_eq!(e.location(), Some(ErrorLocation::Field(1)));
+        assert_eq!(e.field_name(), Some("mode1_automatic"));
+
+        let e = p.parse_sentence("!AIVDM,1,1,,A,w0000000000,0").unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::UnsupportedMessageType);
+        assert_eq!(e.sentence_type(), Some("VDM"));
+        assert_eq!(e.location(), Some(ErrorLocation::Bit(0)));
+        assert_eq!(e.raw_value(), Some("63"));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let sentence = "$GPGGA,123519,4807.038,N,01131.000,E,1,0x,0.9,5x5.4,M,46.9,M,,*4B";
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence_with_warnings(sentence)
+                .map_err(|e| e.kind()),
+            Err(ParseErrorKind::InvalidField)
+        );
+
+        p.set_lenient(true);
+        match p.parse_sentence_with_warnings(sentence) {
+            Ok((ParsedMessage::Gga(gga), warnings)) => {
+                assert::close(gga.latitude.unwrap_or(0.0), 48.117, 0.001);
+                assert::close(gga.longitude.unwrap_or(0.0), 11.517, 0.001);
+                assert_eq!(gga.satellite_count, None);
+                assert_eq!(gga.altitude, None);
+                assert_eq!(gga.hdop, Some(0.9));
+                assert_eq!(warnings.len(), 2);
+                assert_eq!(warnings[0].field_name(), Some("satellite_count"));
+                assert_eq!(warnings[0].raw_value(), Some("0x"));
+                assert_eq!(warnings[1].sentence_type(), Some("GGA"));
+                assert_eq!(warnings[1].location(), Some(ErrorLocation::Field(9)));
+                assert_eq!(warnings[1].raw_value(), Some("5x5.4"));
+            }
+            Ok((ps, _)) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Valid sentence gives no warnings
+        match p.parse_sentence_with_warnings(
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+        ) {
+            Ok((ParsedMessage::Gga(gga), warnings)) => {
+                assert_eq!(gga.altitude, Some(545.4));
+                assert!(warnings.is_empty());
+            }
+            Ok((ps, _)) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Invalid status of RMC is ignored
+        match p.parse_sentence_with_warnings(
+            "$GPRMC,225446,X,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E",
+        ) {
+            Ok((ParsedMessage::Rmc(rmc), warnings)) => {
+                assert_eq!(rmc.status_active, None);
+                assert_eq!(warnings.len(), 1);
+                assert_eq!(warnings[0].field_name(), Some("status_active"));
+            }
+            Ok((ps, _)) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Missing mandatory fields still fail
+        assert_eq!(
+            p.parse_sentence_with_warnings("$WIMWV,295.4,T,")
+                .map_err(|e| e.kind()),
+            Err(ParseErrorKind::MissingField)
+        );
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let rule = |result: Result<ParsedMessage, ParseError>| match result.map_err(|e| e.kind()) {
+            Err(ParseErrorKind::StrictViolation(rule)) => Some(rule),
+            _ => None,
+        };
+        let mut p = NmeaParser::new();
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,A,38Id705000rRVJhE7cl9n;160000,0")
+            .is_ok());
+
+        p.set_strict(true);
+        assert!(p
+            .parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47")
+            .is_ok());
+        assert!(p
+            .parse_sentence("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C")
+            .is_ok());
+        assert_eq!(
+            rule(p.parse_sentence("!AIVDM,1,1,,A,38Id705000rRVJhE7cl9n;160000,0")),
+            Some(StrictRule::MissingChecksum)
+        );
+        assert_eq!(
+            rule(
+                p.parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M*47")
+            ),
+            Some(StrictRule::FieldCount)
+        );
+        assert_eq!(
+            rule(p.parse_sentence("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,7*5B")),
+            Some(StrictRule::FillBits)
+        );
+        assert_eq!(
+            rule(p.parse_sentence(
+                "$GPGGA,123519.00000000,4807.03800000,N,01131.00000000,E,1,08,0.9,545.4,M,46.9,M,,*47"
+            )),
+            Some(StrictRule::SentenceLength)
+        );
+    }
+
     #[test]
     fn test_parse_invalid_talker() {
         // Try parse malformed sentences
         let mut p = NmeaParser::new();
         assert_eq!(
             p.parse_sentence("$QQ,*2C"),
-            Err(ParseError::UnsupportedSentenceType(String::from(
+            Err(ParseError::new(
+                ParseErrorKind::UnsupportedSentenceType,
                 "Unsupported sentence type: $QQ"
-            )))
+            )
+            .with_sentence_type("QQ"))
         );
         assert_eq!(
             p.parse_sentence("$A,a0,*10"),
-            Err(ParseError::InvalidSentence(String::from(
-                "Invalid talker identifier"
-            )))
+            Err(
+                ParseError::new(ParseErrorKind::BadTalker, "Invalid talker identifier")
+                    .with_raw_value("A")
+            )
+        );
+        assert_eq!(
+            p.parse_sentence("$,0a,*51").map_err(|e| e.kind()),
+            Err(ParseErrorKind::BadTalker)
+        );
+    }
+
+    #[test]
+    fn test_parse_multi_fragment() {
+        let fragments = [
+            "!AIVDM,3,1,7,B,802<HH@0EP<AfAP00;@0eUv1f2v40000,0*53",
+            "!AIVDM,3,2,7,B,0L0055`1E`01J0035`OBl00e00;@00aH,0*60",
+            "!AIVDM,3,3,7,B,`b41rbT3Aqhd5>?BD8000000000,0*64",
+        ];
+
+        // Try every arrival order of the three fragments
+        for order in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            let mut p = NmeaParser::new();
+            assert_eq!(
+                p.parse_sentence(fragments[order[0]]),
+                Ok(ParsedMessage::Incomplete)
+            );
+            assert_eq!(
+                p.parse_sentence(fragments[order[1]]),
+                Ok(ParsedMessage::Incomplete)
+            );
+            match p.parse_sentence(fragments[order[2]]) {
+                Ok(ParsedMessage::BinaryBroadcastMessage(bbm)) => {
+                    assert_eq!(bbm.mmsi, 2300001);
+                    assert_eq!(bbm.fid, 22);
+                    assert_eq!(bbm.data.len(), 546 - 56);
+                }
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+            assert_eq!(p.strings_count(), 0);
+        }
+
+        // Fragments of a different message ID are not mixed
+        let mut p = NmeaParser::new();
+        assert_eq!(
+            p.parse_sentence(fragments[0]),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(
+            p.parse_sentence("!AIVDM,3,2,8,B,0L0055`1E`01J0035`OBl00e00;@00aH,0*6F"),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(
+            p.parse_sentence(fragments[2]),
+            Ok(ParsedMessage::Incomplete)
+        );
+        assert_eq!(p.strings_count(), 3);
+    }
+
+    #[test]
+    fn test_fragment_limits() {
+        let fragments = [
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+            "!AIVDM,2,1,2,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1F",
+            "!AIVDM,2,1,3,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1E",
+        ];
+
+        // Eviction of the oldest fragment
+        let mut p = NmeaParser::new();
+        p.set_max_fragments(2);
+        for f in fragments.iter() {
+            assert_eq!(p.parse_sentence(f), Ok(ParsedMessage::Incomplete));
+        }
+        assert_eq!(p.pending_fragments(), 2);
+        assert_eq!(p.orphaned_fragments().len(), 1);
+        assert_eq!(p.orphaned_fragments()[0].key, "!VDM,2,1,1,A");
+        assert_eq!(p.orphaned_fragments()[0].reason, OrphanReason::Evicted);
+        assert_eq!(p.orphaned_fragments()[0].received, None);
+        assert_eq!(p.drain_orphaned_fragments().len(), 1);
+        assert!(p.orphaned_fragments().is_empty());
+
+        // Expiry by age
+        let mut p = NmeaParser::new();
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        p.set_time(t0);
+        assert_eq!(
+            p.parse_sentence(fragments[0]),
+            Ok(ParsedMessage::Incomplete)
+        );
+        p.set_time(t0 + Duration::seconds(30));
+        assert_eq!(
+            p.parse_sentence(fragments[1]),
+            Ok(ParsedMessage::Incomplete)
+        );
+        p.set_time(t0 + Duration::seconds(61));
+        assert_eq!(p.pending_fragments(), 1);
+        assert_eq!(
+            p.orphaned_fragments(),
+            &[OrphanedFragment {
+                key: "!VDM,2,1,1,A".into(),
+                data: "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8".into(),
+                received: Some(t0),
+                reason: OrphanReason::Expired,
+            }]
+        );
+        p.set_max_fragment_age(None);
+        p.set_time(t0 + Duration::days(1));
+        assert_eq!(p.pending_fragments(), 1);
+
+        // Type 24 parts
+        let mut p = NmeaParser::new();
+        p.set_time(t0);
+        assert_eq!(
+            p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D"),
+            Ok(ParsedMessage::Incomplete)
         );
+        assert_eq!(p.pending_vsds(), 1);
+        p.set_max_vsds(0);
+        assert_eq!(p.pending_vsds(), 0);
+        p.set_max_vsds(DEFAULT_MAX_VSDS);
         assert_eq!(
-            p.parse_sentence("$,0a,*51"),
-            Err(ParseError::InvalidSentence(String::from(
-                "Invalid talker identifier"
-            )))
+            p.parse_sentence("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D"),
+            Ok(ParsedMessage::Incomplete)
         );
+        p.set_time(t0 + Duration::minutes(16));
+        assert_eq!(p.pending_vsds(), 0);
+    }
+
+    #[test]
+    fn test_reference_time() {
+        // Without reference time the year 2000 is assumed
+        let mut p = NmeaParser::new();
+        match p.parse_sentence("$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*68")
+        {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(rmc.timestamp.map(|t| t.year()), Some(2094));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Century of RMC date is resolved relative to the reference time
+        p.set_time(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap());
+        match p.parse_sentence("$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*68")
+        {
+            Ok(ParsedMessage::Rmc(rmc)) => {
+                assert_eq!(
+                    rmc.timestamp,
+                    Utc.with_ymd_and_hms(1994, 11, 19, 22, 54, 46).single()
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // GGA time just before midnight belongs to the previous day
+        match p.parse_sentence("$GPGGA,235959,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*4B")
+        {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(
+                    gga.timestamp,
+                    Utc.with_ymd_and_hms(2024, 5, 31, 23, 59, 59).single()
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // AIS ETA year is resolved relative to the clock
+        p.set_clock(Some(Box::new(|| {
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+        })));
+        p.parse_sentence(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        )
+        .ok();
+        match p.parse_sentence("!AIVDM,2,2,1,A,88888888880,2*25") {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => {
+                assert_eq!(
+                    vsd.eta,
+                    Utc.with_ymd_and_hms(2024, 5, 15, 14, 0, 30).single()
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_stateful_clock() {
+        // Clock advancing one day each time it is read
+        let mut t = Utc.with_ymd_and_hms(2024, 5, 30, 12, 0, 0).unwrap();
+        let mut p = NmeaParser::new();
+        p.set_clock(Some(Box::new(move || {
+            t += Duration::days(1);
+            t
+        })));
+        let gga = "$GPGGA,120000,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*49";
+        match p.parse_sentence(gga) {
+            Ok(ParsedMessage::Gga(gga)) => {
+                assert_eq!(
+                    gga.timestamp,
+                    Utc.with_ymd_and_hms(2024, 5, 31, 12, 0, 0).single()
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // A cloned parser continues from the state of the clock
+        let mut p2 = p.clone();
+        for p in [&mut p, &mut p2] {
+            match p.parse_sentence(gga) {
+                Ok(ParsedMessage::Gga(gga)) => {
+                    assert_eq!(
+                        gga.timestamp,
+                        Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).single()
+                    );
+                }
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_sentence_with_tag_block() {
+        let mut p = NmeaParser::new();
+
+        // Single sentence
+        match p.parse_sentence_with_tag_block(
+            "\\s:station1,c:1577836800*76\\!AIVDM,1,1,,A,15MgK45P3@G?fl0E`JbR0OwT0@MS,0*4E",
+        ) {
+            Ok((Some(tag_block), ParsedMessage::VesselDynamicData(vdd))) => {
+                assert_eq!(tag_block.source, Some("station1".into()));
+                assert_eq!(
+                    tag_block.time,
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).single()
+                );
+                assert_eq!(vdd.mmsi, 366730000);
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+
+        // Sentence group without AIS message IDs
+        match p.parse_sentence_with_tag_block(
+            "\\g:1-2-1234,s:rcv1,c:1577836800*1D\\!AIVDM,2,1,,A,A02VqLPA4I6C07h5Ed1h<OrsuBTTwS?r:C?w`?la<gno1RTRwSP9:BcurA8a,0*0F",
+        ) {
+            Ok((Some(tag_block), ParsedMessage::Incomplete)) => {
+                assert_eq!(tag_block.source, Some("rcv1".into()));
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        match p
+            .parse_sentence_with_tag_block("\\g:2-2-1234*59\\!AIVDM,2,2,,A,:Oko02TSwu8<:Jbb,0*24")
+        {
+            Ok((Some(tag_block), ParsedMessage::DgnssBroadcastBinaryMessage(_))) => {
+                assert_eq!(tag_block.source, Some("rcv1".into()));
+                assert_eq!(
+                    tag_block.time,
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).single()
+                );
+                assert_eq!(
+                    tag_block.group,
+                    Some(TagGroup {
+                        sentence_number: 2,
+                        sentence_count: 2,
+                        group_id: 1234,
+                    })
+                );
+            }
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        assert_eq!(p.strings_count(), 0);
+
+        // Sentences failing to parse don't count towards the group
+        assert_eq!(
+            p.parse_sentence_with_tag_block("\\g:1-2-5678\\$IIHDT,15.0,T*00")
+                .map_err(|e| e.kind()),
+            Err(ParseErrorKind::BadChecksum)
+        );
+        assert_eq!(p.saved_tag_blocks.len(), 0);
+        assert!(p
+            .parse_sentence_with_tag_block("\\g:2-2-5678\\$IIHDT,15.0,T*16")
+            .is_ok());
+        assert_eq!(p.saved_tag_blocks.len(), 1);
+        assert!(p
+            .parse_sentence_with_tag_block("\\g:1-2-5678\\$IIHDT,15.0,T*16")
+            .is_ok());
+        assert_eq!(p.saved_tag_blocks.len(), 0);
+
+        // Corrupted TAG block
+        assert_eq!(
+            p.parse_sentence(
+                "\\s:station1,c:1577836800*00\\!AIVDM,1,1,,A,15MgK45P3@G?fl0E`JbR0OwT0@MS,0*4E"
+            )
+            .map_err(|e| e.kind()),
+            Err(ParseErrorKind::BadChecksum)
+        );
+    }
+
+    #[test]
+    fn test_push_bytes() {
+        let mut p = NmeaParser::new();
+        let data = b"!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A\r\n$IIHDT,15.0,T*16\r$INMTW,17.9,C*1B";
+        let mut results = Vec::new();
+        for chunk in data.chunks(7) {
+            results.extend(p.push_bytes(chunk));
+        }
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            results[0],
+            Ok(ParsedMessage::VesselDynamicData(_))
+        ));
+        assert!(matches!(results[1], Ok(ParsedMessage::Hdt(_))));
+
+        // The last sentence is completed by the line terminator
+        let results = p.push_bytes(b"\n");
+        assert!(matches!(results[..], [Ok(ParsedMessage::Mtw(_))]));
+
+        // Overlong lines are discarded as a whole
+        let mut data = vec![b'$'; MAX_LINE_LENGTH + 1];
+        data.extend_from_slice(b"$IIHDT,15.0,T*16\n$IIHDT,15.0,T*16\n");
+        let results = p.push_bytes(&data);
+        assert!(matches!(results[..], [Ok(ParsedMessage::Hdt(_))]));
+
+        // Non-ASCII bytes in the checksum are rejected
+        for data in [
+            &b"$IIHDT,15.0,T*\xff\n"[..],
+            &b"$IIHDT,15.0,T*1\xc3\xa9\n"[..],
+        ] {
+            let results = p.push_bytes(data);
+            assert!(matches!(
+                &results[..],
+                [Err(e)] if e.kind() == ParseErrorKind::InvalidSentence
+            ));
+        }
     }
 
     #[test]
@@ -743,11 +1829,11 @@ mod test {
         // String test
         p.push_string("a".into(), "b".into());
         assert_eq!(p.strings_count(), 1);
-        p.push_string("c".into(), "d".into());
+        p.push_fragment("c".into(), "d".into(), 2);
         assert_eq!(p.strings_count(), 2);
-        p.pull_string("a".into());
+        assert_eq!(p.pull_string("a".into()), Some("b".into()));
         assert_eq!(p.strings_count(), 1);
-        p.pull_string("c".into());
+        assert_eq!(p.pull_fragment("c".into()), Some(("d".into(), 2)));
         assert_eq!(p.strings_count(), 0);
 
         // VesselStaticData test
@@ -761,6 +1847,92 @@ mod test {
         assert_eq!(p.vsds_count(), 0);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut p = NmeaParser::new();
+        let sentences = vec![
+            "!AIVDM,1,1,,A,15RTgt0PAso;90TKcjM8h6g208CQ,0*4A",
+            "!AIVDM,1,1,,A,403OviQuMGCqWrRO9>E6fE700@GO,0*4D",
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+            "!AIVDM,2,2,1,A,88888888880,2*25",
+            "!AIVDM,1,1,,A,63KMWfTr=1l005h2@D0Htwww269cD28;lP,0*55",
+            "!AIVDM,1,1,,A,702R5`hwCjq8,0*6B",
+            "!AIVDM,1,1,,B,802<HH@0Ghee01f8m5sKAQ9hfKseGhVdtR3F2cCwe7hhe:3iVAwwnQ0l9400,0*3C",
+            "!AIVDM,1,1,,A,802<HH@0EP<AfAP00;@0eUv1f2v400000L0055`1E`01J0035`OBl00e00;@00aH`b41rbT3Aqhd5>?BD8000000000,0*60",
+            "!AIVDM,1,1,,A,839ed50j2d<dtL==MR9Pq?ci8hp0,0*6E",
+            "!AIVDM,1,1,,B,91b55wi;hbOS@OdQAC062Ch2089h,0*30",
+            "!AIVDM,1,1,,B,:5MlU41GMK6@,0*6C",
+            "!AIVDM,1,1,,B,;4R33:1uUK2F`q?mOt@@GoQ00000,0*5D",
+            "!AIVDM,1,1,,A,<02:oP0kKcv0@<51C5PB5@?BDPD?P:?2?EB7PDB16693P381>>5<PikP,0*37",
+            "!AIVDM,1,1,,A,=39UOj0jFs9R,0*65",
+            "!AIVDM,1,1,,A,>5?Per18=HB1U:1@E=B0m<L,2*51",
+            "!AIVDM,1,1,,B,?h3Ovn1GP<K0<P@59a0,2*04",
+            "!AIVDM,1,1,,A,@01uEO@mMk7P<P00,0*18",
+            "!AIVDM,2,1,5,A,A02VqLPA4I6C07h5Ed1h<OrsuBTTwS?r:C?w`?la<gno1RTRwSP9:BcurA8a,0*3A",
+            "!AIVDM,2,2,5,A,:Oko02TSwu8<:Jbb,0*11",
+            "!AIVDM,1,1,,A,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*4C",
+            "!AIVDM,1,1,,B,C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220,0*0B",
+            "!AIVDM,1,1,,A,Dh3OvjB8IN>4,0*1D",
+            "!AIVDM,2,1,5,B,E1mg=5J1T4W0h97aRh6ba84<h2d;W:Te=eLvH50```q,0*46",
+            "!AIVDM,2,2,5,B,:D44QDlp0C1DU00,2*36",
+            "!AIVDM,1,1,,A,F030ot22N2P6aoQbhe4736L20000,0*1A",
+            "!AIVDM,1,1,,B,G02:Kn01R`sn@291nj600000900,2*12",
+            "!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D",
+            "!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40",
+            "!AIVDM,1,1,,A,I6SWo?8P00a3PKpEKEVj0?vNP<65,0*73",
+            "!AIVDM,1,1,,A,JB3R0GO7p>vQL8tjw0b5hqpd0706kh9d3lR2vbl0400,2*40",
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            "$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191120,020.3,E*67",
+            "$GNGNS,090310.00,4806.891632,N,01134.134167,E,AAN,10,1.0,532.4,47.0,,,V*68",
+            "$GPGSA,A,3,19,28,14,18,27,22,31,39,,,,,1.7,1.0,1.3*34",
+            "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74",
+            "$GPGSV,3,2,11,14,25,170,00,16,57,208,39,18,67,296,40,19,40,246,00*74",
+            "$GPGSV,3,3,11,22,42,067,42,24,14,311,43,27,05,244,00,,,,*4D",
+            "$BDVTG,054.7,T,034.4,M,005.5,N,010.2,K,D*31",
+            "$GAGLL,4916.45,N,12311.12,W,225444,A,D*48",
+            "$GPALM,1,1,02,0217,00,50F6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,148,001*0A",
+            "$GPDTM,999,,0.002,S,0.005,E,005.8,W84*1A",
+            "$GPMSS,55,27,318.0,100,1*57",
+            "$GPSTN,23",
+            "$GPVBW,2.0,1.5,A,2.1,1.6,X",
+            "$GPZDA,072914.00,31,05,2018,-03,00",
+            "$SDDPT,17.5,0.3*67",
+            "$SDDBS,16.9,f,5.2,M,2.8,F*32",
+            "$INMTW,17.9,C*1B",
+            "$IIVHW,15.0,T,15.0,M,6.3,N,11.8,K*68",
+            "$IIHDT,15.0,T*16",
+            "$WIMWV,295.4,T,33.3,N,A*1C",
+            "$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A",
+            "$GNGBS,235503.00,1.6,1.4,3.2,03,,-21.4,3.8,1,0*4D",
+            "$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20",
+            "$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C",
+            "$GPXTE,A,A,0.67,L,N*6F",
+            "$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45",
+            "$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21",
+            "$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM,A*5D",
+        ];
+        let mut types = Vec::new();
+        for sentence in sentences {
+            let msg = match p.parse_sentence(sentence) {
+                Ok(ParsedMessage::Incomplete) => continue,
+                Ok(msg) => msg,
+                Err(e) => panic!("Unexpected error: {}", e),
+            };
+            let json = serde_json::to_value(&msg).unwrap();
+            if let ParsedMessage::Gsv(_) = msg {
+                assert_eq!(json["satellites"].as_array().unwrap().len(), 11);
+            }
+            types.push(json["type"].as_str().unwrap().to_string());
+            let msg2: ParsedMessage = serde_json::from_value(json).unwrap();
+            assert_eq!(msg2, msg);
+        }
+        // Every variant except `Incomplete` is covered
+        types.sort();
+        types.dedup();
+        assert_eq!(types.len(), 49);
+    }
+
     #[test]
     fn test_country() {
         assert_eq!(vsd(230992580).country().unwrap(), "FI");
@@ -0,0 +1,153 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Streaming NMEA reader over `std::io::BufRead` sources
+
+use super::*;
+use alloc::collections::VecDeque;
+use std::io::{BufRead, ErrorKind};
+
+// -------------------------------------------------------------------------------------------------
+
+/// Iterator over the messages read from a `BufRead` source such as a file, a TCP stream or a
+/// serial port. Lines are framed and parsed with `NmeaParser::push_bytes`, so the same rules
+/// apply: CR, LF and CRLF terminators are accepted, garbage between sentences is skipped and
+/// `Incomplete` results are dropped. A read error is returned as the last item.
+pub struct NmeaReader<R> {
+    reader: R,
+    parser: NmeaParser,
+    results: VecDeque<Result<ParsedMessage, ParseError>>,
+    finished: bool,
+}
+
+impl<R: BufRead> NmeaReader<R> {
+    /// Construct a reader with a new `NmeaParser`.
+    pub fn new(reader: R) -> NmeaReader<R> {
+        NmeaReader::with_parser(reader, NmeaParser::new())
+    }
+
+    /// Construct a reader using the given, possibly pre-configured, parser.
+    pub fn with_parser(reader: R, parser: NmeaParser) -> NmeaReader<R> {
+        NmeaReader {
+            reader,
+            parser,
+            results: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Return reference to the parser.
+    pub fn parser(&self) -> &NmeaParser {
+        &self.parser
+    }
+
+    /// Return mutable reference to the parser, e.g. for setting the current time.
+    pub fn parser_mut(&mut self) -> &mut NmeaParser {
+        &mut self.parser
+    }
+
+    /// Consume the reader and return the underlying reader and the parser.
+    pub fn into_inner(self) -> (R, NmeaParser) {
+        (self.reader, self.parser)
+    }
+}
+
+impl<R: BufRead> Iterator for NmeaReader<R> {
+    type Item = Result<ParsedMessage, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.results.pop_front() {
+                return Some(result);
+            }
+            if self.finished {
+                return None;
+            }
+            let consumed = match self.reader.fill_buf() {
+                Ok([]) => {
+                    // Terminate the last line in case it lacks a line terminator
+                    self.finished = true;
+                    self.results.extend(self.parser.push_bytes(b"\n"));
+                    0
+                }
+                Ok(buf) => {
+                    self.results.extend(self.parser.push_bytes(buf));
+                    buf.len()
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => 0,
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(ParseError::new(
+                        ParseErrorKind::Io,
+                        format!("Read error: {}", e),
+                    )));
+                }
+            };
+            self.reader.consume(consumed);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_nmea_reader() {
+        let data: &[u8] = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+            \xff\xfegarbage\n\
+            !AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C\r\
+            !AIVDM,2,2,1,A,88888888880,2*25\n\
+            \r\n\
+            xx$IIHDT,15.0,T*17\n\
+            \\garbage $IIHDT,15.0,T*16\n\
+            $IIHDT,15.0,T*16";
+        let results: Vec<_> = NmeaReader::new(Cursor::new(data)).collect();
+        assert_eq!(results.len(), 5);
+        match &results[0] {
+            Ok(ParsedMessage::Gga(gga)) => assert_eq!(gga.satellite_count, Some(8)),
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        match &results[1] {
+            Ok(ParsedMessage::VesselStaticData(vsd)) => assert_eq!(vsd.mmsi, 351759000),
+            Ok(ps) => panic!("Unexpected message: {:?}", ps),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+        assert!(matches!(&results[2], Err(e) if e.kind() == ParseErrorKind::BadChecksum));
+        for result in &results[3..] {
+            match result {
+                Ok(ParsedMessage::Hdt(hdt)) => assert_eq!(hdt.heading_true, Some(15.0)),
+                Ok(ps) => panic!("Unexpected message: {:?}", ps),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_nmea_reader_small_buffer() {
+        let data: &[u8] = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\n\
+            \xc3\x28$IIHDT,15.0,T*16\n";
+        let reader = std::io::BufReader::with_capacity(3, Cursor::new(data));
+        let results: Vec<_> = NmeaReader::new(reader).collect();
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Ok(ParsedMessage::Gga(_))));
+        assert!(matches!(results[1], Ok(ParsedMessage::Hdt(_))));
+    }
+}
@@ -0,0 +1,292 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! Checks of the strict mode of `NmeaParser`
+
+use super::*;
+
+/// Maximum length of a sentence including the start character and the line terminator
+pub(crate) const MAX_SENTENCE_LENGTH: usize = 82;
+
+/// Make an error about breaking the given strict mode rule.
+fn violation(rule: StrictRule, message: String) -> ParseError {
+    ParseError::new(ParseErrorKind::StrictViolation(rule), message)
+}
+
+/// Check that the sentence (starting from the start character) isn't too long. The line
+/// terminator is counted even if the sentence doesn't have it.
+pub(crate) fn check_sentence_length(sentence: &str) -> Result<(), ParseError> {
+    let len = sentence.trim_end_matches(['\r', '\n']).len() + 2;
+    if len > MAX_SENTENCE_LENGTH {
+        return Err(violation(
+            StrictRule::SentenceLength,
+            format!(
+                "Sentence length {} exceeds {} characters",
+                len, MAX_SENTENCE_LENGTH
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Check that the checksum is given and that it has only uppercase hexadecimal digits.
+pub(crate) fn check_checksum(checksum_hex: &str) -> Result<(), ParseError> {
+    if checksum_hex.is_empty() {
+        return Err(violation(
+            StrictRule::MissingChecksum,
+            "Checksum missing".into(),
+        ));
+    }
+    if checksum_hex.chars().any(|c| c.is_ascii_lowercase()) {
+        return Err(violation(
+            StrictRule::LowercaseChecksum,
+            format!("Lowercase checksum: {}", checksum_hex),
+        )
+        .with_raw_value(checksum_hex));
+    }
+    Ok(())
+}
+
+/// Check that the number of fields (including the sentence type) matches the sentence type
+/// (e.g. "$GGA"). Unknown sentence types aren't checked.
+pub(crate) fn check_field_count(sentence_type: &str, count: usize) -> Result<(), ParseError> {
+    let valid = match sentence_type {
+        "$GGA" => count == 15,
+        "$RMC" => (12..=14).contains(&count),
+        "$GNS" => (13..=14).contains(&count),
+        "$GSA" => (18..=19).contains(&count),
+        "$GSV" => (4..=21).contains(&count) && count % 4 <= 1,
+        "$VTG" => (9..=10).contains(&count),
+        "$GLL" => (6..=8).contains(&count),
+        "$ALM" => count == 16,
+        "$DTM" => count == 9,
+        "$MSS" => (6..=7).contains(&count),
+        "$STN" => count == 2,
+        "$VBW" => count == 7 || count == 11,
+        "$ZDA" => count == 7,
+        "$DPT" => (3..=4).contains(&count),
+        "$DBS" => count == 7,
+        "$MTW" => count == 3,
+        "$VHW" => count == 9,
+        "$HDT" => count == 3,
+        "$MWV" => count == 6,
+        "$GST" => count == 9,
+        "$GBS" => count == 9 || count == 11,
+        "$RMB" => (14..=15).contains(&count),
+        "$APB" => (15..=16).contains(&count),
+        "$XTE" => (6..=7).contains(&count),
+        "$BOD" => count == 7,
+        "$BWC" | "$BWR" => (13..=14).contains(&count),
+        "!VDM" | "!VDO" => count == 7,
+        _ => true,
+    };
+    if !valid {
+        return Err(violation(
+            StrictRule::FieldCount,
+            format!("Unexpected number of fields: {}", count),
+        )
+        .with_raw_value(&count.to_string()));
+    }
+    Ok(())
+}
+
+/// Check that AIS VDM/VDO fill bits are within 0-5 and zero in non-final fragments.
+pub(crate) fn check_fill_bits(
+    fill_bits: &str,
+    fragment_number: u8,
+    fragment_count: u8,
+) -> Result<(), ParseError> {
+    let valid = match fill_bits.parse::<u8>() {
+        Ok(n) if fragment_number < fragment_count => n == 0,
+        Ok(n) => n <= 5,
+        Err(_) => false,
+    };
+    if !valid {
+        return Err(violation(
+            StrictRule::FillBits,
+            format!(
+                "Invalid fill bits in fragment {}/{}: {}",
+                fragment_number, fragment_count, fill_bits
+            ),
+        )
+        .with_field(6, "fill_bits")
+        .with_raw_value(fill_bits));
+    }
+    Ok(())
+}
+
+/// Check position, true heading and navigation status of AIS position reports for values
+/// outside of the valid ranges.
+pub(crate) fn check_ais_ranges(bv: &BitVec) -> Result<(), ParseError> {
+    // Bit offsets and lengths of longitude and latitude with their resolution, and bit offsets of
+    // true heading and navigation status
+    let (position, heading, nav_status) = match pick_u64(bv, 0, 6) {
+        1..=3 => (Some((61, 28, 89, 27, 600000)), Some(128), Some(38)),
+        4 | 11 => (Some((79, 28, 107, 27, 600000)), None, None),
+        9 => (Some((61, 28, 89, 27, 600000)), None, None),
+        18 | 19 => (Some((57, 28, 85, 27, 600000)), Some(124), None),
+        21 => (Some((164, 28, 192, 27, 600000)), None, None),
+        27 => (Some((44, 18, 62, 17, 600)), None, Some(40)),
+        _ => (None, None, None),
+    };
+
+    if let Some((lon_index, lon_len, lat_index, lat_len, units_per_degree)) = position {
+        let lon_raw = pick_i64(bv, lon_index, lon_len);
+        if lon_raw.abs() > 180 * units_per_degree && lon_raw != 181 * units_per_degree {
+            return Err(violation(
+                StrictRule::Longitude,
+                format!(
+                    "Longitude out of range: {}",
+                    lon_raw as f64 / units_per_degree as f64
+                ),
+            )
+            .with_bit(lon_index, "longitude")
+            .with_raw_value(&lon_raw.to_string()));
+        }
+        let lat_raw = pick_i64(bv, lat_index, lat_len);
+        if lat_raw.abs() > 90 * units_per_degree && lat_raw != 91 * units_per_degree {
+            return Err(violation(
+                StrictRule::Latitude,
+                format!(
+                    "Latitude out of range: {}",
+                    lat_raw as f64 / units_per_degree as f64
+                ),
+            )
+            .with_bit(lat_index, "latitude")
+            .with_raw_value(&lat_raw.to_string()));
+        }
+    }
+
+    if let Some(index) = heading {
+        let heading_raw = pick_u64(bv, index, 9);
+        if (360..=510).contains(&heading_raw) {
+            return Err(violation(
+                StrictRule::Heading,
+                format!("Heading out of range: {}", heading_raw),
+            )
+            .with_bit(index, "heading_true")
+            .with_raw_value(&heading_raw.to_string()));
+        }
+    }
+
+    if let Some(index) = nav_status {
+        let nav_status_raw = pick_u64(bv, index, 4);
+        if (9..=13).contains(&nav_status_raw) {
+            return Err(violation(
+                StrictRule::NavigationStatus,
+                format!("Reserved navigation status: {}", nav_status_raw),
+            )
+            .with_bit(index, "nav_status")
+            .with_raw_value(&nav_status_raw.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Overwrite a numeric field of `len` bits in `BitVec`.
+    fn set_u64(bv: &mut BitVec, index: usize, len: usize, value: u64) {
+        for i in 0..len {
+            bv.set(index + i, (value >> (len - 1 - i)) & 0x01 != 0);
+        }
+    }
+
+    fn rule(result: Result<(), ParseError>) -> Option<StrictRule> {
+        match result.map_err(|e| e.kind()) {
+            Err(ParseErrorKind::StrictViolation(rule)) => Some(rule),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_check_sentence() {
+        assert!(check_sentence_length(&format!("${}", "A".repeat(79))).is_ok());
+        assert!(check_sentence_length(&format!("${}\r\n", "A".repeat(79))).is_ok());
+        assert_eq!(
+            rule(check_sentence_length(&format!("${}", "A".repeat(80)))),
+            Some(StrictRule::SentenceLength)
+        );
+
+        assert!(check_checksum("5C").is_ok());
+        assert_eq!(rule(check_checksum("")), Some(StrictRule::MissingChecksum));
+        assert_eq!(
+            rule(check_checksum("5c")),
+            Some(StrictRule::LowercaseChecksum)
+        );
+
+        assert!(check_field_count("$GGA", 15).is_ok());
+        assert_eq!(
+            rule(check_field_count("$GGA", 14)),
+            Some(StrictRule::FieldCount)
+        );
+        assert!(check_field_count("$GSV", 20).is_ok());
+        assert!(check_field_count("$GSV", 12).is_ok());
+        assert_eq!(
+            rule(check_field_count("$GSV", 14)),
+            Some(StrictRule::FieldCount)
+        );
+        assert!(check_field_count("$XYZ", 1).is_ok());
+
+        assert!(check_fill_bits("0", 1, 2).is_ok());
+        assert!(check_fill_bits("2", 2, 2).is_ok());
+        assert_eq!(rule(check_fill_bits("2", 1, 2)), Some(StrictRule::FillBits));
+        assert_eq!(rule(check_fill_bits("6", 1, 1)), Some(StrictRule::FillBits));
+        assert_eq!(rule(check_fill_bits("", 1, 1)), Some(StrictRule::FillBits));
+    }
+
+    #[test]
+    fn test_check_ais_ranges() {
+        let valid = parse_payload("13u?etPv2;0n:dDPwUM1U1Cb069D", 0).unwrap();
+        assert!(check_ais_ranges(&valid).is_ok());
+
+        let mut bv = valid.clone();
+        set_u64(&mut bv, 89, 27, 92 * 600000);
+        assert_eq!(rule(check_ais_ranges(&bv)), Some(StrictRule::Latitude));
+        set_u64(&mut bv, 89, 27, 91 * 600000);
+        assert!(check_ais_ranges(&bv).is_ok());
+
+        let mut bv = valid.clone();
+        set_u64(&mut bv, 61, 28, 182 * 600000);
+        assert_eq!(rule(check_ais_ranges(&bv)), Some(StrictRule::Longitude));
+
+        let mut bv = valid.clone();
+        set_u64(&mut bv, 128, 9, 400);
+        let e = check_ais_ranges(&bv).unwrap_err();
+        assert_eq!(
+            e.kind(),
+            ParseErrorKind::StrictViolation(StrictRule::Heading)
+        );
+        assert_eq!(e.location(), Some(ErrorLocation::Bit(128)));
+        assert_eq!(e.raw_value(), Some("400"));
+        set_u64(&mut bv, 128, 9, 511);
+        assert!(check_ais_ranges(&bv).is_ok());
+
+        let mut bv = valid;
+        set_u64(&mut bv, 38, 4, 13);
+        assert_eq!(
+            rule(check_ais_ranges(&bv)),
+            Some(StrictRule::NavigationStatus)
+        );
+        set_u64(&mut bv, 38, 4, 14);
+        assert!(check_ais_ranges(&bv).is_ok());
+    }
+}
@@ -0,0 +1,285 @@
+/*
+Copyright 2020 Timo Saarinen
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+//! NMEA 4.x TAG block parsing
+
+use super::*;
+
+/// Maximum number of sentences in a TAG block sentence group
+const MAX_GROUP_SENTENCES: u32 = 63;
+
+// -------------------------------------------------------------------------------------------------
+
+/// NMEA 4.x TAG block prefixing a sentence, e.g. `\s:station1,c:1577836800*76\`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TagBlock {
+    /// Source station identifier (s)
+    pub source: Option<String>,
+
+    /// Receiver UNIX time (c)
+    pub time: Option<DateTime<Utc>>,
+
+    /// Destination identifier (d)
+    pub destination: Option<String>,
+
+    /// Sentence grouping (g)
+    pub group: Option<TagGroup>,
+
+    /// Line count (n)
+    pub line_count: Option<u32>,
+
+    /// Relative time (r)
+    pub relative_time: Option<u64>,
+
+    /// Free text (t)
+    pub text: Option<String>,
+}
+
+/// Sentence grouping of a TAG block
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TagGroup {
+    /// Number of the sentence in the group (1-based)
+    pub sentence_number: u32,
+
+    /// Total number of sentences in the group
+    pub sentence_count: u32,
+
+    /// Group identifier
+    pub group_id: u32,
+}
+
+impl TagBlock {
+    /// Fill the fields missing from `self` with the ones of `other`. Used for combining the TAG
+    /// blocks of a sentence group where only the first sentence usually carries all the fields.
+    pub(crate) fn merge(&mut self, other: &TagBlock) {
+        if self.source.is_none() {
+            self.source = other.source.clone();
+        }
+        if self.time.is_none() {
+            self.time = other.time;
+        }
+        if self.destination.is_none() {
+            self.destination = other.destination.clone();
+        }
+        if self.group.is_none() {
+            self.group = other.group;
+        }
+        if self.line_count.is_none() {
+            self.line_count = other.line_count;
+        }
+        if self.relative_time.is_none() {
+            self.relative_time = other.relative_time;
+        }
+        if self.text.is_none() {
+            self.text = other.text.clone();
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Split the TAG block off the beginning of the sentence if it has one. Return the parsed TAG
+/// block and the rest of the sentence.
+pub(crate) fn split_tag_block(sentence: &str) -> Result<(Option<TagBlock>, &str), ParseError> {
+    let tag_start = match (sentence.find('\\'), sentence.find(['$', '!'])) {
+        (Some(tag_start), Some(sentence_start)) if tag_start < sentence_start => tag_start,
+        (Some(tag_start), None) => tag_start,
+        _ => return Ok((None, sentence)),
+    };
+    let tag_end = match sentence[tag_start + 1..].find('\\') {
+        Some(i) => tag_start + 1 + i,
+        None => {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidSentence,
+                format!("Unterminated TAG block: {}", sentence),
+            ));
+        }
+    };
+    let tag_block = parse_tag_block(&sentence[tag_start + 1..tag_end])?;
+    Ok((Some(tag_block), &sentence[tag_end + 1..]))
+}
+
+/// Parse TAG block content (without the surrounding backslashes).
+pub(crate) fn parse_tag_block(content: &str) -> Result<TagBlock, ParseError> {
+    // Verify checksum if given
+    let content = if let Some(pos) = content.rfind('*') {
+        let checksum = content[..pos].bytes().fold(0u8, |acc, b| acc ^ b);
+        let checksum_hex_given = &content[pos + 1..];
+        let checksum_hex_calculated = format!("{:02X?}", checksum);
+        if !checksum_hex_given.eq_ignore_ascii_case(&checksum_hex_calculated) {
+            return Err(ParseError::new(
+                ParseErrorKind::BadChecksum,
+                format!(
+                    "Corrupted TAG block: {:?} != {:?}",
+                    checksum_hex_calculated, checksum_hex_given
+                ),
+            )
+            .with_raw_value(checksum_hex_given));
+        }
+        &content[..pos]
+    } else {
+        debug!("No checksum found for TAG block: {}", content);
+        content
+    };
+
+    let mut tag_block = TagBlock::default();
+    for field in content.split(',').filter(|f| !f.is_empty()) {
+        let (code, value) = match field.split_once(':') {
+            Some((code, value)) => (code, value),
+            None => {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidSentence,
+                    format!("Invalid TAG block field: {}", field),
+                )
+                .with_raw_value(field));
+            }
+        };
+        match code {
+            "s" => tag_block.source = Some(value.into()),
+            "c" => {
+                let raw = value.parse::<i64>()?;
+                // Some receivers use milliseconds instead of seconds
+                let millis = if raw > 9_999_999_999 { raw } else { raw * 1000 };
+                tag_block.time = match Utc.timestamp_millis_opt(millis) {
+                    chrono::LocalResult::Single(t) => Some(t),
+                    _ => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::OutOfRange,
+                            format!("Invalid TAG block time: {}", value),
+                        )
+                        .with_raw_value(value));
+                    }
+                };
+            }
+            "d" => tag_block.destination = Some(value.into()),
+            "g" => {
+                let parts: Vec<&str> = value.split('-').collect();
+                if parts.len() != 3 {
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidSentence,
+                        format!("Invalid TAG block group: {}", value),
+                    )
+                    .with_raw_value(value));
+                }
+                let group = TagGroup {
+                    sentence_number: parts[0].parse()?,
+                    sentence_count: parts[1].parse()?,
+                    group_id: parts[2].parse()?,
+                };
+                if group.sentence_count > MAX_GROUP_SENTENCES
+                    || group.sentence_number == 0
+                    || group.sentence_number > group.sentence_count
+                {
+                    return Err(ParseError::new(
+                        ParseErrorKind::OutOfRange,
+                        format!("TAG block group out of range: {}", value),
+                    )
+                    .with_raw_value(value));
+                }
+                tag_block.group = Some(group);
+            }
+            "n" => tag_block.line_count = Some(value.parse()?),
+            "r" => tag_block.relative_time = Some(value.parse()?),
+            "t" => tag_block.text = Some(value.into()),
+            _ => {
+                debug!("Unsupported TAG block field: {}", field);
+            }
+        }
+    }
+    Ok(tag_block)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_tag_block() {
+        assert_eq!(
+            parse_tag_block("s:station1,c:1577836800*76"),
+            Ok(TagBlock {
+                source: Some("station1".into()),
+                time: Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).single(),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_tag_block("g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A"),
+            Ok(TagBlock {
+                source: Some("r003669945".into()),
+                time: Utc.with_ymd_and_hms(2009, 5, 5, 17, 20, 35).single(),
+                group: Some(TagGroup {
+                    sentence_number: 1,
+                    sentence_count: 2,
+                    group_id: 73874,
+                }),
+                line_count: Some(157036),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_tag_block("d:dest,r:12345,t:Hello world"),
+            Ok(TagBlock {
+                destination: Some("dest".into()),
+                relative_time: Some(12345),
+                text: Some("Hello world".into()),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_tag_block("c:1577836800123").map(|t| t.time),
+            Ok(Utc.timestamp_millis_opt(1577836800123).single())
+        );
+        assert_eq!(
+            parse_tag_block("s:station1,c:1577836800*00").map_err(|e| e.kind()),
+            Err(ParseErrorKind::BadChecksum)
+        );
+        assert_eq!(
+            parse_tag_block("g:1-2").map_err(|e| e.kind()),
+            Err(ParseErrorKind::InvalidSentence)
+        );
+        for group in ["g:1-64-73874", "g:0-2-73874", "g:3-2-73874"] {
+            assert_eq!(
+                parse_tag_block(group).map_err(|e| e.kind()),
+                Err(ParseErrorKind::OutOfRange)
+            );
+        }
+        assert_eq!(
+            parse_tag_block("g:63-63-73874").map(|t| t.group.map(|g| g.sentence_count)),
+            Ok(Some(63))
+        );
+        assert_eq!(
+            parse_tag_block("station1").map_err(|e| e.kind()),
+            Err(ParseErrorKind::InvalidSentence)
+        );
+    }
+
+    #[test]
+    fn test_split_tag_block() {
+        assert_eq!(
+            split_tag_block("!AIVDM,1,1,,A,15MgK45P3@G?fl0E`JbR0OwT0@MS,0*4E"),
+            Ok((None, "!AIVDM,1,1,,A,15MgK45P3@G?fl0E`JbR0OwT0@MS,0*4E"))
+        );
+        let (tag_block, rest) =
+            split_tag_block("\\s:station1,c:1577836800*76\\!AIVDM,1,1,,A,15MgK45P3@G,0*4E")
+                .unwrap();
+        assert_eq!(tag_block.unwrap().source, Some("station1".into()));
+        assert_eq!(rest, "!AIVDM,1,1,,A,15MgK45P3@G,0*4E");
+        assert!(split_tag_block("\\s:station1!AIVDM").is_err());
+    }
+}
@@ -33,8 +33,9 @@ pub(crate) fn make_fragment_key(
     )
 }
 
-/// Convert AIS VDM/VDO payload armored string into a `BitVec`.
-pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, String> {
+/// Convert AIS VDM/VDO payload armored string into a `BitVec`. The given number of fill bits
+/// is removed from the end of the result.
+pub(crate) fn parse_payload(payload: &str, fill_bits: usize) -> Result<BitVec, String> {
     let mut bv = BitVec::<usize, LocalBits>::with_capacity(payload.len() * 6);
     for c in payload.chars() {
         let mut ci = (c as u8) - 48;
@@ -47,6 +48,7 @@ pub(crate) fn parse_payload(payload: &str) -> Result<BitVec, String> {
             bv.push(((ci >> (5 - i)) & 0x01) != 0);
         }
     }
+    bv.truncate(bv.len().saturating_sub(fill_bits));
 
     Ok(bv)
 }
@@ -83,9 +85,39 @@ pub(crate) fn pick_i64(bv: &BitVec, index: usize, len: usize) -> i64 {
     }
 }
 
+/// Pick a numeric field from `BitVec` or `None` if the field has the "not available" value `na`.
+pub(crate) fn pick_u64_unless_na(bv: &BitVec, index: usize, len: usize, na: u64) -> Option<u64> {
+    let raw = pick_u64(bv, index, len);
+    if raw != na {
+        Some(raw)
+    } else {
+        None
+    }
+}
+
+/// Pick a signed numeric field from `BitVec` or `None` if the field has the "not available"
+/// value `na`.
+pub(crate) fn pick_i64_unless_na(bv: &BitVec, index: usize, len: usize, na: i64) -> Option<i64> {
+    let raw = pick_i64(bv, index, len);
+    if raw != na {
+        Some(raw)
+    } else {
+        None
+    }
+}
+
 /// Pick a string from BitVec. Field `char_count` defines string length in characters.
 /// Characters consist of 6 bits.
 pub(crate) fn pick_string(bv: &BitVec, index: usize, char_count: usize) -> String {
+    let mut res = pick_untrimmed_string(bv, index, char_count);
+    let trimmed_len = res.trim_end().len();
+    res.truncate(trimmed_len);
+    res
+}
+
+/// Pick a string from BitVec without trimming trailing whitespace. Useful when a text is split
+/// into several fields which are concatenated afterwards.
+pub(crate) fn pick_untrimmed_string(bv: &BitVec, index: usize, char_count: usize) -> String {
     let mut res = String::with_capacity(char_count);
     for i in 0..char_count {
         // unwraps below won't panic as char_from::u32 will only ever receive values between
@@ -98,19 +130,103 @@ pub(crate) fn pick_string(bv: &BitVec, index: usize, char_count: usize) -> Strin
             ch => unreachable!("6-bit AIS character expected but value {} encountered!", ch),
         }
     }
-
-    let trimmed_len = res.trim_end().len();
-    res.truncate(trimmed_len);
     res
 }
 
-/// Pick ETA based on UTC month, day, hour and minute.
-pub(crate) fn pick_eta(bv: &BitVec, index: usize) -> Result<Option<DateTime<Utc>>, ParseError> {
-    pick_eta_with_now(
-        bv,
-        index,
-        Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap(),
-    )
+/// Convert `BitVec` into AIS VDM/VDO payload armored string. Return also the number of fill bits
+/// needed to pad the payload to full characters. This is the inverse of `parse_payload`.
+pub(crate) fn make_payload(bv: &BitVec) -> (String, u8) {
+    let mut payload = String::with_capacity(bv.len().div_ceil(AIS_CHAR_BITS));
+    for index in (0..bv.len()).step_by(AIS_CHAR_BITS) {
+        let mut ci = pick_u64(bv, index, AIS_CHAR_BITS) as u8;
+        if ci > 39 {
+            ci += 8;
+        }
+        payload.push((ci + 48) as char);
+    }
+    let fill_bits = (AIS_CHAR_BITS - bv.len() % AIS_CHAR_BITS) % AIS_CHAR_BITS;
+    (payload, fill_bits as u8)
+}
+
+/// Push a numeric field of `len` bits to `BitVec`. This is the inverse of `pick_u64`.
+pub(crate) fn push_u64(bv: &mut BitVec, value: u64, len: usize) {
+    for i in (0..len).rev() {
+        bv.push((value >> i) & 0x01 != 0);
+    }
+}
+
+/// Push a signed numeric field of `len` bits in two's complement to `BitVec`. This is the inverse
+/// of `pick_i64`.
+pub(crate) fn push_i64(bv: &mut BitVec, value: i64, len: usize) {
+    push_u64(bv, value as u64, len);
+}
+
+/// Push a string to `BitVec` as `char_count` 6-bit characters. Shorter strings are padded with
+/// `@` characters and lowercase letters are converted to uppercase. Characters outside the AIS
+/// character set are replaced with `?`. This is the inverse of `pick_string`.
+pub(crate) fn push_string(bv: &mut BitVec, s: &str, char_count: usize) {
+    let mut chars = s.chars();
+    for _ in 0..char_count {
+        let ch = match chars.next().map(|c| c.to_ascii_uppercase() as u32) {
+            Some(ch) if (64..96).contains(&ch) => ch - 64,
+            Some(ch) if (32..64).contains(&ch) => ch,
+            Some(_) => 63,
+            None => 0,
+        };
+        push_u64(bv, ch as u64, AIS_CHAR_BITS);
+    }
+}
+
+/// Push ETA or other UTC month, day, hour and minute fields to `BitVec`. `None` is encoded as the
+/// "not available" values.
+pub(crate) fn push_eta(bv: &mut BitVec, eta: Option<DateTime<Utc>>) {
+    let (month, day, hour, minute) = match eta {
+        Some(eta) => (eta.month(), eta.day(), eta.hour(), eta.minute()),
+        None => (0, 0, 24, 60),
+    };
+    push_u64(bv, month as u64, 4);
+    push_u64(bv, day as u64, 5);
+    push_u64(bv, hour as u64, 5);
+    push_u64(bv, minute as u64, 6);
+}
+
+/// Push a signed longitude field of `len` bits to `BitVec`. `None` is encoded as 181°. This is the
+/// inverse of `pick_longitude`.
+pub(crate) fn push_longitude(
+    bv: &mut BitVec,
+    longitude: Option<f64>,
+    len: usize,
+    units_per_degree: i64,
+) {
+    let raw = (longitude.unwrap_or(181.0) * units_per_degree as f64).round() as i64;
+    push_i64(bv, raw, len);
+}
+
+/// Push a signed latitude field of `len` bits to `BitVec`. `None` is encoded as 91°. This is the
+/// inverse of `pick_latitude`.
+pub(crate) fn push_latitude(
+    bv: &mut BitVec,
+    latitude: Option<f64>,
+    len: usize,
+    units_per_degree: i64,
+) {
+    let raw = (latitude.unwrap_or(91.0) * units_per_degree as f64).round() as i64;
+    push_i64(bv, raw, len);
+}
+
+/// Reference time for date and year inference when the current time isn't known.
+pub(crate) fn default_reference_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single().unwrap()
+}
+
+/// Pick ETA based on UTC month, day, hour and minute. The year is completed relative to the
+/// reference time `now`.
+pub(crate) fn pick_eta(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<Option<DateTime<Utc>>, ParseError> {
+    pick_eta_with_now(bv, index, now.unwrap_or_else(default_reference_time))
 }
 
 /// Pick ETA based on UTC month, day, hour and minute. Define also 'now'. This function is needed
@@ -146,10 +262,63 @@ fn pick_eta_with_now(
         minute = 59;
     }
 
+    complete_year(now, month, day, hour, minute, 30)
+        .map(Some)
+        .map_err(|e| e.with_bit(index, "eta"))
+}
+
+/// Pick UTC month, day, hour and minute and complete the year relative to the reference time
+/// the same way as with ETA. Return `None` if any of the fields has a "not available" value.
+pub(crate) fn pick_month_day_time(
+    bv: &BitVec,
+    index: usize,
+    now: Option<DateTime<Utc>>,
+) -> Result<Option<DateTime<Utc>>, ParseError> {
+    let month = pick_u64(bv, index, 4) as u32;
+    let day = pick_u64(bv, index + 4, 5) as u32;
+    let hour = pick_u64(bv, index + 4 + 5, 5) as u32;
+    let minute = pick_u64(bv, index + 4 + 5 + 5, 6) as u32;
+    month_day_time_to_utc(month, day, hour, minute, now).map_err(|e| e.with_bit(index, "time"))
+}
+
+/// Convert UTC month, day, hour and minute into `DateTime<Utc>` completing the year relative to
+/// the reference time. Return `None` if any of the arguments has a "not available" value
+/// (0 for month and day, 24 for hour and 60 for minute).
+pub(crate) fn month_day_time_to_utc(
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    now: Option<DateTime<Utc>>,
+) -> Result<Option<DateTime<Utc>>, ParseError> {
+    if month == 0 || day == 0 || hour == 24 || minute == 60 {
+        return Ok(None);
+    }
+    complete_year(
+        now.unwrap_or_else(default_reference_time),
+        month,
+        day,
+        hour,
+        minute,
+        0,
+    )
+    .map(Some)
+}
+
+/// Complete the year of the given date and time so that the result is close to `now`. Dates more
+/// than 180 days in the past are assumed to refer to the next year.
+fn complete_year(
+    now: DateTime<Utc>,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> Result<DateTime<Utc>, ParseError> {
     // Ensure that that params from nmea are parsable as valid date
     // Notice that we can't rely on ? operator here because of leap years
-    let res_this = parse_valid_utc(now.year(), month, day, hour, minute, 30, 0);
-    let res_next = parse_valid_utc(now.year() + 1, month, day, hour, minute, 30, 0);
+    let res_this = parse_valid_utc(now.year(), month, day, hour, minute, second, 0);
+    let res_next = parse_valid_utc(now.year() + 1, month, day, hour, minute, second, 0);
     if res_this.is_err() && res_next.is_err() {
         // Both years result invalid date
         match res_this {
@@ -160,48 +329,98 @@ fn pick_eta_with_now(
         }
     } else if res_this.is_err() {
         // Only next year results valid date
-        Ok(Some(res_next.unwrap()))
+        Ok(res_next.unwrap())
     } else if res_next.is_err() {
         // Only this year results valid date
-        Ok(Some(res_this.unwrap()))
+        Ok(res_this.unwrap())
     } else {
         // Both years result a valid date
-        // If the ETA is more than 180 days in past assume it's about next year
-        let this_year_eta = res_this.unwrap();
-        if now - Duration::days(180) <= this_year_eta {
-            Ok(Some(this_year_eta))
+        // If the date is more than 180 days in past assume it's about next year
+        let this_year = res_this.unwrap();
+        if now - Duration::days(180) <= this_year {
+            Ok(this_year)
         } else {
-            Ok(res_next.ok())
+            res_next
         }
     }
 }
 
+/// Pick a signed longitude field of `len` bits. Argument `units_per_degree` defines the
+/// resolution of the field (e.g. 600000 for 1/10000 minutes). The value 181° means "not available"
+/// and results `None`.
+pub(crate) fn pick_longitude(
+    bv: &BitVec,
+    index: usize,
+    len: usize,
+    units_per_degree: i64,
+) -> Option<f64> {
+    let raw = pick_i64(bv, index, len);
+    if raw != 181 * units_per_degree {
+        Some(raw as f64 / units_per_degree as f64)
+    } else {
+        None
+    }
+}
+
+/// Pick a signed latitude field of `len` bits. Argument `units_per_degree` defines the
+/// resolution of the field (e.g. 600000 for 1/10000 minutes). The value 91° means "not available"
+/// and results `None`.
+pub(crate) fn pick_latitude(
+    bv: &BitVec,
+    index: usize,
+    len: usize,
+    units_per_degree: i64,
+) -> Option<f64> {
+    let raw = pick_i64(bv, index, len);
+    if raw != 91 * units_per_degree {
+        Some(raw as f64 / units_per_degree as f64)
+    } else {
+        None
+    }
+}
+
 /// Pick number field from a comma-separated sentence or `None` in case of an empty field.
+/// Argument `name` is the field name reported in case of a parse error.
 pub(crate) fn pick_number_field<T: core::str::FromStr>(
     split: &[&str],
     num: usize,
-) -> Result<Option<T>, String> {
+    name: &'static str,
+) -> Result<Option<T>, ParseError> {
     split
         .get(num)
         .filter(|s| !s.is_empty())
         .map(|s| {
-            s.parse()
-                .map_err(|_| format!("Failed to parse field {}: {}", num, s))
+            s.parse().map_err(|_| {
+                ParseError::new(
+                    ParseErrorKind::InvalidField,
+                    format!("Failed to parse number: {}", s),
+                )
+                .with_field(num, name)
+                .with_raw_value(s)
+            })
         })
         .transpose()
 }
 
 /// Pick hex-formatted field from a comma-separated sentence or `None` in case of an empty field.
+/// Argument `name` is the field name reported in case of a parse error.
 pub(crate) fn pick_hex_field<T: num_traits::Num>(
     split: &[&str],
     num: usize,
-) -> Result<Option<T>, String> {
+    name: &'static str,
+) -> Result<Option<T>, ParseError> {
     split
         .get(num)
         .filter(|s| !s.is_empty())
         .map(|s| {
-            T::from_str_radix(s, 16)
-                .map_err(|_| format!("Failed to parse hex field {}: {}", num, s))
+            T::from_str_radix(s, 16).map_err(|_| {
+                ParseError::new(
+                    ParseErrorKind::InvalidField,
+                    format!("Failed to parse hex number: {}", s),
+                )
+                .with_field(num, name)
+                .with_raw_value(s)
+            })
         })
         .transpose()
 }
@@ -216,22 +435,70 @@ pub(crate) fn pick_string_field(split: &[&str], num: usize) -> Option<String> {
     }
 }
 
-/// Parse time field of format HHMMSS and convert it to `DateTime<Utc>` using the current time.
-pub(crate) fn parse_hhmmss(hhmmss: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, ParseError> {
-    let (hour, minute, second) =
-        parse_time(hhmmss).map_err(|_| format!("Invalid time format: {}", hhmmss))?;
-    parse_valid_utc(now.year(), now.month(), now.day(), hour, minute, second, 0)
+/// Pick status field ("A" for true and "V" for false) or `None` in case of an empty or unknown
+/// field.
+pub(crate) fn pick_status_field(split: &[&str], num: usize) -> Option<bool> {
+    match *split.get(num).unwrap_or(&"") {
+        "A" => Some(true),
+        "V" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse time field of format HHMMSS and convert it to `DateTime<Utc>` using the date closest to
+/// the reference time `now` (see `nearest_day`).
+pub(crate) fn parse_hhmmss(
+    hhmmss: &str,
+    now: Option<DateTime<Utc>>,
+) -> Result<DateTime<Utc>, ParseError> {
+    let (hour, minute, second) = parse_time(hhmmss).map_err(|_| invalid_format("time", hhmmss))?;
+    let date = now.unwrap_or_else(default_reference_time);
+    let t = parse_valid_utc(
+        date.year(),
+        date.month(),
+        date.day(),
+        hour,
+        minute,
+        second,
+        0,
+    )?;
+    Ok(nearest_day(t, now))
+}
+
+/// Move the given time of day to the previous or the next day if that is closer to the reference
+/// time `now`. Without the reference time the given time is returned as such.
+pub(crate) fn nearest_day(t: DateTime<Utc>, now: Option<DateTime<Utc>>) -> DateTime<Utc> {
+    match now {
+        Some(now) if t - now > Duration::hours(12) => t - Duration::days(1),
+        Some(now) if now - t > Duration::hours(12) => t + Duration::days(1),
+        _ => t,
+    }
 }
 
-/// Parse time fields of formats YYMMDD and HHMMSS and convert them to `DateTime<Utc>`.
-pub(crate) fn parse_yymmdd_hhmmss(yymmdd: &str, hhmmss: &str) -> Result<DateTime<Utc>, ParseError> {
-    let now = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
-    let century = (now.year() / 100) * 100;
-    let (day, month, year) =
-        parse_date(yymmdd).map_err(|_| format!("Invalid date format: {}", yymmdd))?;
-    let (hour, minute, second) =
-        parse_time(hhmmss).map_err(|_| format!("Invalid time format: {}", hhmmss))?;
-    parse_valid_utc(century + year, month, day, hour, minute, second, 0)
+/// Parse time fields of formats YYMMDD and HHMMSS and convert them to `DateTime<Utc>`. The
+/// century is chosen so that the year is within 50 years from the reference time `now`. Without
+/// the reference time the 21st century is assumed.
+pub(crate) fn parse_yymmdd_hhmmss(
+    yymmdd: &str,
+    hhmmss: &str,
+    now: Option<DateTime<Utc>>,
+) -> Result<DateTime<Utc>, ParseError> {
+    let (day, month, year) = parse_date(yymmdd).map_err(|_| invalid_format("date", yymmdd))?;
+    let (hour, minute, second) = parse_time(hhmmss).map_err(|_| invalid_format("time", hhmmss))?;
+    let year = match now {
+        Some(now) => {
+            let year = (now.year() / 100) * 100 + year;
+            if year > now.year() + 50 {
+                year - 100
+            } else if year <= now.year() - 50 {
+                year + 100
+            } else {
+                year
+            }
+        }
+        None => 2000 + year,
+    };
+    parse_valid_utc(year, month, day, hour, minute, second, 0)
 }
 
 /// Parse time field of format HHMMSS.SS and convert it to `DateTime<Utc>` using the given date.
@@ -239,8 +506,8 @@ pub(crate) fn parse_hhmmss_ss(
     hhmmss: &str,
     date: DateTime<Utc>,
 ) -> Result<DateTime<Utc>, ParseError> {
-    let (hour, minute, second, nano) = parse_time_with_fractions(hhmmss)
-        .map_err(|_| format!("Invalid time format: {}", hhmmss))?;
+    let (hour, minute, second, nano) =
+        parse_time_with_fractions(hhmmss).map_err(|_| invalid_format("time", hhmmss))?;
     parse_valid_utc(
         date.year(),
         date.month(),
@@ -252,6 +519,11 @@ pub(crate) fn parse_hhmmss_ss(
     )
 }
 
+/// Pick field from a comma-separated sentence or an empty string if the field doesn't exist.
+fn pick_field_or_empty<'a>(split: &[&'a str], num: usize) -> &'a str {
+    split.get(num).unwrap_or(&"")
+}
+
 /// Pick date by picking the given field numbers. Set time part to midnight.
 pub(crate) fn pick_date_with_fields(
     split: &[&str],
@@ -263,9 +535,15 @@ pub(crate) fn pick_date_with_fields(
     second: u32,
     nanos: u32,
 ) -> Result<DateTime<Utc>, ParseError> {
-    let year = split.get(year_field).unwrap_or(&"").parse::<i32>()?;
-    let month = split.get(month_field).unwrap_or(&"").parse::<u32>()?;
-    let day = split.get(day_field).unwrap_or(&"").parse::<u32>()?;
+    let year = pick_field_or_empty(split, year_field)
+        .parse::<i32>()
+        .map_err(|e| ParseError::from(e).with_field(year_field, "year"))?;
+    let month = pick_field_or_empty(split, month_field)
+        .parse::<u32>()
+        .map_err(|e| ParseError::from(e).with_field(month_field, "month"))?;
+    let day = pick_field_or_empty(split, day_field)
+        .parse::<u32>()
+        .map_err(|e| ParseError::from(e).with_field(day_field, "day"))?;
     parse_valid_utc(year, month, day, hour, minute, second, nanos)
 }
 
@@ -275,19 +553,35 @@ pub(crate) fn pick_timezone_with_fields(
     hour_field: usize,
     minute_field: usize,
 ) -> Result<FixedOffset, ParseError> {
-    let hour = split.get(hour_field).unwrap_or(&"").parse::<i32>()?;
-    let minute = split.get(minute_field).unwrap_or(&"0").parse::<i32>()?;
+    let hour = pick_field_or_empty(split, hour_field)
+        .parse::<i32>()
+        .map_err(|e| ParseError::from(e).with_field(hour_field, "zone_hours"))?;
+    let minute = split
+        .get(minute_field)
+        .unwrap_or(&"0")
+        .parse::<i32>()
+        .map_err(|e| ParseError::from(e).with_field(minute_field, "zone_minutes"))?;
 
     if let Some(offset) = FixedOffset::east_opt(hour * 3600 + hour.signum() * minute * 60) {
         Ok(offset)
     } else {
-        Err(ParseError::InvalidSentence(format!(
-            "Time zone offset out of bounds: {}:{}",
-            hour, minute
-        )))
+        Err(ParseError::new(
+            ParseErrorKind::OutOfRange,
+            format!("Time zone offset out of bounds: {}:{}", hour, minute),
+        )
+        .with_field(hour_field, "zone_hours"))
     }
 }
 
+/// Make an error about a field value not matching the expected format.
+fn invalid_format(what: &str, raw_value: &str) -> ParseError {
+    ParseError::new(
+        ParseErrorKind::InvalidField,
+        format!("Invalid {} format: {}", what, raw_value),
+    )
+    .with_raw_value(raw_value)
+}
+
 /// Parse day, month and year from YYMMDD string.
 fn parse_date(yymmdd: &str) -> Result<(u32, u32, i32), ParseError> {
     let day = pick_s2(yymmdd, 0).parse::<u32>()?;
@@ -349,11 +643,13 @@ pub fn parse_valid_utc(
         chrono::LocalResult::Single(valid_utc) | chrono::LocalResult::Ambiguous(valid_utc, _) => {
             Ok(valid_utc)
         }
-        chrono::LocalResult::None => Err(format!(
-            "Failed to parse Utc Date from y:{} m:{} d:{} h:{} m:{} s:{}",
-            year, month, day, hour, min, sec
-        )
-        .into()),
+        chrono::LocalResult::None => Err(ParseError::new(
+            ParseErrorKind::OutOfRange,
+            format!(
+                "Failed to parse Utc Date from y:{} m:{} d:{} h:{} m:{} s:{}",
+                year, month, day, hour, min, sec
+            ),
+        )),
     }
 }
 
@@ -385,7 +681,7 @@ pub(crate) fn parse_latitude_ddmm_mmm(
             .map(|c| c.is_ascii_digit())
             .unwrap_or(false))
     {
-        return Err(format!("Failed to parse latitude (DDMM.MMM) from {}", lat_string).into());
+        return Err(invalid_format("latitude (DDMM.MMM)", lat_string));
     }
     let end = 5 + byte_string
         .iter()
@@ -411,7 +707,7 @@ pub(crate) fn parse_latitude_ddmm_mmm(
 pub(crate) fn parse_longitude_dddmm_mmm(
     lon_string: &str,
     hemisphere: &str,
-) -> Result<Option<f64>, String> {
+) -> Result<Option<f64>, ParseError> {
     // DDDMM.MMM
     if lon_string.is_empty() {
         return Ok(None);
@@ -426,10 +722,7 @@ pub(crate) fn parse_longitude_dddmm_mmm(
             .map(|c| c.is_ascii_digit())
             .unwrap_or(false))
     {
-        return Err(format!(
-            "Failed to parse longitude (DDDMM.MMM) from {}",
-            lon_string
-        ));
+        return Err(invalid_format("longitude (DDDMM.MMM)", lon_string));
     }
     let end = 6 + byte_string
         .iter()
@@ -461,9 +754,9 @@ pub(crate) fn parse_latitude_m_m(
             Ok(lat) => match hemisphere {
                 "N" => Ok(Some(lat / 60.0)),
                 "S" => Ok(Some(-lat / 60.0)),
-                _ => Err(format!("Bad hemispehre: {}", hemisphere).into()),
+                _ => Err(invalid_format("hemisphere", hemisphere)),
             },
-            Err(_) => Err(format!("Failed to parse float: {}", lat_string).into()),
+            Err(_) => Err(invalid_format("latitude offset", lat_string)),
         }
     } else {
         Ok(None)
@@ -477,15 +770,15 @@ pub(crate) fn parse_latitude_m_m(
 pub(crate) fn parse_longitude_m_m(
     lon_string: &str,
     hemisphere: &str,
-) -> Result<Option<f64>, String> {
+) -> Result<Option<f64>, ParseError> {
     if !lon_string.is_empty() {
         match lon_string.parse::<f64>() {
             Ok(lon) => match hemisphere {
                 "E" => Ok(Some(lon / 60.0)),
                 "W" => Ok(Some(-lon / 60.0)),
-                _ => Err(format!("Bad hemispehre: {}", hemisphere)),
+                _ => Err(invalid_format("hemisphere", hemisphere)),
             },
-            Err(_) => Err(format!("Failed to parse float: {}", lon_string)),
+            Err(_) => Err(invalid_format("longitude offset", lon_string)),
         }
     } else {
         Ok(None)
@@ -494,13 +787,158 @@ pub(crate) fn parse_longitude_m_m(
 
 // -------------------------------------------------------------------------------------------------
 
+/// Make a complete NMEA sentence by prepending the start character (`$` or `!`) to the given
+/// content and appending the checksum.
+pub(crate) fn make_sentence(start: char, content: &str) -> String {
+    let checksum = content.bytes().fold(0, |acc, c| acc ^ c);
+    format!("{}{}*{:02X}", start, content, checksum)
+}
+
+/// Format a number field with at most `decimals` decimals or an empty string in case of `None`.
+/// Trailing zeros are removed but at least one decimal is always kept.
+pub(crate) fn format_number_field(value: Option<f64>, decimals: usize) -> String {
+    match value {
+        Some(val) => trim_decimals(format!("{:.*}", decimals, val), 1),
+        None => "".into(),
+    }
+}
+
+/// Format an integer field padded with zeros to the given width or an empty string in case of
+/// `None`.
+pub(crate) fn format_integer_field<T: core::fmt::Display>(
+    value: Option<T>,
+    width: usize,
+) -> String {
+    match value {
+        Some(val) => format!("{:0w$}", val, w = width),
+        None => "".into(),
+    }
+}
+
+/// Format a hex field padded with zeros to the given width or an empty string in case of `None`.
+pub(crate) fn format_hex_field<T: core::fmt::UpperHex>(value: Option<T>, width: usize) -> String {
+    match value {
+        Some(val) => format!("{:0w$X}", val, w = width),
+        None => "".into(),
+    }
+}
+
+/// Format a string field or an empty string in case of `None`.
+pub(crate) fn format_string_field(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("")
+}
+
+/// Format status field as "A" (true) or "V" (false) or an empty string in case of `None`.
+pub(crate) fn format_status_field(value: Option<bool>) -> &'static str {
+    match value {
+        Some(true) => "A",
+        Some(false) => "V",
+        None => "",
+    }
+}
+
+/// Format time field of format HHMMSS.SS or an empty string in case of `None`.
+pub(crate) fn format_hhmmss_ss(timestamp: Option<DateTime<Utc>>) -> String {
+    match timestamp {
+        Some(t) => format!(
+            "{:02}{:02}{:02}.{:02}",
+            t.hour(),
+            t.minute(),
+            t.second(),
+            min(t.nanosecond() / 10000000, 99)
+        ),
+        None => "".into(),
+    }
+}
+
+/// Format date field of format DDMMYY or an empty string in case of `None`.
+pub(crate) fn format_ddmmyy(timestamp: Option<DateTime<Utc>>) -> String {
+    match timestamp {
+        Some(t) => format!("{:02}{:02}{:02}", t.day(), t.month(), t.year() % 100),
+        None => "".into(),
+    }
+}
+
+/// Format latitude to DDMM.MMMMM and hemisphere fields. Both are empty in case of `None`.
+pub(crate) fn format_latitude_ddmm_mmm(latitude: Option<f64>) -> (String, &'static str) {
+    match latitude {
+        Some(lat) => (
+            format_degrees_minutes(lat.abs(), 2),
+            if lat < 0.0 { "S" } else { "N" },
+        ),
+        None => ("".into(), ""),
+    }
+}
+
+/// Format longitude to DDDMM.MMMMM and hemisphere fields. Both are empty in case of `None`.
+pub(crate) fn format_longitude_dddmm_mmm(longitude: Option<f64>) -> (String, &'static str) {
+    match longitude {
+        Some(lon) => (
+            format_degrees_minutes(lon.abs(), 3),
+            if lon < 0.0 { "W" } else { "E" },
+        ),
+        None => ("".into(), ""),
+    }
+}
+
+/// Format latitude offset in minutes and hemisphere fields. Both are empty in case of `None`.
+pub(crate) fn format_latitude_m_m(latitude: Option<f64>) -> (String, &'static str) {
+    match latitude {
+        Some(lat) => (
+            format_number_field(Some(lat.abs() * 60.0), 4),
+            if lat < 0.0 { "S" } else { "N" },
+        ),
+        None => ("".into(), ""),
+    }
+}
+
+/// Format longitude offset in minutes and hemisphere fields. Both are empty in case of `None`.
+pub(crate) fn format_longitude_m_m(longitude: Option<f64>) -> (String, &'static str) {
+    match longitude {
+        Some(lon) => (
+            format_number_field(Some(lon.abs() * 60.0), 4),
+            if lon < 0.0 { "W" } else { "E" },
+        ),
+        None => ("".into(), ""),
+    }
+}
+
+/// Format non-negative degrees to degrees and minutes with three to six decimals. The rounding is
+/// done in integers to avoid 60.000000 minutes.
+fn format_degrees_minutes(degrees: f64, degree_digits: usize) -> String {
+    let total = (degrees * 60000000.0).round() as u64;
+    let minutes = total % 60000000;
+    trim_decimals(
+        format!(
+            "{:0w$}{:02}.{:06}",
+            total / 60000000,
+            minutes / 1000000,
+            minutes % 1000000,
+            w = degree_digits
+        ),
+        3,
+    )
+}
+
+/// Remove trailing zeros from a decimal number string, leaving at least `min_decimals` decimals.
+fn trim_decimals(s: String, min_decimals: usize) -> String {
+    if let Some(pos) = s.find('.') {
+        let keep = max(s.trim_end_matches('0').len(), pos + 1 + min_decimals);
+        s[..min(keep, s.len())].to_string()
+    } else {
+        s
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     #[test]
     fn test_parse_payload() {
-        match parse_payload("w7b0P1") {
+        match parse_payload("w7b0P1", 0) {
             Ok(bv) => {
                 assert_eq!(
                     bv,
@@ -518,6 +956,16 @@ mod test {
                 assert_eq!(e, "OK");
             }
         }
+
+        match parse_payload("w7b0P1", 4) {
+            Ok(bv) => {
+                assert_eq!(bv.len(), 32);
+                assert_eq!(pick_u64(&bv, 26, 6), 0b000000);
+            }
+            Err(e) => {
+                assert_eq!(e, "OK");
+            }
+        }
     }
 
     #[test]
@@ -540,6 +988,15 @@ mod test {
         assert_eq!(pick_i64(&bitvec![1, 0, 0, 0, 0, 0], 0, 6), -32);
     }
 
+    #[test]
+    fn test_pick_unless_na() {
+        let bv = bitvec![1, 0, 1, 1, 0, 1];
+        assert_eq!(pick_u64_unless_na(&bv, 0, 6, 63), Some(45));
+        assert_eq!(pick_u64_unless_na(&bv, 0, 6, 45), None);
+        assert_eq!(pick_i64_unless_na(&bv, 0, 6, -32), Some(-19));
+        assert_eq!(pick_i64_unless_na(&bv, 0, 6, -19), None);
+    }
+
     #[test]
     fn test_pick_string() {
         let bv = bitvec![
@@ -565,7 +1022,7 @@ mod test {
             1, 0, 1, 1, 0, // 22
             1, 1, 1, 0, 0, 1, // 57
         ];
-        let eta = pick_eta(&bv, 0).ok().unwrap();
+        let eta = pick_eta(&bv, 0, None).ok().unwrap();
         assert_eq!(
             eta,
             Utc.with_ymd_and_hms(eta.unwrap().year(), 10, 11, 22, 57, 30)
@@ -579,7 +1036,7 @@ mod test {
             1, 0, 1, 1, 0, // 22
             1, 1, 1, 0, 0, 1, // 57
         ];
-        assert!(!pick_eta(&bv, 0).is_ok());
+        assert!(!pick_eta(&bv, 0, None).is_ok());
 
         // Invalid day
         let bv = bitvec![
@@ -588,7 +1045,7 @@ mod test {
             1, 0, 1, 1, 0, // 22
             1, 1, 1, 0, 0, 1, // 57
         ];
-        assert!(!pick_eta(&bv, 0).is_ok());
+        assert!(!pick_eta(&bv, 0, None).is_ok());
 
         // Invalid hour
         let bv = bitvec![
@@ -597,7 +1054,7 @@ mod test {
             1, 1, 0, 0, 1, // 25
             1, 1, 1, 0, 0, 1, // 57
         ];
-        assert!(!pick_eta(&bv, 0).is_ok());
+        assert!(!pick_eta(&bv, 0, None).is_ok());
 
         // Invalid minute
         let bv = bitvec![
@@ -606,7 +1063,7 @@ mod test {
             1, 0, 1, 1, 0, // 22
             1, 1, 1, 1, 0, 1, // 61
         ];
-        assert!(!pick_eta(&bv, 0).is_ok());
+        assert!(!pick_eta(&bv, 0, None).is_ok());
     }
 
     #[test]
@@ -676,6 +1133,69 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_pick_month_day_time() {
+        // Valid case
+        let bv = bitvec![
+            1, 0, 1, 0, // 10
+            0, 1, 0, 1, 1, // 11
+            1, 0, 1, 1, 0, // 22
+            1, 1, 1, 0, 0, 1, // 57
+        ];
+        assert_eq!(
+            pick_month_day_time(&bv, 0, None).ok().unwrap(),
+            Utc.with_ymd_and_hms(2000, 10, 11, 22, 57, 0).single()
+        );
+
+        // Day not available
+        let bv = bitvec![
+            1, 0, 1, 0, // 10
+            0, 0, 0, 0, 0, // 0
+            1, 0, 1, 1, 0, // 22
+            1, 1, 1, 0, 0, 1, // 57
+        ];
+        assert_eq!(pick_month_day_time(&bv, 0, None).ok().unwrap(), None);
+
+        // Invalid day
+        let bv = bitvec![
+            0, 0, 1, 0, // 2
+            1, 1, 1, 1, 1, // 31
+            1, 0, 1, 1, 0, // 22
+            1, 1, 1, 0, 0, 1, // 57
+        ];
+        assert!(pick_month_day_time(&bv, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_month_day_time_to_utc() {
+        assert_eq!(
+            month_day_time_to_utc(2, 29, 23, 59, None).ok().unwrap(),
+            Utc.with_ymd_and_hms(2000, 2, 29, 23, 59, 0).single()
+        );
+        assert_eq!(
+            month_day_time_to_utc(2, 29, 24, 0, None).ok().unwrap(),
+            None
+        );
+        assert_eq!(
+            month_day_time_to_utc(2, 29, 0, 60, None).ok().unwrap(),
+            None
+        );
+        assert!(month_day_time_to_utc(13, 1, 0, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_pick_latitude_and_longitude() {
+        let mut bv = BitVec::<usize, LocalBits>::new();
+        for i in (0..25).rev() {
+            bv.push(((181 * 60000) >> i) & 1 != 0);
+        }
+        for i in (0..24).rev() {
+            bv.push(((-30000i64) >> i) & 1 != 0);
+        }
+        assert_eq!(pick_longitude(&bv, 0, 25, 60000), None);
+        assert_eq!(pick_latitude(&bv, 25, 24, 60000), Some(-0.5));
+    }
+
     #[test]
     fn test_parse_valid_utc() {
         assert!(parse_valid_utc(2020, 2, 29, 0, 0, 0, 0).is_ok());
@@ -685,23 +1205,48 @@ mod test {
     #[test]
     fn test_pick_number_field() {
         let s: Vec<&str> = "128,0,8.0,,xyz".split(',').collect();
-        assert_eq!(pick_number_field::<u8>(&s, 0).ok().unwrap().unwrap(), 128);
-        assert_eq!(pick_number_field::<u8>(&s, 1).ok().unwrap().unwrap(), 0);
-        assert_eq!(pick_number_field::<f64>(&s, 2).ok().unwrap().unwrap(), 8.0);
-        assert_eq!(pick_number_field::<u16>(&s, 3).ok().unwrap(), None);
-        assert!(!pick_number_field::<u32>(&s, 4).is_ok());
-        assert_eq!(pick_number_field::<u32>(&s, 5).ok().unwrap(), None);
+        assert_eq!(
+            pick_number_field::<u8>(&s, 0, "test")
+                .ok()
+                .unwrap()
+                .unwrap(),
+            128
+        );
+        assert_eq!(
+            pick_number_field::<u8>(&s, 1, "test")
+                .ok()
+                .unwrap()
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            pick_number_field::<f64>(&s, 2, "test")
+                .ok()
+                .unwrap()
+                .unwrap(),
+            8.0
+        );
+        assert_eq!(pick_number_field::<u16>(&s, 3, "test").ok().unwrap(), None);
+        let e = pick_number_field::<u32>(&s, 4, "test").unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::InvalidField);
+        assert_eq!(e.location(), Some(ErrorLocation::Field(4)));
+        assert_eq!(e.field_name(), Some("test"));
+        assert_eq!(e.raw_value(), Some("xyz"));
+        assert_eq!(pick_number_field::<u32>(&s, 5, "test").ok().unwrap(), None);
     }
 
     #[test]
     fn test_pick_hex_field() {
         let s: Vec<&str> = "ff,0,,FFFF,8080808080808080".split(',').collect();
-        assert_eq!(pick_hex_field::<u8>(&s, 0).unwrap().unwrap(), 255);
-        assert_eq!(pick_hex_field::<u8>(&s, 1).unwrap().unwrap(), 0);
-        assert_eq!(pick_hex_field::<u8>(&s, 2).unwrap(), None);
-        assert_eq!(pick_hex_field::<u16>(&s, 3).unwrap().unwrap(), 65535);
+        assert_eq!(pick_hex_field::<u8>(&s, 0, "test").unwrap().unwrap(), 255);
+        assert_eq!(pick_hex_field::<u8>(&s, 1, "test").unwrap().unwrap(), 0);
+        assert_eq!(pick_hex_field::<u8>(&s, 2, "test").unwrap(), None);
+        assert_eq!(
+            pick_hex_field::<u16>(&s, 3, "test").unwrap().unwrap(),
+            65535
+        );
         assert_eq!(
-            pick_hex_field::<u64>(&s, 4).unwrap().unwrap(),
+            pick_hex_field::<u64>(&s, 4, "test").unwrap().unwrap(),
             9259542123273814144
         );
     }
@@ -791,6 +1336,42 @@ mod test {
         assert_eq!(parse_hhmmss_ss("123456@", then).ok(), None);
     }
 
+    #[test]
+    fn test_parse_yymmdd_hhmmss() {
+        assert_eq!(
+            parse_yymmdd_hhmmss("191194", "225446", None).ok(),
+            Utc.with_ymd_and_hms(2094, 11, 19, 22, 54, 46).single()
+        );
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).single();
+        assert_eq!(
+            parse_yymmdd_hhmmss("191194", "225446", now).ok(),
+            Utc.with_ymd_and_hms(1994, 11, 19, 22, 54, 46).single()
+        );
+        assert_eq!(
+            parse_yymmdd_hhmmss("010174", "000000", now).ok(),
+            Utc.with_ymd_and_hms(2074, 1, 1, 0, 0, 0).single()
+        );
+        assert!(parse_yymmdd_hhmmss("321194", "225446", now).is_err());
+    }
+
+    #[test]
+    fn test_parse_hhmmss() {
+        assert_eq!(
+            parse_hhmmss("123456", None).ok(),
+            Utc.with_ymd_and_hms(2000, 1, 1, 12, 34, 56).single()
+        );
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 10).single();
+        assert_eq!(
+            parse_hhmmss("235959", now).ok(),
+            Utc.with_ymd_and_hms(2024, 5, 31, 23, 59, 59).single()
+        );
+        let now = Utc.with_ymd_and_hms(2024, 5, 31, 23, 59, 50).single();
+        assert_eq!(
+            parse_hhmmss("000005", now).ok(),
+            Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 5).single()
+        );
+    }
+
     #[test]
     fn test_pick_date_with_fields() {
         let s: Vec<&str> = "$GPZDA,072914.00,31,05,2018,+02,00".split(',').collect();
@@ -820,4 +1401,78 @@ mod test {
         let s: Vec<&str> = ",,,,,+25,00".split(',').collect();
         assert!(!pick_timezone_with_fields(&s, 5, 6).is_ok());
     }
+
+    #[test]
+    fn test_make_sentence() {
+        assert_eq!(
+            make_sentence('$', "IIHDT,15.0,T"),
+            "$IIHDT,15.0,T*16".to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_fields() {
+        assert_eq!(format_number_field(Some(545.4), 3), "545.4");
+        assert_eq!(format_number_field(Some(545.0), 3), "545.0");
+        assert_eq!(format_number_field(Some(0.12345), 3), "0.123");
+        assert_eq!(format_number_field(None, 3), "");
+        assert_eq!(format_integer_field(Some(8), 2), "08");
+        assert_eq!(format_hex_field(Some(0x3au8), 2), "3A");
+        assert_eq!(
+            format_latitude_ddmm_mmm(Some(48.1173)),
+            ("4807.038".to_string(), "N")
+        );
+        assert_eq!(
+            format_longitude_dddmm_mmm(Some(-11.516666666)),
+            ("01131.000".to_string(), "W")
+        );
+        assert_eq!(
+            format_latitude_ddmm_mmm(Some(59.9999999999)),
+            ("6000.000".to_string(), "N")
+        );
+        assert_eq!(
+            format_latitude_ddmm_mmm(Some(48.114860533)),
+            ("4806.891632".to_string(), "N")
+        );
+        assert_eq!(format_latitude_m_m(Some(-0.1)), ("6.0".to_string(), "S"));
+        let t = Utc
+            .with_ymd_and_hms(2020, 11, 9, 22, 54, 46)
+            .unwrap()
+            .with_nanosecond(500000000);
+        assert_eq!(format_hhmmss_ss(t), "225446.50");
+        assert_eq!(format_ddmmyy(t), "091120");
+        assert_eq!(format_hhmmss_ss(None), "");
+    }
+
+    #[test]
+    fn test_make_payload() {
+        let payload = "13u?etPv2;0n:dDPwUM1U1Cb069D";
+        let bv = parse_payload(payload, 0).unwrap();
+        assert_eq!(make_payload(&bv), (payload.to_string(), 0));
+
+        let mut bv = BitVec::new();
+        push_u64(&mut bv, 1, 6);
+        push_u64(&mut bv, 1, 2);
+        assert_eq!(make_payload(&bv), ("1@".to_string(), 4));
+    }
+
+    #[test]
+    fn test_push_fields() {
+        let mut bv = BitVec::new();
+        push_u64(&mut bv, 0x2a, 8);
+        push_i64(&mut bv, -5, 6);
+        push_string(&mut bv, "Abc?", 6);
+        push_longitude(&mut bv, Some(-74.1), 28, 600000);
+        push_latitude(&mut bv, None, 27, 600000);
+        assert_eq!(bv.len(), 8 + 6 + 36 + 28 + 27);
+        assert_eq!(pick_u64(&bv, 0, 8), 0x2a);
+        assert_eq!(pick_i64(&bv, 8, 6), -5);
+        assert_eq!(pick_string(&bv, 14, 6), "ABC?");
+        assert::close(
+            pick_longitude(&bv, 50, 28, 600000).unwrap(),
+            -74.1,
+            0.000001,
+        );
+        assert_eq!(pick_latitude(&bv, 78, 27, 600000), None);
+    }
 }